@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Result,
+    model::actions::ActionJob,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListJobsBuilder {
+    #[serde(skip)]
+    #[skip]
+    path: String,
+    /// Only list jobs with this status, e.g. "queued", "in_progress", "success" or "failure".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListJobsBuilder {
+    pub(crate) fn new(path: impl ToString) -> Self {
+        Self {
+            path: path.to_string(),
+            status: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the jobs.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<ActionJob>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<ActionJob>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<ActionJob>> {
+        let req = client.get(&self.path).query(self).build()?;
+        #[derive(Deserialize)]
+        struct Response {
+            total_count: u64,
+            jobs: Vec<ActionJob>,
+        }
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let Response { total_count, jobs } = client.parse_response(res).await?;
+        Ok(Paged::with_total_count(jobs, &headers, total_count))
+    }
+}
+
+impl_pageable!(ListJobsBuilder, ActionJob);
@@ -0,0 +1,4 @@
+//! Actions endpoints shared by repositories, organizations, users and the admin API.
+//! They only differ in the path the runs and jobs live under.
+pub mod jobs;
+pub mod runs;
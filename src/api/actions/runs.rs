@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Result,
+    model::actions::ActionRun,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListRunsBuilder {
+    #[serde(skip)]
+    #[skip]
+    path: String,
+    /// Only list runs triggered by this event, e.g. "push".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event: Option<String>,
+    /// Only list runs of this branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    /// Only list runs with this status, e.g. "queued", "in_progress", "success" or "failure".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    /// Only list runs triggered by this user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actor: Option<String>,
+    /// Only list runs of this commit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    head_sha: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListRunsBuilder {
+    pub(crate) fn new(path: impl ToString) -> Self {
+        Self {
+            path: path.to_string(),
+            event: None,
+            branch: None,
+            status: None,
+            actor: None,
+            head_sha: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the workflow runs.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<ActionRun>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<ActionRun>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<ActionRun>> {
+        let req = client.get(&self.path).query(self).build()?;
+        #[derive(Deserialize)]
+        struct Response {
+            total_count: u64,
+            workflow_runs: Vec<ActionRun>,
+        }
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let Response {
+            total_count,
+            workflow_runs,
+        } = client.parse_response(res).await?;
+        Ok(Paged::with_total_count(workflow_runs, &headers, total_count))
+    }
+}
+
+impl_pageable!(ListRunsBuilder, ActionRun);
@@ -0,0 +1,51 @@
+use crate::api::actions::{jobs::ListJobsBuilder, runs::ListRunsBuilder};
+
+pub struct AdminActions;
+
+impl AdminActions {
+    /// Lists the workflow runs of the whole instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_admin_runs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let runs = client
+    ///     .admin()
+    ///     .actions()
+    ///     .runs()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn runs(&self) -> ListRunsBuilder {
+        ListRunsBuilder::new("admin/actions/runs")
+    }
+
+    /// Lists the jobs of the whole instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_admin_jobs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let jobs = client
+    ///     .admin()
+    ///     .actions()
+    ///     .jobs()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn jobs(&self) -> ListJobsBuilder {
+        ListJobsBuilder::new("admin/actions/jobs")
+    }
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::orgs::{Organization, Visibility},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+/// Represents the options for creating an organization on behalf of a user.
+/// The only required field is `name`.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateOrgBuilder {
+    /// The user that will own the organization.
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    /// Name of the organization.
+    #[skip]
+    username: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repo_admin_change_team_access: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<Visibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    website: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateOrgBuilder {
+    pub fn new(owner: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            username: name.to_string(),
+            description: None,
+            email: None,
+            full_name: None,
+            location: None,
+            repo_admin_change_team_access: None,
+            visibility: None,
+            website: None,
+            timeout: None,
+        }
+    }
+    /// Send the request to create the [Organization].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Organization> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Organization);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Organization>> {
+        let owner = &self.owner;
+        let req = client
+            .post(format!("admin/users/{owner}/orgs"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
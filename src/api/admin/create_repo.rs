@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::repos::{ObjectFormatName, Repository, TrustModel},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+/// Represents the options for creating a new repository on behalf of a user.
+/// The only required field is `name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Builder)]
+#[build_it(into)]
+#[serde(default)]
+pub struct CreateRepoBuilder {
+    /// The user that will own the repository.
+    #[build_it(skip)]
+    #[serde(skip)]
+    owner: String,
+    /// Name of the repository to create.
+    #[build_it(skip)]
+    name: String,
+    /// Whether the repository should be automatically initialized.
+    /// This will create a README, LICENSE, and .gitignore file.
+    auto_init: Option<bool>,
+    /// Default branch of the repository.
+    default_branch: Option<String>,
+    /// Description of the repository.
+    description: Option<String>,
+    /// Optional Gitignore templates to use.
+    /// Will be ignored if `auto_init` is false.
+    gitignores: Option<String>,
+    /// Optional Issue label-set to use.
+    issue_labels: Option<String>,
+    /// Optional LICENSE to use.
+    license: Option<String>,
+    /// Object Format Name of the underlying git repository.
+    object_format_name: Option<ObjectFormatName>,
+    /// Whether the repository is private.
+    private: Option<bool>,
+    /// Optional README template to use.
+    /// Will be ignored if `auto_init` is false.
+    readme: Option<String>,
+    /// Whether the repository is a template.
+    template: Option<bool>,
+    /// Trust model for verifying commits in the repository.
+    trust_model: Option<TrustModel>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateRepoBuilder {
+    pub fn new(owner: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            auto_init: None,
+            default_branch: None,
+            description: None,
+            gitignores: None,
+            issue_labels: None,
+            license: None,
+            object_format_name: None,
+            private: None,
+            readme: None,
+            template: None,
+            trust_model: None,
+            timeout: None,
+        }
+    }
+    /// Send the request to create the repository.
+    /// This will return the created [Repository].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
+        let owner = &self.owner;
+        let req = client
+            .post(format!("admin/users/{owner}/repos"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -1,7 +1,16 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::user::User, Client};
+use crate::{
+    error::Result,
+    model::{orgs::Visibility, user::User},
+    response::ApiResponse,
+    secret::SecretString,
+    timestamp::Timestamp,
+    GiteaHttp,
+};
 
 /// Represents the options for creating a new user.
 /// The only required field is `email` and `username`.
@@ -17,9 +26,9 @@ pub struct CreateUserBuilder {
     pub username: String,
     #[build_it(skip)]
     /// The password of the user
-    pub password: String,
+    pub password: SecretString,
     /// Date the user was created at.
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     /// Full name of the user.
     pub full_name: Option<String>,
     /// If the user needs to change the password.
@@ -31,10 +40,12 @@ pub struct CreateUserBuilder {
     /// The source id
     pub source_id: Option<i64>,
     /// User visibility.
-    /// Can be one of "public", "limited", or "private".
-    pub visibility: Option<String>,
+    pub visibility: Option<Visibility>,
     /// The user's authenticated sign-in name. Empty by default.
     pub login_name: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateUserBuilder {
@@ -45,19 +56,30 @@ impl CreateUserBuilder {
             created_at: None,
             full_name: None,
             must_change_password: None,
-            password: password.to_string(),
+            password: password.to_string().into(),
             restricted: None,
             send_notify: None,
             source_id: None,
             visibility: None,
             login_name: None,
+            timeout: None,
         }
     }
     /// Send the request to create the user.
     /// This will return the created [User].
-    pub async fn send(&self, client: &Client) -> Result<User> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<User> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(User);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<User>> {
         let req = client.post("admin/users".to_string()).json(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::admin::Cron,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+pub struct Crons;
+
+impl Crons {
+    /// Lists the cron tasks of the instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_crons() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let crons = client
+    ///     .admin()
+    ///     .crons()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListCronsBuilder {
+        ListCronsBuilder::new()
+    }
+
+    /// Runs a cron task immediately.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn run_cron() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .crons()
+    ///     .run("repo_health_check")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn run(&self, task: impl ToString) -> RunCronBuilder {
+        RunCronBuilder::new(task)
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+pub struct ListCronsBuilder {
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListCronsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to list the cron tasks.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Cron>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Cron>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Cron>> {
+        let req = client.get("admin/cron").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListCronsBuilder, Cron);
+
+#[derive(Debug, Clone)]
+pub struct RunCronBuilder {
+    task: String,
+    timeout: Option<Duration>,
+}
+
+impl RunCronBuilder {
+    pub fn new(task: impl ToString) -> Self {
+        Self {
+            task: task.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to run the cron task.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let task = &self.task;
+        let req = client.post(format!("admin/cron/{task}")).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
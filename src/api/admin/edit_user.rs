@@ -1,7 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::user::User, Client};
+use crate::{
+    error::Result,
+    model::{orgs::Visibility, user::User},
+    response::ApiResponse,
+    secret::SecretString,
+    GiteaHttp,
+};
 
 /// Represents the options for creating a new user.
 /// The only required field is `email` and `username`.
@@ -43,12 +51,14 @@ pub struct EditUserBuilder {
     /// If the user needs to change the password.
     pub must_change_password: Option<String>,
     /// The password of the user
-    pub password: Option<String>,
+    pub password: Option<SecretString>,
     /// Whether the user is restricted.
     pub restricted: Option<bool>,
     /// User visibility.
-    /// Can be one of "public", "limited", or "private".
-    pub visibility: Option<String>,
+    pub visibility: Option<Visibility>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditUserBuilder {
@@ -72,17 +82,28 @@ impl EditUserBuilder {
             password: None,
             restricted: None,
             visibility: None,
+            timeout: None,
         }
     }
     /// Send the request to update the user.
     /// This will return the updated [User].
-    pub async fn send(&self, client: &Client) -> Result<User> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<User> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(User);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<User>> {
         let username = &self.username;
         let req = client
             .patch(format!("admin/users/{username}"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
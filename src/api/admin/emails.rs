@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::Email,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+pub struct AdminEmails;
+
+impl AdminEmails {
+    /// Lists the email addresses of all users.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let emails = client
+    ///     .admin()
+    ///     .emails()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListEmailsBuilder {
+        ListEmailsBuilder::new()
+    }
+
+    /// Searches the email addresses of all users.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn search_emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let emails = client
+    ///     .admin()
+    ///     .emails()
+    ///     .search("example.com")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn search(&self, query: impl ToString) -> SearchEmailsBuilder {
+        SearchEmailsBuilder::new(query)
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+pub struct ListEmailsBuilder {
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListEmailsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to list all email addresses.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Email>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Email>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Email>> {
+        let req = client.get("admin/emails").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListEmailsBuilder, Email);
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SearchEmailsBuilder {
+    /// Keyword to search for
+    #[serde(rename = "q")]
+    #[skip]
+    query: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl SearchEmailsBuilder {
+    pub fn new(query: impl ToString) -> Self {
+        Self {
+            query: query.to_string(),
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to search all email addresses.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Email>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Email>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Email>> {
+        let req = client.get("admin/emails/search").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(SearchEmailsBuilder, Email);
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::user::PublicKey, response::ApiResponse, GiteaHttp};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct AddUserKeyBuilder {
+    #[serde(skip)]
+    #[skip]
+    username: String,
+    #[skip]
+    title: String,
+    #[skip]
+    key: String,
+    /// Whether the key only grants read access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    read_only: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl AddUserKeyBuilder {
+    pub fn new(username: impl ToString, title: impl ToString, key: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            title: title.to_string(),
+            key: key.to_string(),
+            read_only: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add an SSH key to the user.
+    /// This will return the created [PublicKey].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PublicKey> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PublicKey);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PublicKey>> {
+        let username = &self.username;
+        let req = client
+            .post(format!("admin/users/{username}/keys"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteUserKeyBuilder {
+    username: String,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteUserKeyBuilder {
+    pub fn new(username: impl ToString, id: i64) -> Self {
+        Self {
+            username: username.to_string(),
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete an SSH key of the user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { username, id, .. } = self;
+        let req = client
+            .delete(format!("admin/users/{username}/keys/{id}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
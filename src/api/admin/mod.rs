@@ -1,2 +1,163 @@
+pub mod actions;
+pub mod create_org;
+pub mod create_repo;
 pub mod create_user;
+pub mod crons;
 pub mod edit_user;
+pub mod emails;
+pub mod keys;
+pub mod quota;
+pub mod users;
+
+/// Site administration endpoints.
+/// All of these require the authenticated user to be a site administrator.
+pub struct Admin;
+
+impl Admin {
+    /// Access the email addresses of all users.
+    /// See [emails::AdminEmails] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn admin_emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let emails = client
+    ///     .admin()
+    ///     .emails()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn emails(&self) -> emails::AdminEmails {
+        emails::AdminEmails
+    }
+
+    /// Access the cron tasks of the instance.
+    /// See [crons::Crons] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn admin_crons() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let crons = client
+    ///     .admin()
+    ///     .crons()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn crons(&self) -> crons::Crons {
+        crons::Crons
+    }
+
+    /// Manage the system webhooks of the instance.
+    /// See [Hooks](crate::api::hooks::Hooks) for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn admin_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .admin()
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> crate::api::hooks::Hooks {
+        crate::api::hooks::Hooks {
+            path: "admin/hooks".to_string(),
+        }
+    }
+
+    /// Manage the users of the instance.
+    /// See [users::AdminUsers] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn admin_users() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .users()
+    ///     .delete_key("username", 1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn users(&self) -> users::AdminUsers {
+        users::AdminUsers
+    }
+
+    /// Manage the quota rules and groups of the instance.
+    /// See [quota::Quota] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn admin_quota() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let groups = client
+    ///     .admin()
+    ///     .quota()
+    ///     .list_groups()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn quota(&self) -> quota::Quota {
+        quota::Quota
+    }
+
+    /// Access the Actions runs and jobs of the whole instance.
+    /// See [actions::AdminActions] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn admin_actions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let jobs = client
+    ///     .admin()
+    ///     .actions()
+    ///     .jobs()
+    ///     .status("running")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn actions(&self) -> actions::AdminActions {
+        actions::AdminActions
+    }
+}
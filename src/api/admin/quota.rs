@@ -0,0 +1,917 @@
+//! Quota endpoints of the admin API.
+//! Quotas are a Forgejo feature: Gitea (as of the 1.23 API) has no such endpoints and answers 404.
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::{
+        quota::{QuotaGroup, QuotaInfo, QuotaRule},
+        user::User,
+    },
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+/// Quota rules and groups of the instance.
+/// These endpoints are only available on Forgejo instances with quota support enabled.
+pub struct Quota;
+
+impl Quota {
+    /// Lists the quota rules of the instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_quota_rules() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rules = client
+    ///     .admin()
+    ///     .quota()
+    ///     .list_rules()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_rules(&self) -> ListQuotaRulesBuilder {
+        ListQuotaRulesBuilder::new()
+    }
+
+    /// Gets a quota rule by name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_quota_rule() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rule = client
+    ///     .admin()
+    ///     .quota()
+    ///     .get_rule("repos-1g")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_rule(&self, name: impl ToString) -> GetQuotaRuleBuilder {
+        GetQuotaRuleBuilder::new(name)
+    }
+
+    /// Creates a new quota rule.
+    /// The limit is given in bytes, `-1` means unlimited.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_quota_rule() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rule = client
+    ///     .admin()
+    ///     .quota()
+    ///     .create_rule("repos-1g", 1024 * 1024 * 1024)
+    ///     .subjects(vec!["size:repos:all".to_string()])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create_rule(&self, name: impl ToString, limit: i64) -> CreateQuotaRuleBuilder {
+        CreateQuotaRuleBuilder::new(name, limit)
+    }
+
+    /// Edits an existing quota rule.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_quota_rule() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rule = client
+    ///     .admin()
+    ///     .quota()
+    ///     .edit_rule("repos-1g")
+    ///     .limit(2 * 1024 * 1024 * 1024)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit_rule(&self, name: impl ToString) -> EditQuotaRuleBuilder {
+        EditQuotaRuleBuilder::new(name)
+    }
+
+    /// Deletes a quota rule.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_quota_rule() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .quota()
+    ///     .delete_rule("repos-1g")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete_rule(&self, name: impl ToString) -> DeleteQuotaRuleBuilder {
+        DeleteQuotaRuleBuilder::new(name)
+    }
+
+    /// Lists the quota groups of the instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_quota_groups() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let groups = client
+    ///     .admin()
+    ///     .quota()
+    ///     .list_groups()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_groups(&self) -> ListQuotaGroupsBuilder {
+        ListQuotaGroupsBuilder::new()
+    }
+
+    /// Gets a quota group by name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_quota_group() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let group = client
+    ///     .admin()
+    ///     .quota()
+    ///     .get_group("default")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_group(&self, name: impl ToString) -> GetQuotaGroupBuilder {
+        GetQuotaGroupBuilder::new(name)
+    }
+
+    /// Creates a new quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_quota_group() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let group = client
+    ///     .admin()
+    ///     .quota()
+    ///     .create_group("default")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create_group(&self, name: impl ToString) -> CreateQuotaGroupBuilder {
+        CreateQuotaGroupBuilder::new(name)
+    }
+
+    /// Deletes a quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_quota_group() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .quota()
+    ///     .delete_group("default")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete_group(&self, name: impl ToString) -> DeleteQuotaGroupBuilder {
+        DeleteQuotaGroupBuilder::new(name)
+    }
+
+    /// Adds a quota rule to a quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_quota_group_rule() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .quota()
+    ///     .add_rule("default", "repos-1g")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_rule(&self, group: impl ToString, rule: impl ToString) -> AddQuotaGroupRuleBuilder {
+        AddQuotaGroupRuleBuilder::new(group, rule)
+    }
+
+    /// Removes a quota rule from a quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_quota_group_rule() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .quota()
+    ///     .remove_rule("default", "repos-1g")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove_rule(
+        &self,
+        group: impl ToString,
+        rule: impl ToString,
+    ) -> RemoveQuotaGroupRuleBuilder {
+        RemoveQuotaGroupRuleBuilder::new(group, rule)
+    }
+
+    /// Lists the users in a quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_quota_group_users() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let users = client
+    ///     .admin()
+    ///     .quota()
+    ///     .list_users("default")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_users(&self, group: impl ToString) -> ListQuotaGroupUsersBuilder {
+        ListQuotaGroupUsersBuilder::new(group)
+    }
+
+    /// Adds a user to a quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_quota_group_user() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .quota()
+    ///     .add_user("default", "username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_user(
+        &self,
+        group: impl ToString,
+        username: impl ToString,
+    ) -> AddQuotaGroupUserBuilder {
+        AddQuotaGroupUserBuilder::new(group, username)
+    }
+
+    /// Removes a user from a quota group.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_quota_group_user() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .quota()
+    ///     .remove_user("default", "username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove_user(
+        &self,
+        group: impl ToString,
+        username: impl ToString,
+    ) -> RemoveQuotaGroupUserBuilder {
+        RemoveQuotaGroupUserBuilder::new(group, username)
+    }
+
+    /// Gets the quota of a user, including their usage and the groups they are in.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_user_quota() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let quota = client
+    ///     .admin()
+    ///     .quota()
+    ///     .get_user_quota("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_user_quota(&self, username: impl ToString) -> GetUserQuotaBuilder {
+        GetUserQuotaBuilder::new(username)
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ListQuotaRulesBuilder {
+    timeout: Option<Duration>,
+}
+
+impl ListQuotaRulesBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to list the quota rules.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<QuotaRule>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<QuotaRule>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<QuotaRule>>> {
+        let req = client.get("admin/quota/rules").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetQuotaRuleBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl GetQuotaRuleBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the quota rule.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaRule> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaRule);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaRule>> {
+        let name = &self.name;
+        let req = client.get(format!("admin/quota/rules/{name}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateQuotaRuleBuilder {
+    #[skip]
+    name: String,
+    #[skip]
+    limit: i64,
+    /// The subjects the rule applies to, e.g. "size:repos:all".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subjects: Option<Vec<String>>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateQuotaRuleBuilder {
+    pub fn new(name: impl ToString, limit: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            limit,
+            subjects: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to create the quota rule.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaRule> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaRule);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaRule>> {
+        let req = client.post("admin/quota/rules").json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditQuotaRuleBuilder {
+    #[serde(skip)]
+    #[skip]
+    name: String,
+    /// The new limit in bytes. `-1` means unlimited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    /// The new subjects the rule applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subjects: Option<Vec<String>>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl EditQuotaRuleBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            limit: None,
+            subjects: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to edit the quota rule.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaRule> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaRule);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaRule>> {
+        let name = &self.name;
+        let req = client
+            .patch(format!("admin/quota/rules/{name}"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteQuotaRuleBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl DeleteQuotaRuleBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete the quota rule.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let name = &self.name;
+        let req = client.delete(format!("admin/quota/rules/{name}")).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ListQuotaGroupsBuilder {
+    timeout: Option<Duration>,
+}
+
+impl ListQuotaGroupsBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to list the quota groups.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<QuotaGroup>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<QuotaGroup>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<QuotaGroup>>> {
+        let req = client.get("admin/quota/groups").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetQuotaGroupBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl GetQuotaGroupBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaGroup> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaGroup);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaGroup>> {
+        let name = &self.name;
+        let req = client.get(format!("admin/quota/groups/{name}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateQuotaGroupBuilder {
+    #[skip]
+    name: String,
+    /// Rules to create and add to the group along with it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rules: Option<Vec<QuotaRule>>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateQuotaGroupBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            rules: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to create the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaGroup> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaGroup);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaGroup>> {
+        let req = client.post("admin/quota/groups").json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteQuotaGroupBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl DeleteQuotaGroupBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let name = &self.name;
+        let req = client
+            .delete(format!("admin/quota/groups/{name}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct AddQuotaGroupRuleBuilder {
+    group: String,
+    rule: String,
+    timeout: Option<Duration>,
+}
+
+impl AddQuotaGroupRuleBuilder {
+    pub fn new(group: impl ToString, rule: impl ToString) -> Self {
+        Self {
+            group: group.to_string(),
+            rule: rule.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add the rule to the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { group, rule, .. } = self;
+        let req = client
+            .put(format!("admin/quota/groups/{group}/rules/{rule}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveQuotaGroupRuleBuilder {
+    group: String,
+    rule: String,
+    timeout: Option<Duration>,
+}
+
+impl RemoveQuotaGroupRuleBuilder {
+    pub fn new(group: impl ToString, rule: impl ToString) -> Self {
+        Self {
+            group: group.to_string(),
+            rule: rule.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to remove the rule from the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { group, rule, .. } = self;
+        let req = client
+            .delete(format!("admin/quota/groups/{group}/rules/{rule}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct ListQuotaGroupUsersBuilder {
+    group: String,
+    timeout: Option<Duration>,
+}
+
+impl ListQuotaGroupUsersBuilder {
+    pub fn new(group: impl ToString) -> Self {
+        Self {
+            group: group.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the users in the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<User>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<User>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<User>>> {
+        let group = &self.group;
+        let req = client
+            .get(format!("admin/quota/groups/{group}/users"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddQuotaGroupUserBuilder {
+    group: String,
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl AddQuotaGroupUserBuilder {
+    pub fn new(group: impl ToString, username: impl ToString) -> Self {
+        Self {
+            group: group.to_string(),
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add the user to the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            group, username, ..
+        } = self;
+        let req = client
+            .put(format!("admin/quota/groups/{group}/users/{username}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveQuotaGroupUserBuilder {
+    group: String,
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl RemoveQuotaGroupUserBuilder {
+    pub fn new(group: impl ToString, username: impl ToString) -> Self {
+        Self {
+            group: group.to_string(),
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to remove the user from the quota group.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            group, username, ..
+        } = self;
+        let req = client
+            .delete(format!("admin/quota/groups/{group}/users/{username}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct GetUserQuotaBuilder {
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl GetUserQuotaBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the quota of the user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaInfo> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaInfo);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaInfo>> {
+        let username = &self.username;
+        let req = client
+            .get(format!("admin/users/{username}/quota"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,172 @@
+use super::{create_org, create_repo, create_user, edit_user, keys};
+
+pub struct AdminUsers;
+
+impl AdminUsers {
+    /// Creates a new user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_user() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let user = client
+    ///     .admin()
+    ///     .users()
+    ///     .create("user@example.com", "username", "password")
+    ///     .must_change_password("true")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(
+        &self,
+        email: impl ToString,
+        username: impl ToString,
+        password: impl ToString,
+    ) -> create_user::CreateUserBuilder {
+        create_user::CreateUserBuilder::new(email, username, password)
+    }
+
+    /// Edits a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_user() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let user = client
+    ///     .admin()
+    ///     .users()
+    ///     .edit("username", "username")
+    ///     .prohibit_login(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(
+        &self,
+        username: impl ToString,
+        login_name: impl ToString,
+    ) -> edit_user::EditUserBuilder {
+        edit_user::EditUserBuilder::new(username, login_name)
+    }
+
+    /// Adds an SSH key to a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .admin()
+    ///     .users()
+    ///     .add_key("username", "laptop", "ssh-ed25519 AAAA...")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_key(
+        &self,
+        username: impl ToString,
+        title: impl ToString,
+        key: impl ToString,
+    ) -> keys::AddUserKeyBuilder {
+        keys::AddUserKeyBuilder::new(username, title, key)
+    }
+
+    /// Deletes an SSH key of a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .admin()
+    ///     .users()
+    ///     .delete_key("username", 1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete_key(&self, username: impl ToString, id: i64) -> keys::DeleteUserKeyBuilder {
+        keys::DeleteUserKeyBuilder::new(username, id)
+    }
+
+    /// Creates an organization owned by a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::orgs::Visibility};
+    /// # async fn create_org() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let org = client
+    ///     .admin()
+    ///     .users()
+    ///     .create_org("username", "org-name")
+    ///     .full_name("Organization")
+    ///     .visibility(Visibility::Private)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will create the organization "org-name" owned by the user "username".
+    pub fn create_org(
+        &self,
+        username: impl ToString,
+        name: impl ToString,
+    ) -> create_org::CreateOrgBuilder {
+        create_org::CreateOrgBuilder::new(username, name)
+    }
+
+    /// Creates a repository owned by a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let repo = client
+    ///     .admin()
+    ///     .users()
+    ///     .create_repo("username", "my-repo")
+    ///     .private(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will create the private repository "username/my-repo".
+    pub fn create_repo(
+        &self,
+        username: impl ToString,
+        name: impl ToString,
+    ) -> create_repo::CreateRepoBuilder {
+        create_repo::CreateRepoBuilder::new(username, name)
+    }
+}
@@ -1,8 +1,10 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
-use crate::{error::Result, Client};
+use crate::{error::Result, GiteaHttp};
 
 #[derive(Debug, Serialize, Clone, Deserialize, Builder)]
 #[build_it(into)]
@@ -11,6 +13,9 @@ pub struct UpdateOrgAvatarBuilder {
     name: String,
     #[skip]
     image: String,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl UpdateOrgAvatarBuilder {
@@ -18,15 +23,20 @@ impl UpdateOrgAvatarBuilder {
         Self {
             name: name.to_string(),
             image: image.to_string(),
+            timeout: None,
         }
     }
 
-    pub async fn send(&self, client: &Client) -> Result<StatusCode> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<StatusCode> {
         let req = client
             .post(format!("orgs/{}/avatar", self.name))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
+        let res = client.make_request(self.with_timeout(req)).await?;
         Ok(res.status())
     }
+
+    crate::transport::send_blocking!(StatusCode);
+
+    crate::transport::request_timeout!();
 }
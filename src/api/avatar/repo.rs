@@ -1,4 +1,6 @@
-use crate::{error::Result, Client};
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
 use build_it::Builder;
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
@@ -12,6 +14,9 @@ pub struct UpdateRepoAvatarBuilder {
     repo: String,
     #[skip]
     image: String,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl UpdateRepoAvatarBuilder {
@@ -20,15 +25,20 @@ impl UpdateRepoAvatarBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             image: image.to_string(),
+            timeout: None,
         }
     }
 
-    pub async fn send(&self, client: &Client) -> Result<StatusCode> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<StatusCode> {
         let req = client
             .post(format!("repos/{}/{}/avatar", self.owner, self.repo))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
+        let res = client.make_request(self.with_timeout(req)).await?;
         Ok(res.status())
     }
+
+    crate::transport::send_blocking!(StatusCode);
+
+    crate::transport::request_timeout!();
 }
@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::hooks::{Hook, HookType},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateHookBuilder {
+    #[serde(skip)]
+    #[skip]
+    path: String,
+    #[skip]
+    r#type: HookType,
+    /// The configuration of the hook. `url` and `content_type` are required.
+    #[skip]
+    config: BTreeMap<String, String>,
+
+    /// The events that trigger the hook. Defaults to "push".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    /// Whether the hook is active. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+    /// Only trigger the hook for branches matching this glob pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch_filter: Option<String>,
+    /// Value of the Authorization header sent with each delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorization_header: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateHookBuilder {
+    pub(crate) fn new(path: impl ToString, r#type: HookType, url: impl ToString) -> Self {
+        let config = BTreeMap::from([
+            ("url".to_string(), url.to_string()),
+            ("content_type".to_string(), "json".to_string()),
+        ]);
+        Self {
+            path: path.to_string(),
+            r#type,
+            config,
+            events: None,
+            active: None,
+            branch_filter: None,
+            authorization_header: None,
+            timeout: None,
+        }
+    }
+
+    /// Sets a configuration value of the hook, e.g. `content_type` or `secret`.
+    pub fn config(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.config.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Sends the request to create the webhook.
+    /// This will return the created [Hook].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Hook> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Hook);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Hook>> {
+        let req = client.post(&self.path).json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct DeleteHookBuilder {
+    path: String,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteHookBuilder {
+    pub(crate) fn new(path: impl ToString, id: i64) -> Self {
+        Self {
+            path: path.to_string(),
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete a webhook.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { path, id, .. } = self;
+        let req = client.delete(format!("{path}/{id}")).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::hooks::Hook, response::ApiResponse, GiteaHttp};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditHookBuilder {
+    #[serde(skip)]
+    #[skip]
+    path: String,
+    #[serde(skip)]
+    #[skip]
+    id: i64,
+
+    /// The configuration of the hook.
+    /// This replaces the whole configuration, so `url` and `content_type` must be included.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<BTreeMap<String, String>>,
+    /// The events that trigger the hook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    /// Whether the hook is active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+    /// Only trigger the hook for branches matching this glob pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch_filter: Option<String>,
+    /// Value of the Authorization header sent with each delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorization_header: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl EditHookBuilder {
+    pub(crate) fn new(path: impl ToString, id: i64) -> Self {
+        Self {
+            path: path.to_string(),
+            id,
+            config: None,
+            events: None,
+            active: None,
+            branch_filter: None,
+            authorization_header: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to edit the webhook.
+    /// This will return the updated [Hook].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Hook> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Hook);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Hook>> {
+        let Self { path, id, .. } = self;
+        let req = client.patch(format!("{path}/{id}")).json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
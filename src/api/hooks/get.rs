@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::hooks::Hook,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListHooksBuilder {
+    #[serde(skip)]
+    #[skip]
+    path: String,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListHooksBuilder {
+    pub(crate) fn new(path: impl ToString) -> Self {
+        Self {
+            path: path.to_string(),
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the webhooks.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Hook>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Hook>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Hook>> {
+        let req = client.get(&self.path).query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListHooksBuilder, Hook);
+
+#[derive(Debug, Clone)]
+pub struct GetHookBuilder {
+    path: String,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl GetHookBuilder {
+    pub(crate) fn new(path: impl ToString, id: i64) -> Self {
+        Self {
+            path: path.to_string(),
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get a webhook.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Hook> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Hook);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Hook>> {
+        let Self { path, id, .. } = self;
+        let req = client.get(format!("{path}/{id}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
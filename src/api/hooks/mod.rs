@@ -0,0 +1,135 @@
+//! Webhook endpoints shared by users, organizations, repositories and the admin API.
+//! They only differ in the path the hooks live under.
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+
+use crate::model::hooks::HookType;
+
+pub struct Hooks {
+    /// Path of the hook collection, e.g. "user/hooks".
+    pub(crate) path: String,
+}
+
+impl Hooks {
+    /// List the [Hooks](crate::model::hooks::Hook).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .user()
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> get::ListHooksBuilder {
+        get::ListHooksBuilder::new(&self.path)
+    }
+
+    /// Get a [Hook](crate::model::hooks::Hook) by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .user()
+    ///     .hooks()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> get::GetHookBuilder {
+        get::GetHookBuilder::new(&self.path, id)
+    }
+
+    /// Create a [Hook](crate::model::hooks::Hook) delivering to `url`.
+    /// The payload content type defaults to JSON.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::hooks::HookType};
+    /// # async fn create_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .user()
+    ///     .hooks()
+    ///     .create(HookType::Gitea, "https://ci.example.com/hook")
+    ///     .config("secret", "s3cr3t")
+    ///     .events(vec!["push".to_string()])
+    ///     .active(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, r#type: HookType, url: impl ToString) -> create::CreateHookBuilder {
+        create::CreateHookBuilder::new(&self.path, r#type, url)
+    }
+
+    /// Edit a [Hook](crate::model::hooks::Hook).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .user()
+    ///     .hooks()
+    ///     .edit(1)
+    ///     .active(false)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, id: i64) -> edit::EditHookBuilder {
+        edit::EditHookBuilder::new(&self.path, id)
+    }
+
+    /// Delete a [Hook](crate::model::hooks::Hook).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .user()
+    ///     .hooks()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> delete::DeleteHookBuilder {
+        delete::DeleteHookBuilder::new(&self.path, id)
+    }
+}
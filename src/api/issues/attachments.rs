@@ -0,0 +1,488 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::upload::FileUpload;
+use crate::{error::Result, model::issues::Attachment, response::ApiResponse, GiteaHttp};
+
+/// The [Attachments] struct provides methods for managing the attachments of an issue.
+pub struct Attachments {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) issue: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateIssueAttachmentBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    name: String,
+    data: Vec<u8>,
+    timeout: Option<Duration>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct UploadIssueAttachmentBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    file: FileUpload,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListIssueAttachmentsBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetIssueAttachmentBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    attachment: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditIssueAttachmentBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    #[serde(skip)]
+    #[skip]
+    issue: i64,
+    #[serde(skip)]
+    #[skip]
+    attachment: i64,
+
+    /// New name of the attachment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteIssueAttachmentBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    attachment: i64,
+    timeout: Option<Duration>,
+}
+
+impl Attachments {
+    /// Upload a new attachment to an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_attachment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachment = client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1)
+    ///     .create("build.log", "build failed".as_bytes())
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will upload a file named "build.log" to the issue #1 in the repository "owner/repo".
+    pub fn create(
+        &self,
+        name: impl ToString,
+        data: impl Into<Vec<u8>>,
+    ) -> CreateIssueAttachmentBuilder {
+        CreateIssueAttachmentBuilder::new(&self.owner, &self.repo, self.issue, name, data)
+    }
+
+    /// Upload a new attachment to an issue, streaming it from a file instead of holding it in
+    /// memory. See [FileUpload] for details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use gitea_sdk::{Client, Auth, upload::FileUpload};
+    /// # async fn upload_attachment() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let file = tokio::fs::File::open("core.dump").await?;
+    /// let length = file.metadata().await?.len();
+    /// let attachment = client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1)
+    ///     .upload(FileUpload::new("core.dump", file, length))
+    ///     .send(&client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn upload(&self, file: FileUpload) -> UploadIssueAttachmentBuilder {
+        UploadIssueAttachmentBuilder::new(&self.owner, &self.repo, self.issue, file)
+    }
+
+    /// List the attachments of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_attachments() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachments = client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1)
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListIssueAttachmentsBuilder {
+        ListIssueAttachmentsBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+
+    /// Get a single attachment of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_attachment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachment = client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1)
+    ///     .get(2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, attachment: i64) -> GetIssueAttachmentBuilder {
+        GetIssueAttachmentBuilder::new(&self.owner, &self.repo, self.issue, attachment)
+    }
+
+    /// Edit an attachment of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_attachment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachment = client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1)
+    ///     .edit(2)
+    ///     .name("renamed.log")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, attachment: i64) -> EditIssueAttachmentBuilder {
+        EditIssueAttachmentBuilder::new(&self.owner, &self.repo, self.issue, attachment)
+    }
+
+    /// Delete an attachment of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_attachment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1)
+    ///     .delete(2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, attachment: i64) -> DeleteIssueAttachmentBuilder {
+        DeleteIssueAttachmentBuilder::new(&self.owner, &self.repo, self.issue, attachment)
+    }
+}
+
+impl CreateIssueAttachmentBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        issue: i64,
+        name: impl ToString,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            name: name.to_string(),
+            data: data.into(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to upload the attachment.
+    /// This will return the created [Attachment].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Attachment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Attachment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Attachment>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let part = Part::bytes(self.data.clone()).file_name(self.name.clone());
+        let form = Form::new().part("attachment", part);
+        let req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/assets"))
+            .query(&[("name", &self.name)])
+            .multipart(form)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl UploadIssueAttachmentBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, file: FileUpload) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            file,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to upload the attachment, consuming the file.
+    /// This will return the created [Attachment].
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<Attachment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(self, Attachment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Attachment>> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            file,
+            timeout,
+        } = self;
+        let name = file.file_name().to_string();
+        let mut req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/assets"))
+            .query(&[("name", name)])
+            .multipart(file.into_form("attachment"))
+            .build()?;
+        *req.timeout_mut() = timeout;
+        let res = client.make_request(req).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl ListIssueAttachmentsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list an issue's attachments.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Attachment>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Attachment>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Attachment>>> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/issues/{issue}/assets"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl GetIssueAttachmentBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, attachment: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            attachment,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get an issue's attachment.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Attachment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Attachment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Attachment>> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            attachment,
+            ..
+        } = self;
+        let req = client
+            .get(format!(
+                "repos/{owner}/{repo}/issues/{issue}/assets/{attachment}"
+            ))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl EditIssueAttachmentBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, attachment: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            attachment,
+            name: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to edit an issue's attachment.
+    /// This will return the updated [Attachment].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Attachment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Attachment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Attachment>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let attachment = self.attachment;
+        let req = client
+            .patch(format!(
+                "repos/{owner}/{repo}/issues/{issue}/assets/{attachment}"
+            ))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl DeleteIssueAttachmentBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, attachment: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            attachment,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete an issue's attachment.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            attachment,
+            ..
+        } = self;
+        let req = client
+            .delete(format!(
+                "repos/{owner}/{repo}/issues/{issue}/assets/{attachment}"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,333 @@
+use std::time::Duration;
+
+use reqwest::multipart::{Form, Part};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::upload::FileUpload;
+use crate::{error::Result, model::issues::Attachment, response::ApiResponse, GiteaHttp};
+
+/// The [Attachments] struct provides methods for managing the attachments of a comment.
+pub struct Attachments {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) comment: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateCommentAttachmentBuilder {
+    owner: String,
+    repo: String,
+    comment: i64,
+    name: String,
+    data: Vec<u8>,
+    timeout: Option<Duration>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct UploadCommentAttachmentBuilder {
+    owner: String,
+    repo: String,
+    comment: i64,
+    file: FileUpload,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListCommentAttachmentsBuilder {
+    owner: String,
+    repo: String,
+    comment: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteCommentAttachmentBuilder {
+    owner: String,
+    repo: String,
+    comment: i64,
+    attachment: i64,
+    timeout: Option<Duration>,
+}
+
+impl Attachments {
+    /// Upload a new attachment to a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_attachment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachment = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .attachments(1)
+    ///     .create("screenshot.png", vec![0u8; 16])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will upload a file named "screenshot.png" to the comment with the ID 1.
+    pub fn create(
+        &self,
+        name: impl ToString,
+        data: impl Into<Vec<u8>>,
+    ) -> CreateCommentAttachmentBuilder {
+        CreateCommentAttachmentBuilder::new(&self.owner, &self.repo, self.comment, name, data)
+    }
+
+    /// Upload a new attachment to a comment, streaming it from a file instead of holding it in
+    /// memory. See [FileUpload] for details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use gitea_sdk::{Client, Auth, upload::FileUpload};
+    /// # async fn upload_attachment() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let file = tokio::fs::File::open("recording.mp4").await?;
+    /// let length = file.metadata().await?.len();
+    /// let attachment = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .attachments(1)
+    ///     .upload(FileUpload::new("recording.mp4", file, length))
+    ///     .send(&client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn upload(&self, file: FileUpload) -> UploadCommentAttachmentBuilder {
+        UploadCommentAttachmentBuilder::new(&self.owner, &self.repo, self.comment, file)
+    }
+
+    /// List the attachments of a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_attachments() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachments = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .attachments(1)
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListCommentAttachmentsBuilder {
+        ListCommentAttachmentsBuilder::new(&self.owner, &self.repo, self.comment)
+    }
+
+    /// Delete an attachment of a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_attachment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .attachments(1)
+    ///     .delete(2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, attachment: i64) -> DeleteCommentAttachmentBuilder {
+        DeleteCommentAttachmentBuilder::new(&self.owner, &self.repo, self.comment, attachment)
+    }
+}
+
+impl CreateCommentAttachmentBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        comment: i64,
+        name: impl ToString,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            name: name.to_string(),
+            data: data.into(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to upload the attachment.
+    /// This will return the created [Attachment].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Attachment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Attachment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Attachment>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let comment = self.comment;
+        let part = Part::bytes(self.data.clone()).file_name(self.name.clone());
+        let form = Form::new().part("attachment", part);
+        let req = client
+            .post(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/assets"
+            ))
+            .query(&[("name", &self.name)])
+            .multipart(form)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl UploadCommentAttachmentBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, comment: i64, file: FileUpload) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            file,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to upload the attachment, consuming the file.
+    /// This will return the created [Attachment].
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<Attachment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(self, Attachment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Attachment>> {
+        let Self {
+            owner,
+            repo,
+            comment,
+            file,
+            timeout,
+        } = self;
+        let name = file.file_name().to_string();
+        let mut req = client
+            .post(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/assets"
+            ))
+            .query(&[("name", name)])
+            .multipart(file.into_form("attachment"))
+            .build()?;
+        *req.timeout_mut() = timeout;
+        let res = client.make_request(req).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl ListCommentAttachmentsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, comment: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list a comment's attachments.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Attachment>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Attachment>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Attachment>>> {
+        let Self {
+            owner,
+            repo,
+            comment,
+            ..
+        } = self;
+        let req = client
+            .get(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/assets"
+            ))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl DeleteCommentAttachmentBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, comment: i64, attachment: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            attachment,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete a comment's attachment.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            repo,
+            comment,
+            attachment,
+            ..
+        } = self;
+        let req = client
+            .delete(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/assets/{attachment}"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -1,7 +1,11 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{
+    error::Result, model::issues::Comment, response::ApiResponse, timestamp::Timestamp, GiteaHttp,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 pub struct CreateCommentBuilder {
@@ -18,7 +22,10 @@ pub struct CreateCommentBuilder {
     /// The content of the comment.
     #[build_it(skip)]
     body: String,
-    updated_at: Option<String>,
+    updated_at: Option<Timestamp>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateCommentBuilder {
@@ -29,11 +36,22 @@ impl CreateCommentBuilder {
             repo: repo.to_string(),
             body: body.to_string(),
             updated_at: None,
+            timeout: None,
         }
     }
 
     /// Sends the request to create a comment on an issue.
-    pub async fn send(self, client: &Client) -> Result<Comment> {
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<Comment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(self, Comment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(self, client: &impl GiteaHttp) -> Result<ApiResponse<Comment>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let issue = self.issue;
@@ -41,7 +59,7 @@ impl CreateCommentBuilder {
             .post(format!("repos/{owner}/{repo}/issues/{issue}/comments"))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
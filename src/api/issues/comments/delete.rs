@@ -1,10 +1,13 @@
-use crate::{error::Result, Client};
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
 
 #[derive(Debug, Clone)]
 pub struct DeleteCommentBuilder {
     owner: String,
     repo: String,
     comment: i64,
+    timeout: Option<Duration>,
 }
 
 impl DeleteCommentBuilder {
@@ -13,18 +16,23 @@ impl DeleteCommentBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             comment,
+            timeout: None,
         }
     }
 
     /// Sends the request to delete a comment.
-    pub async fn send(&self, client: &Client) -> Result<()> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
         let req = client
             .delete(format!(
                 "repos/{}/{}/issues/comments/{}",
                 self.owner, self.repo, self.comment
             ))
             .build()?;
-        let _ = client.make_request(req).await?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
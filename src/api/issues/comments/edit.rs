@@ -1,7 +1,9 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{error::Result, model::issues::Comment, timestamp::Timestamp, GiteaHttp};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 pub struct EditCommentBuilder {
@@ -18,7 +20,10 @@ pub struct EditCommentBuilder {
     /// The content of the comment.
     #[build_it(skip)]
     body: String,
-    updated_at: Option<String>,
+    updated_at: Option<Timestamp>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditCommentBuilder {
@@ -34,6 +39,7 @@ impl EditCommentBuilder {
             repo: repo.to_string(),
             body: body.to_string(),
             updated_at: None,
+            timeout: None,
         }
     }
 
@@ -41,7 +47,7 @@ impl EditCommentBuilder {
     /// NOTE: This is the only endpoint which returns an option. That's because the Gitea API
     /// decided - in their infinite wisdom - to sometimes return a 204 No Content status code
     /// when editing a comment, which means there's no response body to parse.
-    pub async fn send(self, client: &Client) -> Result<Option<Comment>> {
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<Option<Comment>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let comment = self.comment;
@@ -49,10 +55,14 @@ impl EditCommentBuilder {
             .patch(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
+        let res = client.make_request(self.with_timeout(req)).await?;
         if res.status() == 204 {
             return Ok(None);
         }
         client.parse_response(res).await
     }
+
+    crate::transport::send_blocking!(self, Option<Comment>);
+
+    crate::transport::request_timeout!();
 }
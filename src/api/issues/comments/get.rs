@@ -1,10 +1,13 @@
-use crate::{error::Result, model::issues::Comment, Client};
+use std::time::Duration;
+
+use crate::{error::Result, model::issues::Comment, response::ApiResponse, GiteaHttp};
 
 #[derive(Debug, Clone)]
 pub struct GetCommentBuilder {
     owner: String,
     repo: String,
     comment: i64,
+    timeout: Option<Duration>,
 }
 
 impl GetCommentBuilder {
@@ -13,18 +16,29 @@ impl GetCommentBuilder {
             comment,
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
 
     /// Sends the request to get a comment on an issue.
-    pub async fn send(self, client: &Client) -> Result<Comment> {
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<Comment> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(self, Comment);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(self, client: &impl GiteaHttp) -> Result<ApiResponse<Comment>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let comment = self.comment;
         let req = client
             .get(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,7 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{
+    error::Result,
+    model::issues::Comment,
+    pagination::{impl_pageable, Paged},
+    timestamp::Timestamp,
+    GiteaHttp,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 #[build_it(into)]
@@ -14,13 +22,16 @@ pub struct ListAllCommentsBuilder {
     repo: String,
 
     /// If provided, only comments updated since the provided time are returned.
-    since: Option<String>,
+    since: Option<Timestamp>,
     /// If provided, only comments updated before the provided time are returned.
-    before: Option<String>,
+    before: Option<Timestamp>,
     /// Page number of results to return (1-based).
     page: Option<i64>,
     /// Page size of results
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Builder, Serialize)]
@@ -37,13 +48,16 @@ pub struct ListCommentsBuilder {
     issue: i64,
 
     /// If provided, only comments updated since the provided time are returned.
-    since: Option<String>,
+    since: Option<Timestamp>,
     /// If provided, only comments updated before the provided time are returned.
-    before: Option<String>,
+    before: Option<Timestamp>,
     /// Page number of results to return (1-based).
     page: Option<i64>,
     /// Page size of results
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListAllCommentsBuilder {
@@ -55,22 +69,35 @@ impl ListAllCommentsBuilder {
             before: None,
             page: None,
             limit: None,
+            timeout: None,
         }
     }
 
     /// Sends the request to list a repository's comments.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Comment>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Comment>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Comment>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Comment>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .get(format!("repos/{owner}/{repo}/issues/comments"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
 
+impl_pageable!(ListAllCommentsBuilder, Comment);
+
 impl ListCommentsBuilder {
     pub fn new(owner: &str, repo: &str, issue: i64) -> Self {
         Self {
@@ -81,11 +108,22 @@ impl ListCommentsBuilder {
             before: None,
             page: None,
             limit: None,
+            timeout: None,
         }
     }
 
     /// Sends the request to list an issue's comments.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Comment>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Comment>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Comment>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Comment>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let issue = self.issue;
@@ -93,7 +131,9 @@ impl ListCommentsBuilder {
             .get(format!("repos/{owner}/{repo}/issues/{issue}/comments"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(ListCommentsBuilder, Comment);
@@ -1,8 +1,10 @@
+pub mod attachments;
 pub mod create;
 pub mod delete;
 pub mod edit;
 pub mod get;
 pub mod list;
+pub mod reactions;
 
 pub struct Comments {
     pub(crate) owner: String,
@@ -123,4 +125,52 @@ impl Comments {
     pub fn delete(&self, comment: i64) -> delete::DeleteCommentBuilder {
         delete::DeleteCommentBuilder::new(&self.owner, &self.repo, comment)
     }
+
+    /// Miscellaneous methods for the reactions to a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn comment_reactions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let reactions = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .reactions(1);
+    /// # }
+    /// ```
+    pub fn reactions(&self, comment: i64) -> reactions::Reactions {
+        reactions::Reactions {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            comment,
+        }
+    }
+
+    /// Miscellaneous methods for the attachments of a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn comment_attachments() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachments = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .attachments(1);
+    /// # }
+    /// ```
+    pub fn attachments(&self, comment: i64) -> attachments::Attachments {
+        attachments::Attachments {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            comment,
+        }
+    }
 }
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Reaction, response::ApiResponse, GiteaHttp};
+
+/// The [Reactions] struct provides methods for managing the reactions to a comment.
+pub struct Reactions {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) comment: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListCommentReactionsBuilder {
+    owner: String,
+    repo: String,
+    comment: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AddCommentReactionBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    comment: i64,
+    /// The reaction to add, e.g. "+1", "heart" or "laugh".
+    content: String,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoveCommentReactionBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    comment: i64,
+    /// The reaction to remove.
+    content: String,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl Reactions {
+    /// List the reactions to a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_reactions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let reactions = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .reactions(1)
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListCommentReactionsBuilder {
+        ListCommentReactionsBuilder::new(&self.owner, &self.repo, self.comment)
+    }
+
+    /// Add a reaction to a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_reaction() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let reaction = client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .reactions(1)
+    ///     .add("heart")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will react to the comment with the ID 1 with a heart.
+    pub fn add(&self, content: impl ToString) -> AddCommentReactionBuilder {
+        AddCommentReactionBuilder::new(&self.owner, &self.repo, self.comment, content)
+    }
+
+    /// Remove a reaction from a comment.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_reaction() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .comments()
+    ///     .reactions(1)
+    ///     .remove("heart")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove(&self, content: impl ToString) -> RemoveCommentReactionBuilder {
+        RemoveCommentReactionBuilder::new(&self.owner, &self.repo, self.comment, content)
+    }
+}
+
+impl ListCommentReactionsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, comment: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list a comment's reactions.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Reaction>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Reaction>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Reaction>>> {
+        let Self {
+            owner,
+            repo,
+            comment,
+            ..
+        } = self;
+        let req = client
+            .get(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/reactions"
+            ))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl AddCommentReactionBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        comment: i64,
+        content: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            content: content.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add a reaction to a comment.
+    /// If the user already reacted with the same content, the existing [Reaction] is returned.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Reaction> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Reaction);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Reaction>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let comment = self.comment;
+        let req = client
+            .post(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/reactions"
+            ))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl RemoveCommentReactionBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        comment: i64,
+        content: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            comment,
+            content: content.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to remove a reaction from a comment.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let comment = self.comment;
+        let req = client
+            .delete(format!(
+                "repos/{owner}/{repo}/issues/comments/{comment}/reactions"
+            ))
+            .json(self)
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -1,7 +1,10 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Issue};
+use crate::GiteaHttp;
+use crate::{error::Result, model::issues::Issue, response::ApiResponse, timestamp::Timestamp};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -22,7 +25,7 @@ pub struct CreateIssueBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_date: Option<String>,
+    pub due_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<i64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,6 +33,9 @@ pub struct CreateIssueBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[build_it(rename = "refs")]
     pub r#ref: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateIssueBuilder {
@@ -45,11 +51,22 @@ impl CreateIssueBuilder {
             labels: None,
             milestone: None,
             r#ref: None,
+            timeout: None,
         }
     }
 
     /// Send the request to create the issue.
-    pub async fn send(&self, client: &crate::Client) -> Result<Issue> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Issue> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Issue);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Issue>> {
         // send the request
         let owner = &self.owner;
         let repo = &self.repo;
@@ -57,7 +74,7 @@ impl CreateIssueBuilder {
             .post(format!("repos/{owner}/{repo}/issues"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{
+    error::Result, model::issues::IssueDeadline, response::ApiResponse, timestamp::Timestamp,
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetDeadlineBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    issue: i64,
+
+    /// The new deadline. `None` removes the deadline.
+    due_date: Option<Timestamp>,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl SetDeadlineBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        issue: i64,
+        due_date: Option<Timestamp>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            due_date,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to set the deadline of an issue.
+    /// This will return the new [IssueDeadline].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<IssueDeadline> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(IssueDeadline);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<IssueDeadline>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/deadline"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use crate::error::Result;
+use crate::GiteaHttp;
 
 pub struct DeleteIssueBuilder {
     owner: String,
     repo: String,
     issue_number: i64,
+    timeout: Option<Duration>,
 }
 
 impl DeleteIssueBuilder {
@@ -12,18 +16,24 @@ impl DeleteIssueBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             issue_number,
+            timeout: None,
         }
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<()> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
         let DeleteIssueBuilder {
             owner,
             repo,
             issue_number,
+            ..
         } = self;
         let req = client
             .delete(format!("repos/{owner}/{repo}/issues/{issue_number}",))
             .build()?;
-        client.make_request(req).await?;
+        client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
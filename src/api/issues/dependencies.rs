@@ -0,0 +1,309 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::issues::Issue,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+/// The [IssueRelations] struct provides methods for managing the dependencies of an issue, or
+/// the issues it blocks.
+pub struct IssueRelations {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) issue: i64,
+    /// Either "dependencies" or "blocks".
+    pub(crate) relation: &'static str,
+}
+
+/// Identifies the other issue of a dependency relation.
+/// The issue may live in a different repository.
+#[derive(Debug, Clone, Serialize)]
+struct IssueMeta {
+    owner: String,
+    repo: String,
+    index: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListIssueRelationsBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    #[serde(skip)]
+    #[skip]
+    issue: i64,
+    #[serde(skip)]
+    #[skip]
+    relation: &'static str,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AddIssueRelationBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    relation: &'static str,
+    other: IssueMeta,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveIssueRelationBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    relation: &'static str,
+    other: IssueMeta,
+    timeout: Option<Duration>,
+}
+
+impl IssueRelations {
+    /// List the related issues.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_dependencies() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let dependencies = client
+    ///     .issues("owner", "repo")
+    ///     .dependencies(1)
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will list all issues the issue #1 in the repository "owner/repo" depends on.
+    pub fn list(&self) -> ListIssueRelationsBuilder {
+        ListIssueRelationsBuilder::new(&self.owner, &self.repo, self.issue, self.relation)
+    }
+
+    /// Add a related issue.
+    /// The related issue is identified by its repository and issue number.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_block() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .blocks(1)
+    ///     .add("owner", "other-repo", 2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will mark the issue #1 in the repository "owner/repo" as blocking the issue #2 in the
+    /// repository "owner/other-repo".
+    pub fn add(
+        &self,
+        owner: impl ToString,
+        repo: impl ToString,
+        index: i64,
+    ) -> AddIssueRelationBuilder {
+        AddIssueRelationBuilder::new(
+            &self.owner,
+            &self.repo,
+            self.issue,
+            self.relation,
+            IssueMeta {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                index,
+            },
+        )
+    }
+
+    /// Remove a related issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_dependency() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .dependencies(1)
+    ///     .remove("owner", "repo", 2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will remove the dependency of the issue #1 on the issue #2 in the repository
+    /// "owner/repo".
+    pub fn remove(
+        &self,
+        owner: impl ToString,
+        repo: impl ToString,
+        index: i64,
+    ) -> RemoveIssueRelationBuilder {
+        RemoveIssueRelationBuilder::new(
+            &self.owner,
+            &self.repo,
+            self.issue,
+            self.relation,
+            IssueMeta {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                index,
+            },
+        )
+    }
+}
+
+impl ListIssueRelationsBuilder {
+    fn new(owner: &str, repo: &str, issue: i64, relation: &'static str) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            relation,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the related issues.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Issue>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Issue>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Issue>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let relation = self.relation;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/issues/{issue}/{relation}"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListIssueRelationsBuilder, Issue);
+
+impl AddIssueRelationBuilder {
+    fn new(owner: &str, repo: &str, issue: i64, relation: &'static str, other: IssueMeta) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            relation,
+            other,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add the related issue.
+    /// This will return the related [Issue].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Issue> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Issue);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Issue>> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            relation,
+            other,
+            ..
+        } = self;
+        let req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/{relation}"))
+            .json(other)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl RemoveIssueRelationBuilder {
+    fn new(owner: &str, repo: &str, issue: i64, relation: &'static str, other: IssueMeta) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            relation,
+            other,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to remove the related issue.
+    /// This will return the formerly related [Issue].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Issue> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Issue);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Issue>> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            relation,
+            other,
+            ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/issues/{issue}/{relation}"))
+            .json(other)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -1,7 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Issue};
+use crate::GiteaHttp;
+use crate::{
+    error::Result,
+    model::issues::{Issue, StateType},
+    response::ApiResponse,
+    timestamp::Timestamp,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -21,19 +29,23 @@ pub struct EditIssueBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_date: Option<String>,
+    pub due_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub milestone: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[build_it(rename = "refs")]
     pub r#ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unset_due_date: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    // TODO: Make this a DateTime<Utc>
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditIssueBuilder {
@@ -47,14 +59,26 @@ impl EditIssueBuilder {
             due_date: None,
             milestone: None,
             r#ref: None,
+            state: None,
             title: None,
             unset_due_date: None,
             updated_at: None,
+            timeout: None,
         }
     }
 
     /// Send the request to edit the issue.
-    pub async fn send(&self, client: &crate::Client) -> Result<Issue> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Issue> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Issue);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Issue>> {
         // send the request
         let owner = &self.owner;
         let repo = &self.repo;
@@ -63,7 +87,7 @@ impl EditIssueBuilder {
             .patch(format!("repos/{owner}/{repo}/issues/{index}"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,11 +1,16 @@
+use std::time::Duration;
+
 use crate::error::Result;
 use crate::model::issues::Issue;
+use crate::response::ApiResponse;
+use crate::GiteaHttp;
 
 #[derive(Debug, Clone)]
 pub struct GetIssueBuilder {
     owner: String,
     repo: String,
     issue_number: i64,
+    timeout: Option<Duration>,
 }
 
 impl GetIssueBuilder {
@@ -14,17 +19,28 @@ impl GetIssueBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             issue_number,
+            timeout: None,
         }
     }
     /// Send the request to get the issues.
-    pub async fn send(&self, client: &crate::Client) -> Result<Issue> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Issue> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Issue);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Issue>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let index = &self.issue_number;
         let req = client
             .get(format!("repos/{owner}/{repo}/issues/{index}"))
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,7 +1,9 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Label, Client};
+use crate::{error::Result, model::issues::Label, response::ApiResponse, GiteaHttp};
 
 /// Represents the options for creating a new user.
 /// The only required field is `email` and `username`.
@@ -30,6 +32,9 @@ pub struct EditRepoLabelBuilder {
     pub exclusive: Option<bool>,
     /// Whether the label is archived
     pub is_archived: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditRepoLabelBuilder {
@@ -43,12 +48,23 @@ impl EditRepoLabelBuilder {
             description: None,
             exclusive: None,
             is_archived: None,
+            timeout: None,
         }
     }
 
     /// Send the request to create the label.
     /// This will return the created [Label].
-    pub async fn send(&self, client: &Client) -> Result<Label> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Label> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Label);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Label>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let id = &self.id;
@@ -56,7 +72,7 @@ impl EditRepoLabelBuilder {
             .patch(format!("repos/{owner}/{repo}/labels/{id}"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Label, response::ApiResponse, GiteaHttp};
+
+/// Identifies a label either by its ID or by its name.
+/// The Gitea API accepts both when adding or replacing an issue's labels.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum LabelRef {
+    Id(i64),
+    Name(String),
+}
+
+impl From<i64> for LabelRef {
+    fn from(id: i64) -> Self {
+        LabelRef::Id(id)
+    }
+}
+impl From<&str> for LabelRef {
+    fn from(name: &str) -> Self {
+        LabelRef::Name(name.to_string())
+    }
+}
+impl From<String> for LabelRef {
+    fn from(name: String) -> Self {
+        LabelRef::Name(name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AddIssueLabelsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    issue: i64,
+    /// The labels to add to the issue.
+    labels: Vec<LabelRef>,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplaceIssueLabelsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    issue: i64,
+    /// The labels the issue should have after the request.
+    labels: Vec<LabelRef>,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveIssueLabelBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    label: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClearIssueLabelsBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+impl AddIssueLabelsBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        issue: i64,
+        labels: Vec<impl Into<LabelRef>>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            labels: labels.into_iter().map(Into::into).collect(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to add labels to an issue.
+    /// This will return all [Label]s of the issue after the labels have been added.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Label>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Label>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Label>>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/labels"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl ReplaceIssueLabelsBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        issue: i64,
+        labels: Vec<impl Into<LabelRef>>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            labels: labels.into_iter().map(Into::into).collect(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to replace an issue's labels.
+    /// This will return the new [Label]s of the issue.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Label>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Label>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Label>>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let req = client
+            .put(format!("repos/{owner}/{repo}/issues/{issue}/labels"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl RemoveIssueLabelBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, label: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            label,
+            timeout: None,
+        }
+    }
+    /// Sends the request to remove a label from an issue.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            label,
+            ..
+        } = self;
+        let req = client
+            .delete(format!(
+                "repos/{owner}/{repo}/issues/{issue}/labels/{label}"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+impl ClearIssueLabelsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+    /// Sends the request to remove all labels from an issue.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/issues/{issue}/labels"))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -1,2 +1,114 @@
 pub mod create;
 pub mod edit;
+pub mod issue;
+
+/// The [IssueLabels] struct provides methods for managing the labels of a single issue.
+pub struct IssueLabels {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) issue: i64,
+}
+
+impl IssueLabels {
+    /// Add labels to an issue.
+    /// Labels can be given either by their ID or by their name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .issues("owner", "repo")
+    ///     .labels(1)
+    ///     .add(vec!["bug", "help wanted"])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will add the labels "bug" and "help wanted" to the issue #1 in the repository
+    /// "owner/repo".
+    pub fn add(&self, labels: Vec<impl Into<issue::LabelRef>>) -> issue::AddIssueLabelsBuilder {
+        issue::AddIssueLabelsBuilder::new(&self.owner, &self.repo, self.issue, labels)
+    }
+
+    /// Replace all labels of an issue.
+    /// Labels can be given either by their ID or by their name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn replace_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .issues("owner", "repo")
+    ///     .labels(1)
+    ///     .replace(vec![1, 2])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will set the labels of the issue #1 in the repository "owner/repo" to the labels
+    /// with the IDs 1 and 2.
+    pub fn replace(
+        &self,
+        labels: Vec<impl Into<issue::LabelRef>>,
+    ) -> issue::ReplaceIssueLabelsBuilder {
+        issue::ReplaceIssueLabelsBuilder::new(&self.owner, &self.repo, self.issue, labels)
+    }
+
+    /// Remove a single label from an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .labels(1)
+    ///     .remove(3)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will remove the label with the ID 3 from the issue #1 in the repository "owner/repo".
+    pub fn remove(&self, label: i64) -> issue::RemoveIssueLabelBuilder {
+        issue::RemoveIssueLabelBuilder::new(&self.owner, &self.repo, self.issue, label)
+    }
+
+    /// Remove all labels from an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn clear_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .labels(1)
+    ///     .clear()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn clear(&self) -> issue::ClearIssueLabelsBuilder {
+        issue::ClearIssueLabelsBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+}
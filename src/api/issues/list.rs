@@ -1,8 +1,13 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::issues::{Issue, IssueType, State};
+use crate::pagination::{impl_pageable, Paged};
+use crate::timestamp::Timestamp;
+use crate::GiteaHttp;
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -28,9 +33,9 @@ pub struct ListIssuesBuilder {
     /// Fetch only issues that have any of this milestones. Non existent milestones are discarded
     pub milestone: Option<String>,
     /// Only show items updated after the given time. This is a timestamp in RFC 3339 format
-    pub since: Option<String>,
+    pub since: Option<Timestamp>,
     /// Only show items updated before the given time. This is a timestamp in RFC 3339 format
-    pub before: Option<String>,
+    pub before: Option<Timestamp>,
     /// Only show items which were created by the given user
     pub created_by: Option<String>,
     /// Only show items for which the given user is assigned
@@ -41,6 +46,9 @@ pub struct ListIssuesBuilder {
     pub page: Option<i64>,
     /// Page size of results
     pub limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListIssuesBuilder {
@@ -60,17 +68,30 @@ impl ListIssuesBuilder {
             mentioned_by: None,
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Send the request to get the issues.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Issue>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Issue>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Issue>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Issue>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .get(format!("repos/{owner}/{repo}/issues"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(ListIssuesBuilder, Issue);
@@ -1,10 +1,19 @@
+pub mod attachments;
 pub mod comments;
 pub mod create;
+pub mod deadline;
 pub mod delete;
+pub mod dependencies;
 pub mod edit;
 pub mod get;
 pub mod labels;
 pub mod list;
+pub mod pinned;
+pub mod stopwatch;
+pub mod times;
+
+use crate::model::issues::StateType;
+use crate::timestamp::Timestamp;
 
 pub struct Issues {
     pub(crate) owner: String,
@@ -116,6 +125,54 @@ impl Issues {
         edit::EditIssueBuilder::new(&self.owner, &self.repo, issue_number)
     }
 
+    /// Close an issue.
+    /// This is a shorthand for [Issues::edit] with the state set to [StateType::Closed].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn close_issue() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .close(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will close the issue #1 in the repository "owner/repo".
+    pub fn close(&self, issue_number: i64) -> edit::EditIssueBuilder {
+        self.edit(issue_number).state(StateType::Closed)
+    }
+
+    /// Reopen a closed issue.
+    /// This is a shorthand for [Issues::edit] with the state set to [StateType::Open].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn reopen_issue() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .reopen(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will reopen the issue #1 in the repository "owner/repo".
+    pub fn reopen(&self, issue_number: i64) -> edit::EditIssueBuilder {
+        self.edit(issue_number).state(StateType::Open)
+    }
+
     /// List a repository's issues.
     /// The [GetIssuesOption] struct provides a number of optional fields to filter the results,
     /// but all fields are entirely optional.
@@ -166,4 +223,298 @@ impl Issues {
             repo: self.repo.clone(),
         }
     }
+
+    /// Miscellaneous methods for the labels of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn issue_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .issues("owner", "repo")
+    ///     .labels(1);
+    /// # }
+    /// ```
+    pub fn labels(&self, issue_number: i64) -> labels::IssueLabels {
+        labels::IssueLabels {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            issue: issue_number,
+        }
+    }
+
+    /// Miscellaneous methods for the attachments of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn issue_attachments() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let attachments = client
+    ///     .issues("owner", "repo")
+    ///     .attachments(1);
+    /// # }
+    /// ```
+    pub fn attachments(&self, issue_number: i64) -> attachments::Attachments {
+        attachments::Attachments {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            issue: issue_number,
+        }
+    }
+
+    /// Miscellaneous methods for the time tracked on an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn issue_times() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let times = client
+    ///     .issues("owner", "repo")
+    ///     .times(1);
+    /// # }
+    /// ```
+    pub fn times(&self, issue_number: i64) -> times::Times {
+        times::Times {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            issue: issue_number,
+        }
+    }
+
+    /// Miscellaneous methods for the stopwatch of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn issue_stopwatch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let stopwatch = client
+    ///     .issues("owner", "repo")
+    ///     .stopwatch(1);
+    /// # }
+    /// ```
+    pub fn stopwatch(&self, issue_number: i64) -> stopwatch::IssueStopwatch {
+        stopwatch::IssueStopwatch {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            issue: issue_number,
+        }
+    }
+
+    /// Miscellaneous methods for the issues an issue depends on.
+    /// An issue cannot be closed while any of its dependencies are still open.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn issue_dependencies() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let dependencies = client
+    ///     .issues("owner", "repo")
+    ///     .dependencies(1);
+    /// # }
+    /// ```
+    pub fn dependencies(&self, issue_number: i64) -> dependencies::IssueRelations {
+        dependencies::IssueRelations {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            issue: issue_number,
+            relation: "dependencies",
+        }
+    }
+
+    /// Miscellaneous methods for the issues an issue blocks.
+    /// This is the inverse of [Issues::dependencies].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn issue_blocks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let blocks = client
+    ///     .issues("owner", "repo")
+    ///     .blocks(1);
+    /// # }
+    /// ```
+    pub fn blocks(&self, issue_number: i64) -> dependencies::IssueRelations {
+        dependencies::IssueRelations {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            issue: issue_number,
+            relation: "blocks",
+        }
+    }
+
+    /// Pin an issue.
+    /// Pinned issues are shown at the top of the repository's issue list.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pin_issue() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .pin(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will pin the issue #1 in the repository "owner/repo".
+    pub fn pin(&self, issue_number: i64) -> pinned::PinIssueBuilder {
+        pinned::PinIssueBuilder::new(&self.owner, &self.repo, issue_number)
+    }
+
+    /// Unpin an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn unpin_issue() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .unpin(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will unpin the issue #1 in the repository "owner/repo".
+    pub fn unpin(&self, issue_number: i64) -> pinned::UnpinIssueBuilder {
+        pinned::UnpinIssueBuilder::new(&self.owner, &self.repo, issue_number)
+    }
+
+    /// Move a pinned issue to a new position.
+    /// Positions are 1-based.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn move_pin() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .move_pin(3, 1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will move the pinned issue #3 in the repository "owner/repo" to the first position.
+    pub fn move_pin(&self, issue_number: i64, position: i64) -> pinned::MovePinBuilder {
+        pinned::MovePinBuilder::new(&self.owner, &self.repo, issue_number, position)
+    }
+
+    /// Get a list of pinned issues in a repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pinned_issues() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let pinned_issues = client
+    ///     .issues("owner", "repo")
+    ///     .list_pinned()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get all pinned issues in the repository "owner/repo".
+    pub fn list_pinned(&self) -> pinned::PinnedIssuesBuilder {
+        pinned::PinnedIssuesBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Set the deadline of an issue.
+    /// Unlike [Issues::edit], this only requires the permission to be assigned to the issue.
+    /// Only the date will be taken into account, and time of day ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, Timestamp};
+    /// # async fn set_deadline() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let due_date: Timestamp = "2024-12-31T00:00:00Z".parse().unwrap();
+    /// let deadline = client
+    ///     .issues("owner", "repo")
+    ///     .set_deadline(1, due_date)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will set the deadline of the issue #1 in the repository "owner/repo" to the
+    /// 31st of December 2024.
+    pub fn set_deadline(
+        &self,
+        issue_number: i64,
+        due_date: impl Into<Timestamp>,
+    ) -> deadline::SetDeadlineBuilder {
+        deadline::SetDeadlineBuilder::new(
+            &self.owner,
+            &self.repo,
+            issue_number,
+            Some(due_date.into()),
+        )
+    }
+
+    /// Remove the deadline of an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn clear_deadline() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .clear_deadline(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn clear_deadline(&self, issue_number: i64) -> deadline::SetDeadlineBuilder {
+        deadline::SetDeadlineBuilder::new(&self.owner, &self.repo, issue_number, None)
+    }
 }
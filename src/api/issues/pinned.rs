@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+use crate::{error::Result, model::issues::Issue, response::ApiResponse, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct PinnedIssuesBuilder {
+    owner: String,
+    repo: String,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PinIssueBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnpinIssueBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MovePinBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    position: i64,
+    timeout: Option<Duration>,
+}
+
+impl PinnedIssuesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to list pinned issues.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Issue>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Issue>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Issue>>> {
+        let Self { owner, repo, .. } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/issues/pinned"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl PinIssueBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+    /// Sends the request to pin an issue.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/pin"))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+impl UnpinIssueBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+    /// Sends the request to unpin an issue.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/issues/{issue}/pin"))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+impl MovePinBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, position: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            position,
+            timeout: None,
+        }
+    }
+    /// Sends the request to move a pinned issue to a new position.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            position,
+            ..
+        } = self;
+        let req = client
+            .patch(format!(
+                "repos/{owner}/{repo}/issues/{issue}/pin/{position}"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
+
+/// The [IssueStopwatch] struct provides methods for controlling the stopwatch of an issue.
+pub struct IssueStopwatch {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) issue: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct StartStopwatchBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StopStopwatchBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteStopwatchBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+impl IssueStopwatch {
+    /// Start a stopwatch on an issue.
+    /// If a stopwatch is already running on the issue, this method will return a 409 status
+    /// code.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn start_stopwatch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .stopwatch(1)
+    ///     .start()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn start(&self) -> StartStopwatchBuilder {
+        StartStopwatchBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+
+    /// Stop the running stopwatch on an issue.
+    /// The elapsed time will be added to the issue's tracked times.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn stop_stopwatch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .stopwatch(1)
+    ///     .stop()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn stop(&self) -> StopStopwatchBuilder {
+        StopStopwatchBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+
+    /// Delete the running stopwatch on an issue without tracking the elapsed time.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_stopwatch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .stopwatch(1)
+    ///     .delete()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self) -> DeleteStopwatchBuilder {
+        DeleteStopwatchBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+}
+
+impl StartStopwatchBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to start the stopwatch.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .post(format!(
+                "repos/{owner}/{repo}/issues/{issue}/stopwatch/start"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+impl StopStopwatchBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to stop the stopwatch.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .post(format!(
+                "repos/{owner}/{repo}/issues/{issue}/stopwatch/stop"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+impl DeleteStopwatchBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete the stopwatch.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .delete(format!(
+                "repos/{owner}/{repo}/issues/{issue}/stopwatch/delete"
+            ))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
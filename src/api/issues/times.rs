@@ -0,0 +1,338 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::issues::TrackedTime,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    timestamp::Timestamp,
+    GiteaHttp,
+};
+
+/// The [Times] struct provides methods for managing the time tracked on an issue.
+pub struct Times {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+    pub(crate) issue: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct AddTimeBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    #[serde(skip)]
+    #[skip]
+    issue: i64,
+
+    /// Time to add in seconds.
+    #[skip]
+    time: i64,
+    /// Date the time was spent at. Defaults to now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created: Option<Timestamp>,
+    /// User who spent the time. Defaults to the authenticated user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "user_name")]
+    user: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTimesBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    #[serde(skip)]
+    #[skip]
+    issue: i64,
+
+    /// Only show times tracked by the given user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+    /// Only show times updated after the given time. This is a timestamp in RFC 3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<Timestamp>,
+    /// Only show times updated before the given time. This is a timestamp in RFC 3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<Timestamp>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteTimeBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResetTimesBuilder {
+    owner: String,
+    repo: String,
+    issue: i64,
+    timeout: Option<Duration>,
+}
+
+impl Times {
+    /// Add tracked time to an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_time() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let time = client
+    ///     .issues("owner", "repo")
+    ///     .times(1)
+    ///     .add(3600)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will add one hour of tracked time to the issue #1 in the repository "owner/repo".
+    pub fn add(&self, seconds: i64) -> AddTimeBuilder {
+        AddTimeBuilder::new(&self.owner, &self.repo, self.issue, seconds)
+    }
+
+    /// List the times tracked on an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_times() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let times = client
+    ///     .issues("owner", "repo")
+    ///     .times(1)
+    ///     .list()
+    ///     .user("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will list the times "username" tracked on the issue #1 in the repository
+    /// "owner/repo".
+    pub fn list(&self) -> ListTimesBuilder {
+        ListTimesBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+
+    /// Delete a single tracked time from an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_time() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .times(1)
+    ///     .delete(2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> DeleteTimeBuilder {
+        DeleteTimeBuilder::new(&self.owner, &self.repo, self.issue, id)
+    }
+
+    /// Reset the time the authenticated user tracked on an issue.
+    /// WARNING: This will delete all of the user's tracked times on the issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn reset_times() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .times(1)
+    ///     .reset()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn reset(&self) -> ResetTimesBuilder {
+        ResetTimesBuilder::new(&self.owner, &self.repo, self.issue)
+    }
+}
+
+impl AddTimeBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, time: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            time,
+            created: None,
+            user: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add tracked time to an issue.
+    /// This will return the created [TrackedTime].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<TrackedTime> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(TrackedTime);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<TrackedTime>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let req = client
+            .post(format!("repos/{owner}/{repo}/issues/{issue}/times"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+impl ListTimesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            user: None,
+            since: None,
+            before: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list an issue's tracked times.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<TrackedTime>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<TrackedTime>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<TrackedTime>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let issue = self.issue;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/issues/{issue}/times"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListTimesBuilder, TrackedTime);
+
+impl DeleteTimeBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete a tracked time.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            repo,
+            issue,
+            id,
+            ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/issues/{issue}/times/{id}"))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+impl ResetTimesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to reset the tracked times.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, issue, ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/issues/{issue}/times"))
+            .build()?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -1,19 +1,40 @@
+use std::time::Duration;
+
 use crate::error::Result;
 use crate::model::orgs::Organization;
+use crate::response::ApiResponse;
+use crate::GiteaHttp;
 use build_it::Builder;
 use serde::Serialize;
 
 #[derive(Default, Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
-pub struct ListOrgsBuilder {}
+pub struct ListOrgsBuilder {
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
 
 impl ListOrgsBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Organization>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Organization>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Organization>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Organization>>> {
         let req = client.get("orgs").build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,7 +1,10 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{model::repos::Repository, Result};
+use crate::GiteaHttp;
+use crate::{model::repos::Repository, response::ApiResponse, secret::SecretString, Result};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Builder)]
 #[build_it(into)]
@@ -11,8 +14,8 @@ pub struct MigrateRepoBuilder {
     clone_addr: String,
     #[skip]
     repo_name: String,
-    auth_password: Option<String>,
-    auth_token: Option<String>,
+    auth_password: Option<SecretString>,
+    auth_token: Option<SecretString>,
     auth_username: Option<String>,
     description: Option<String>,
     issues: Option<bool>,
@@ -28,6 +31,9 @@ pub struct MigrateRepoBuilder {
     repo_owner: Option<String>,
     service: Option<String>,
     wiki: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl MigrateRepoBuilder {
@@ -52,13 +58,27 @@ impl MigrateRepoBuilder {
             repo_owner: None,
             service: None,
             wiki: None,
+            timeout: None,
         }
     }
 
     /// Send the request to migrate a repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
         let req = client.post("repos/migrate").json(&self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
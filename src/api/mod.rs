@@ -1,11 +1,17 @@
+pub mod actions;
 pub mod admin;
 pub mod avatar;
+pub mod hooks;
 pub mod issues;
 pub mod list;
 pub mod migrate;
+pub mod notifications;
 pub mod orgs;
+pub mod packages;
 pub mod pulls;
 pub mod repos;
 pub mod search;
+pub mod signing_key;
+pub mod templates;
 pub mod user;
 pub mod users;
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use crate::{
+    error::Result, model::notifications::NotificationCount, response::ApiResponse, GiteaHttp,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct NotificationCountBuilder {
+    timeout: Option<Duration>,
+}
+
+impl NotificationCountBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to get the number of unread notifications.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<NotificationCount> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(NotificationCount);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<NotificationCount>> {
+        let req = client.get("notifications/new").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,55 @@
+pub mod count;
+pub mod thread;
+
+/// Notifications of the authenticated user.
+pub struct Notifications;
+
+impl Notifications {
+    /// Access a single notification thread.
+    /// See [thread::Thread] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn notification_thread() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let thread = client
+    ///     .notifications()
+    ///     .thread(1)
+    ///     .get()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn thread(&self, id: i64) -> thread::Thread {
+        thread::Thread { id }
+    }
+
+    /// Gets the number of unread notifications of the authenticated user.
+    /// This is a cheap request, suitable for polling.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn notification_count() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let count = client
+    ///     .notifications()
+    ///     .count()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// println!("{} unread notifications", count.new);
+    /// # }
+    /// ```
+    pub fn count(&self) -> count::NotificationCountBuilder {
+        count::NotificationCountBuilder::new()
+    }
+}
@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::notifications::{NotificationStatus, NotificationThread},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+pub struct Thread {
+    pub(crate) id: i64,
+}
+
+impl Thread {
+    /// Gets the notification thread.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_thread() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let thread = client
+    ///     .notifications()
+    ///     .thread(1)
+    ///     .get()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self) -> GetThreadBuilder {
+        GetThreadBuilder::new(self.id)
+    }
+
+    /// Marks the notification thread as read.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn mark_thread_read() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let thread = client
+    ///     .notifications()
+    ///     .thread(1)
+    ///     .mark_read()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn mark_read(&self) -> MarkThreadBuilder {
+        MarkThreadBuilder::new(self.id, NotificationStatus::Read)
+    }
+
+    /// Marks the notification thread as unread.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn mark_thread_unread() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let thread = client
+    ///     .notifications()
+    ///     .thread(1)
+    ///     .mark_unread()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn mark_unread(&self) -> MarkThreadBuilder {
+        MarkThreadBuilder::new(self.id, NotificationStatus::Unread)
+    }
+
+    /// Pins the notification thread.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn mark_thread_pinned() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let thread = client
+    ///     .notifications()
+    ///     .thread(1)
+    ///     .mark_pinned()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn mark_pinned(&self) -> MarkThreadBuilder {
+        MarkThreadBuilder::new(self.id, NotificationStatus::Pinned)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetThreadBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl GetThreadBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+
+    /// Sends the request to get the notification thread.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<NotificationThread> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(NotificationThread);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<NotificationThread>> {
+        let id = self.id;
+        let req = client.get(format!("notifications/threads/{id}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MarkThreadBuilder {
+    #[serde(skip)]
+    id: i64,
+    #[serde(rename = "to-status")]
+    to_status: NotificationStatus,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl MarkThreadBuilder {
+    pub fn new(id: i64, to_status: NotificationStatus) -> Self {
+        Self {
+            id,
+            to_status,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to change the status of the notification thread.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<NotificationThread> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(NotificationThread);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<NotificationThread>> {
+        let id = self.id;
+        let req = client
+            .patch(format!("notifications/threads/{id}"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
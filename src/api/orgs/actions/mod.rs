@@ -0,0 +1,82 @@
+pub mod secrets;
+
+use crate::api::actions::{jobs::ListJobsBuilder, runs::ListRunsBuilder};
+
+pub struct OrgActions {
+    pub(crate) org: String,
+}
+
+impl OrgActions {
+    /// Manage the Actions secrets of the organization.
+    /// See [secrets::OrgSecrets] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn org_secrets() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let secrets = client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .secrets()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn secrets(&self) -> secrets::OrgSecrets {
+        secrets::OrgSecrets {
+            org: self.org.clone(),
+        }
+    }
+
+    /// Lists the workflow runs of all repositories of the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_org_runs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let runs = client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .runs()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn runs(&self) -> ListRunsBuilder {
+        ListRunsBuilder::new(format!("orgs/{}/actions/runs", self.org))
+    }
+
+    /// Lists the jobs of all repositories of the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_org_jobs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let jobs = client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .jobs()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn jobs(&self) -> ListJobsBuilder {
+        ListJobsBuilder::new(format!("orgs/{}/actions/jobs", self.org))
+    }
+}
@@ -0,0 +1,210 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::actions::Secret,
+    pagination::{impl_pageable, Paged},
+    secret::SecretString,
+    GiteaHttp,
+};
+
+pub struct OrgSecrets {
+    pub(crate) org: String,
+}
+
+impl OrgSecrets {
+    /// List the Actions secrets of the organization.
+    /// Only the names are returned, the values can not be read back.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_secrets() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let secrets = client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .secrets()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListSecretsBuilder {
+        ListSecretsBuilder::new(&self.org)
+    }
+
+    /// Create or update an Actions secret of the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn put_secret() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .secrets()
+    ///     .put("DEPLOY_TOKEN", "s3cr3t")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will set the secret "DEPLOY_TOKEN" of the organization "org-name".
+    pub fn put(&self, name: impl ToString, value: impl ToString) -> PutSecretBuilder {
+        PutSecretBuilder::new(&self.org, name, value)
+    }
+
+    /// Delete an Actions secret of the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_secret() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .secrets()
+    ///     .delete("DEPLOY_TOKEN")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, name: impl ToString) -> DeleteSecretBuilder {
+        DeleteSecretBuilder::new(&self.org, name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListSecretsBuilder {
+    #[serde(skip)]
+    #[skip]
+    org: String,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListSecretsBuilder {
+    pub fn new(org: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to list an organization's Actions secrets.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Secret>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Secret>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Secret>> {
+        let org = &self.org;
+        let req = client
+            .get(format!("orgs/{org}/actions/secrets"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListSecretsBuilder, Secret);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PutSecretBuilder {
+    #[serde(skip)]
+    org: String,
+    #[serde(skip)]
+    name: String,
+    /// The value of the secret
+    data: SecretString,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl PutSecretBuilder {
+    pub fn new(org: impl ToString, name: impl ToString, value: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            name: name.to_string(),
+            data: value.to_string().into(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to create or update an organization's Actions secret.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { org, name, .. } = self;
+        let req = client
+            .put(format!("orgs/{org}/actions/secrets/{name}"))
+            .json(self)
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteSecretBuilder {
+    org: String,
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl DeleteSecretBuilder {
+    pub fn new(org: impl ToString, name: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to delete an organization's Actions secret.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { org, name, .. } = self;
+        let req = client
+            .delete(format!("orgs/{org}/actions/secrets/{name}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
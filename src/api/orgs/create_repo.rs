@@ -1,10 +1,13 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
 use crate::{
     error::Result,
     model::repos::{ObjectFormatName, Repository, TrustModel},
-    Client,
+    response::ApiResponse,
+    GiteaHttp,
 };
 
 /// Represents the options for creating a new repository.
@@ -45,6 +48,9 @@ pub struct CreateRepoBuilder {
     template: Option<bool>,
     /// Trust model for verifying commits in the repository.
     trust_model: Option<TrustModel>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateRepoBuilder {
@@ -63,14 +69,28 @@ impl CreateRepoBuilder {
             readme: None,
             template: None,
             trust_model: None,
+            timeout: None,
         }
     }
     /// Send the request to create the repository.
     /// This will return the created [Repository].
-    pub async fn send(&self, client: &Client) -> Result<Repository> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
         let org = &self.org;
         let req = client.post(format!("orgs/{org}/repos")).json(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
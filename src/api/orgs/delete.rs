@@ -1,17 +1,25 @@
+use std::time::Duration;
+
 pub struct DeleteOrgBuilder {
     name: String,
+    timeout: Option<Duration>,
 }
 
 impl DeleteOrgBuilder {
     pub fn new(name: impl ToString) -> Self {
         Self {
             name: name.to_string(),
+            timeout: None,
         }
     }
     /// Send the request to delete an [Organization](crate::model::orgs::Organization).
-    pub async fn send(&self, client: &crate::Client) -> crate::error::Result<()> {
+    pub async fn send(&self, client: &impl crate::GiteaHttp) -> crate::error::Result<()> {
         let req = client.delete(format!("orgs/{}", self.name)).build()?;
-        client.make_request(req).await?;
+        client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
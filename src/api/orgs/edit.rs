@@ -1,10 +1,13 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Result,
     model::orgs::{Organization, Visibility},
-    Client,
+    response::ApiResponse,
+    GiteaHttp,
 };
 
 #[derive(Debug, Serialize, Clone, Deserialize, Builder)]
@@ -20,6 +23,9 @@ pub struct EditOrgBuilder {
     pub repo_admin_change_team_access: Option<bool>,
     pub visibility: Option<Visibility>,
     pub website: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditOrgBuilder {
@@ -33,14 +39,28 @@ impl EditOrgBuilder {
             repo_admin_change_team_access: None,
             visibility: None,
             website: None,
+            timeout: None,
         }
     }
-    pub async fn send(&self, client: &Client) -> Result<Organization> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Organization> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Organization);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Organization>> {
         let req = client
             .patch(format!("orgs/{}", self.name))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
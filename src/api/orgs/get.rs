@@ -1,19 +1,36 @@
-use crate::{error::Result, model::orgs::Organization, Client};
+use std::time::Duration;
+
+use crate::{error::Result, model::orgs::Organization, response::ApiResponse, GiteaHttp};
 
 pub struct GetOrgBuilder {
     name: String,
+    timeout: Option<Duration>,
 }
 
 impl GetOrgBuilder {
     pub fn new(name: impl ToString) -> Self {
         Self {
             name: name.to_string(),
+            timeout: None,
         }
     }
     /// Send the request to get an [Organization].
-    pub async fn send(&self, client: &Client) -> Result<Organization> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Organization> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Organization);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Organization>> {
         let req = client.get(format!("orgs/{}", self.name)).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
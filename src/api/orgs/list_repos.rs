@@ -1,7 +1,14 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result,
+    model::repos::Repository,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 #[build_it(into)]
@@ -13,6 +20,9 @@ pub struct ListReposBuilder {
     page: Option<i64>,
     /// Page size of results.
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListReposBuilder {
@@ -21,15 +31,28 @@ impl ListReposBuilder {
             org: org.to_string(),
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Sends the request to list an organization's repositories.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
         let req = client
             .get(format!("/orgs/{}/repos", self.org))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(ListReposBuilder, Repository);
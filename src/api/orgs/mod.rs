@@ -1,3 +1,4 @@
+pub mod actions;
 pub mod create;
 pub mod create_repo;
 pub mod delete;
@@ -326,4 +327,56 @@ impl Orgs {
     ) -> public_members::PublicizeMembershipBuilder {
         public_members::PublicizeMembershipBuilder::new(self.name.clone(), username)
     }
+
+    /// Manage the [Teams](crate::model::team::Team) of an
+    /// [Organization](crate::model::orgs::Organization).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn teams(&self) -> teams::Teams {
+        teams::Teams {
+            org: self.name.clone(),
+        }
+    }
+
+    /// Manage the Actions settings of an [Organization](crate::model::orgs::Organization).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn org_actions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .actions()
+    ///     .secrets()
+    ///     .delete("DEPLOY_TOKEN")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn actions(&self) -> actions::OrgActions {
+        actions::OrgActions {
+            org: self.name.clone(),
+        }
+    }
 }
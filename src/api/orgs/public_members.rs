@@ -1,8 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use reqwest::StatusCode;
 use serde::Serialize;
 
-use crate::{error::Result, model::user::User, Client};
+use crate::{
+    error::Result,
+    model::user::User,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 #[build_it(into)]
@@ -14,24 +21,30 @@ pub struct ListPublicMembersBuilder {
     page: Option<i64>,
     /// Page size of results.
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
 pub struct IsPublicMemberBuilder {
     org: String,
     username: String,
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ConcealMembershipBuilder {
     org: String,
     username: String,
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
 pub struct PublicizeMembershipBuilder {
     org: String,
     username: String,
+    timeout: Option<Duration>,
 }
 
 impl ListPublicMembersBuilder {
@@ -40,34 +53,48 @@ impl ListPublicMembersBuilder {
             org: org.to_string(),
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Sends the request to list an organization's public members.
     /// This will return a list of [User] objects.
-    pub async fn send(&self, client: &Client) -> Result<Vec<User>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<User>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<User>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<User>> {
         let req = client
             .get(format!("/orgs/{}/public_members", self.org))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
 
+impl_pageable!(ListPublicMembersBuilder, User);
+
 impl IsPublicMemberBuilder {
     pub fn new(org: impl ToString, username: impl ToString) -> Self {
         Self {
             org: org.to_string(),
             username: username.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to check if a user is a public member of an organization.
-    pub async fn send(&self, client: &Client) -> Result<bool> {
-        let Self { org, username } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<bool> {
+        let Self { org, username, .. } = self;
         let req = client
             .get(format!("/orgs/{org}/public_members/{username}"))
             .build()?;
-        match client.make_request(req).await {
+        match client.make_request(self.with_timeout(req)).await {
             Ok(_) => Ok(true),
             Err(e) => {
                 if e.status_code == StatusCode::NOT_FOUND {
@@ -78,6 +105,10 @@ impl IsPublicMemberBuilder {
             }
         }
     }
+
+    crate::transport::send_blocking!(bool);
+
+    crate::transport::request_timeout!();
 }
 
 impl ConcealMembershipBuilder {
@@ -85,17 +116,22 @@ impl ConcealMembershipBuilder {
         Self {
             org: org.to_string(),
             username: username.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to conceal a user's membership in an organization.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { org, username } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { org, username, .. } = self;
         let req = client
             .delete(format!("/orgs/{org}/public_members/{username}"))
             .build()?;
-        let _ = client.make_request(req).await?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
 
 impl PublicizeMembershipBuilder {
@@ -103,15 +139,20 @@ impl PublicizeMembershipBuilder {
         Self {
             org: org.to_string(),
             username: username.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to publicize a user's membership in an organization.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { org, username } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { org, username, .. } = self;
         let req = client
             .put(format!("/orgs/{org}/public_members/{username}"))
             .build()?;
-        let _ = client.make_request(req).await?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
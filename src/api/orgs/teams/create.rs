@@ -1,9 +1,15 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
 
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::team::Team, Client};
+use crate::{
+    error::Result,
+    model::team::{Permission, Team},
+    response::ApiResponse,
+    GiteaHttp,
+};
 
 /// Represents the options for creating a new user.
 /// The only required field is `email` and `username`.
@@ -21,7 +27,7 @@ pub struct CreateTeamBuilder {
     /// Description of the label
     pub description: Option<String>,
     /// Permissions of team
-    pub permission: Option<String>,
+    pub permission: Option<Permission>,
     /// Permission Units of the Team
     pub units: Option<Vec<String>>,
     /// Permission Units of the Team
@@ -30,6 +36,9 @@ pub struct CreateTeamBuilder {
     pub includes_all_repositories: Option<bool>,
     /// Whether team is allowed to create repos
     pub can_create_org_repo: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateTeamBuilder {
@@ -43,18 +52,29 @@ impl CreateTeamBuilder {
             units: None,
             units_map: None,
             can_create_org_repo: None,
+            timeout: None,
         }
     }
 
     /// Send the request to create the Team.
     /// This will return the created [Team].
-    pub async fn send(&self, client: &Client) -> Result<Team> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Team> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Team);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Team>> {
         let org = &self.org;
         let req = client
             .post(format!("orgs/{org}/teams"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
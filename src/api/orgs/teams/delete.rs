@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct DeleteTeamBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteTeamBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+    /// Sends the request to delete a team.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let id = self.id;
+        let req = client.delete(format!("teams/{id}")).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
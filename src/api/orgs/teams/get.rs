@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use crate::{error::Result, model::team::Team, response::ApiResponse, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct GetTeamBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl GetTeamBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+    /// Sends the request to get a team.
+    /// This will return the [Team].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Team> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Team);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Team>> {
+        let id = self.id;
+        let req = client.get(format!("teams/{id}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
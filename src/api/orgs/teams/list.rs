@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Result,
+    model::team::Team,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamsBuilder {
+    #[serde(skip)]
+    #[skip]
+    org: String,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListTeamsBuilder {
+    pub fn new(org: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to list an organization's teams.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Team>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Team>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Team>> {
+        let org = &self.org;
+        let req = client
+            .get(format!("orgs/{org}/teams"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListTeamsBuilder, Team);
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SearchTeamsBuilder {
+    #[serde(skip)]
+    #[skip]
+    org: String,
+    /// Keyword to search for
+    #[serde(rename = "q")]
+    #[skip]
+    query: String,
+
+    /// Whether to also search in the team descriptions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_desc: Option<bool>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl SearchTeamsBuilder {
+    pub fn new(org: impl ToString, query: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            query: query.to_string(),
+            include_desc: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to search an organization's teams.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Team>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Team>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Team>> {
+        let org = &self.org;
+        let req = client
+            .get(format!("orgs/{org}/teams/search"))
+            .query(self)
+            .build()?;
+        #[derive(Deserialize)]
+        struct Response {
+            #[allow(dead_code)]
+            ok: bool,
+            data: Vec<Team>,
+        }
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let items = client.parse_response::<Response>(res).await?.data;
+        Ok(Paged::new(items, &headers))
+    }
+}
+
+impl_pageable!(SearchTeamsBuilder, Team);
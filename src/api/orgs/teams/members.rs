@@ -0,0 +1,326 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::User,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+pub struct TeamMembers {
+    pub(crate) id: i64,
+}
+
+impl TeamMembers {
+    /// List the members of a team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_team_members() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let members = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .members(1)
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListTeamMembersBuilder {
+        ListTeamMembersBuilder::new(self.id)
+    }
+
+    /// Get a member of a team.
+    /// This will return an error if the user is not a member of the team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let member = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .members(1)
+    ///     .get("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, username: impl ToString) -> GetTeamMemberBuilder {
+        GetTeamMemberBuilder::new(self.id, username)
+    }
+
+    /// Check if a user is a member of a team.
+    /// Use [TeamMembers::get] to fetch the member itself.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let is_member = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .members(1)
+    ///     .is_member("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_member(&self, username: impl ToString) -> IsTeamMemberBuilder {
+        IsTeamMemberBuilder::new(self.id, username)
+    }
+
+    /// Add a user to a team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .members(1)
+    ///     .add("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add(&self, username: impl ToString) -> AddTeamMemberBuilder {
+        AddTeamMemberBuilder::new(self.id, username)
+    }
+
+    /// Remove a user from a team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .members(1)
+    ///     .remove("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove(&self, username: impl ToString) -> RemoveTeamMemberBuilder {
+        RemoveTeamMemberBuilder::new(self.id, username)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamMembersBuilder {
+    #[serde(skip)]
+    #[skip]
+    id: i64,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListTeamMembersBuilder {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to list a team's members.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<User>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<User>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<User>> {
+        let id = self.id;
+        let req = client
+            .get(format!("teams/{id}/members"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListTeamMembersBuilder, User);
+
+#[derive(Debug, Clone)]
+pub struct GetTeamMemberBuilder {
+    id: i64,
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl GetTeamMemberBuilder {
+    pub fn new(id: i64, username: impl ToString) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to get a member of a team.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<User> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(User);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<User>> {
+        let Self { id, username, .. } = self;
+        let req = client
+            .get(format!("teams/{id}/members/{username}"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IsTeamMemberBuilder {
+    id: i64,
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl IsTeamMemberBuilder {
+    pub fn new(id: i64, username: impl ToString) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to check if a user is a member of a team.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<bool> {
+        let Self { id, username, .. } = self;
+        let req = client
+            .get(format!("teams/{id}/members/{username}"))
+            .build()?;
+        match client.make_request(self.with_timeout(req)).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.status_code == StatusCode::NOT_FOUND {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    crate::transport::send_blocking!(bool);
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct AddTeamMemberBuilder {
+    id: i64,
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl AddTeamMemberBuilder {
+    pub fn new(id: i64, username: impl ToString) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to add a user to a team.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { id, username, .. } = self;
+        let req = client
+            .put(format!("teams/{id}/members/{username}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveTeamMemberBuilder {
+    id: i64,
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl RemoveTeamMemberBuilder {
+    pub fn new(id: i64, username: impl ToString) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to remove a user from a team.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { id, username, .. } = self;
+        let req = client
+            .delete(format!("teams/{id}/members/{username}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -1,2 +1,237 @@
 pub mod create;
+pub mod delete;
 pub mod edit;
+pub mod get;
+pub mod list;
+pub mod members;
+pub mod repos;
+
+pub struct Teams {
+    pub(crate) org: String,
+}
+
+impl Teams {
+    /// Create a [Team](crate::model::team::Team) in the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .create("team-name")
+    ///     .description("My team")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will create a team called "team-name" in the organization "org-name".
+    pub fn create(&self, name: impl ToString) -> create::CreateTeamBuilder {
+        create::CreateTeamBuilder::new(&self.org, name)
+    }
+
+    /// List the [Teams](crate::model::team::Team) of the organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will list all teams in the organization "org-name".
+    pub fn list(&self) -> list::ListTeamsBuilder {
+        list::ListTeamsBuilder::new(&self.org)
+    }
+
+    /// Search the [Teams](crate::model::team::Team) of the organization by name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn search_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .search("dev")
+    ///     .include_desc(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will search for teams matching "dev" in their name or description.
+    pub fn search(&self, query: impl ToString) -> list::SearchTeamsBuilder {
+        list::SearchTeamsBuilder::new(&self.org, query)
+    }
+
+    /// Get a [Team](crate::model::team::Team) by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the team with the ID 1.
+    pub fn get(&self, id: i64) -> get::GetTeamBuilder {
+        get::GetTeamBuilder::new(id)
+    }
+
+    /// Edit a [Team](crate::model::team::Team).
+    /// The name is always required, all other fields are optional.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::team::Permission};
+    /// # async fn edit_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .edit(1, "new-name")
+    ///     .permission(Permission::Write)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will rename the team with the ID 1 to "new-name" and grant it write access.
+    pub fn edit(&self, id: i64, name: impl ToString) -> edit::EditTeamBuilder {
+        edit::EditTeamBuilder::new(id, name)
+    }
+
+    /// Delete a [Team](crate::model::team::Team).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will delete the team with the ID 1.
+    pub fn delete(&self, id: i64) -> delete::DeleteTeamBuilder {
+        delete::DeleteTeamBuilder::new(id)
+    }
+
+    /// Manage the members of a [Team](crate::model::team::Team).
+    /// See [members::TeamMembers] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn team_members() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .members(1)
+    ///     .add("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will add the user "username" to the team with the ID 1.
+    pub fn members(&self, id: i64) -> members::TeamMembers {
+        members::TeamMembers { id }
+    }
+
+    /// Check if a user is a member of a [Team](crate::model::team::Team).
+    /// This is a shorthand for [members::TeamMembers::is_member].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let is_member = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .is_member(1, "username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_member(&self, id: i64, username: impl ToString) -> members::IsTeamMemberBuilder {
+        members::IsTeamMemberBuilder::new(id, username)
+    }
+
+    /// Manage the repositories a [Team](crate::model::team::Team) has access to.
+    /// See [repos::TeamRepos] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn team_repos() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .repos(1)
+    ///     .add("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will grant the team with the ID 1 access to the repository "org-name/repo".
+    pub fn repos(&self, id: i64) -> repos::TeamRepos {
+        repos::TeamRepos { id }
+    }
+}
@@ -0,0 +1,278 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::repos::Repository,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+pub struct TeamRepos {
+    pub(crate) id: i64,
+}
+
+impl TeamRepos {
+    /// List the repositories of a team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_team_repos() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let repos = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .repos(1)
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListTeamReposBuilder {
+        ListTeamReposBuilder::new(self.id)
+    }
+
+    /// Get a repository of a team.
+    /// This will return an error if the team has no access to the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_team_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let repo = client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .repos(1)
+    ///     .get("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, owner: impl ToString, repo: impl ToString) -> GetTeamRepoBuilder {
+        GetTeamRepoBuilder::new(self.id, owner, repo)
+    }
+
+    /// Grant a team access to a repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_team_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .repos(1)
+    ///     .add("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add(&self, owner: impl ToString, repo: impl ToString) -> AddTeamRepoBuilder {
+        AddTeamRepoBuilder::new(self.id, owner, repo)
+    }
+
+    /// Revoke a team's access to a repository.
+    /// This does not delete the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_team_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .orgs("org-name")
+    ///     .teams()
+    ///     .repos(1)
+    ///     .remove("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove(&self, owner: impl ToString, repo: impl ToString) -> RemoveTeamRepoBuilder {
+        RemoveTeamRepoBuilder::new(self.id, owner, repo)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamReposBuilder {
+    #[serde(skip)]
+    #[skip]
+    id: i64,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListTeamReposBuilder {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to list a team's repositories.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
+        let id = self.id;
+        let req = client
+            .get(format!("teams/{id}/repos"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListTeamReposBuilder, Repository);
+
+#[derive(Debug, Clone)]
+pub struct GetTeamRepoBuilder {
+    id: i64,
+    owner: String,
+    repo: String,
+    timeout: Option<Duration>,
+}
+
+impl GetTeamRepoBuilder {
+    pub fn new(id: i64, owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            id,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to get a repository of a team.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
+        let Self {
+            id, owner, repo, ..
+        } = self;
+        let req = client
+            .get(format!("teams/{id}/repos/{owner}/{repo}"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddTeamRepoBuilder {
+    id: i64,
+    owner: String,
+    repo: String,
+    timeout: Option<Duration>,
+}
+
+impl AddTeamRepoBuilder {
+    pub fn new(id: i64, owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            id,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to grant a team access to a repository.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            id, owner, repo, ..
+        } = self;
+        let req = client
+            .put(format!("teams/{id}/repos/{owner}/{repo}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveTeamRepoBuilder {
+    id: i64,
+    owner: String,
+    repo: String,
+    timeout: Option<Duration>,
+}
+
+impl RemoveTeamRepoBuilder {
+    pub fn new(id: i64, owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            id,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to revoke a team's access to a repository.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            id, owner, repo, ..
+        } = self;
+        let req = client
+            .delete(format!("teams/{id}/repos/{owner}/{repo}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
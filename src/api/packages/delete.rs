@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use crate::{error::Result, model::packages::PackageType, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct DeletePackageBuilder {
+    owner: String,
+    package_type: PackageType,
+    name: String,
+    version: String,
+    timeout: Option<Duration>,
+}
+
+impl DeletePackageBuilder {
+    pub fn new(
+        owner: impl ToString,
+        package_type: PackageType,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            package_type,
+            name: name.to_string(),
+            version: version.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete the package version.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            package_type,
+            name,
+            version,
+            ..
+        } = self;
+        let req = client
+            .delete(format!("packages/{owner}/{package_type}/{name}/{version}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use crate::{
+    error::Result,
+    model::packages::{PackageFile, PackageType},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListPackageFilesBuilder {
+    owner: String,
+    package_type: PackageType,
+    name: String,
+    version: String,
+    timeout: Option<Duration>,
+}
+
+impl ListPackageFilesBuilder {
+    pub fn new(
+        owner: impl ToString,
+        package_type: PackageType,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            package_type,
+            name: name.to_string(),
+            version: version.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the files of the package version.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<PackageFile>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<PackageFile>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<PackageFile>>> {
+        let Self {
+            owner,
+            package_type,
+            name,
+            version,
+            ..
+        } = self;
+        let req = client
+            .get(format!(
+                "packages/{owner}/{package_type}/{name}/{version}/files"
+            ))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
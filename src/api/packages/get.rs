@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use crate::{
+    error::Result,
+    model::packages::{Package, PackageType},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetPackageBuilder {
+    owner: String,
+    package_type: PackageType,
+    name: String,
+    version: String,
+    timeout: Option<Duration>,
+}
+
+impl GetPackageBuilder {
+    pub fn new(
+        owner: impl ToString,
+        package_type: PackageType,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            package_type,
+            name: name.to_string(),
+            version: version.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the package version.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Package> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Package);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Package>> {
+        let Self {
+            owner,
+            package_type,
+            name,
+            version,
+            ..
+        } = self;
+        let req = client
+            .get(format!("packages/{owner}/{package_type}/{name}/{version}"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use crate::{error::Result, model::packages::PackageType, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct LinkPackageBuilder {
+    owner: String,
+    package_type: PackageType,
+    name: String,
+    repo: String,
+    timeout: Option<Duration>,
+}
+
+impl LinkPackageBuilder {
+    pub fn new(
+        owner: impl ToString,
+        package_type: PackageType,
+        name: impl ToString,
+        repo: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            package_type,
+            name: name.to_string(),
+            repo: repo.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to link the package to the repository.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            package_type,
+            name,
+            repo,
+            ..
+        } = self;
+        let req = client
+            .post(format!(
+                "packages/{owner}/{package_type}/{name}/-/link/{repo}"
+            ))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct UnlinkPackageBuilder {
+    owner: String,
+    package_type: PackageType,
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl UnlinkPackageBuilder {
+    pub fn new(owner: impl ToString, package_type: PackageType, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            package_type,
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to unlink the package from its repository.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            package_type,
+            name,
+            ..
+        } = self;
+        let req = client
+            .post(format!("packages/{owner}/{package_type}/{name}/-/unlink"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
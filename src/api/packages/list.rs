@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::packages::{Package, PackageType},
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListPackagesBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    /// Only list packages of this type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    package_type: Option<PackageType>,
+    /// Only list packages whose name matches this keyword.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListPackagesBuilder {
+    pub fn new(owner: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            package_type: None,
+            q: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the packages.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Package>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Package>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Package>> {
+        let owner = &self.owner;
+        let req = client
+            .get(format!("packages/{owner}"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListPackagesBuilder, Package);
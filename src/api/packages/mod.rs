@@ -0,0 +1,173 @@
+pub mod delete;
+pub mod files;
+pub mod get;
+pub mod link;
+pub mod list;
+
+use crate::model::packages::PackageType;
+
+/// Packages owned by a user or organization.
+pub struct Packages {
+    pub(crate) owner: String,
+}
+
+impl Packages {
+    /// Lists the packages of the owner.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::packages::PackageType};
+    /// # async fn list_packages() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let packages = client
+    ///     .packages("owner")
+    ///     .list()
+    ///     .package_type(PackageType::Cargo)
+    ///     .q("my-crate")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListPackagesBuilder {
+        list::ListPackagesBuilder::new(&self.owner)
+    }
+
+    /// Gets a specific version of a package.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::packages::PackageType};
+    /// # async fn get_package() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let package = client
+    ///     .packages("owner")
+    ///     .get(PackageType::Generic, "my-package", "1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(
+        &self,
+        package_type: PackageType,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> get::GetPackageBuilder {
+        get::GetPackageBuilder::new(&self.owner, package_type, name, version)
+    }
+
+    /// Deletes a specific version of a package.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::packages::PackageType};
+    /// # async fn delete_package() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .packages("owner")
+    ///     .delete(PackageType::Generic, "my-package", "1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(
+        &self,
+        package_type: PackageType,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> delete::DeletePackageBuilder {
+        delete::DeletePackageBuilder::new(&self.owner, package_type, name, version)
+    }
+
+    /// Lists the files of a specific version of a package.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::packages::PackageType};
+    /// # async fn list_package_files() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let files = client
+    ///     .packages("owner")
+    ///     .files(PackageType::Generic, "my-package", "1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn files(
+        &self,
+        package_type: PackageType,
+        name: impl ToString,
+        version: impl ToString,
+    ) -> files::ListPackageFilesBuilder {
+        files::ListPackageFilesBuilder::new(&self.owner, package_type, name, version)
+    }
+
+    /// Links a package to a repository of the owner.
+    /// All versions of the package are linked.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::packages::PackageType};
+    /// # async fn link_package() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .packages("owner")
+    ///     .link(PackageType::Generic, "my-package", "my-repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn link(
+        &self,
+        package_type: PackageType,
+        name: impl ToString,
+        repo: impl ToString,
+    ) -> link::LinkPackageBuilder {
+        link::LinkPackageBuilder::new(&self.owner, package_type, name, repo)
+    }
+
+    /// Unlinks a package from its repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::packages::PackageType};
+    /// # async fn unlink_package() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .packages("owner")
+    ///     .unlink(PackageType::Generic, "my-package")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn unlink(
+        &self,
+        package_type: PackageType,
+        name: impl ToString,
+    ) -> link::UnlinkPackageBuilder {
+        link::UnlinkPackageBuilder::new(&self.owner, package_type, name)
+    }
+}
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use reqwest::StatusCode;
+
+use crate::{
+    error::{Result, TeatimeError, TeatimeErrorKind},
+    model::{pulls::PullRequest, repos::Compare},
+    timestamp::Timestamp,
+    GiteaHttp,
+};
+
+use super::create::CreatePullRequestBuilder;
+
+/// Creates a pull request whose title and body are derived from the commits between `base`
+/// and `head`.
+/// The title is the subject of the oldest commit, the body lists the subjects of all remaining
+/// commits.
+#[derive(Debug, Clone, Builder)]
+#[build_it(into)]
+pub struct AutoFillPullRequestBuilder {
+    #[skip]
+    owner: String,
+    #[skip]
+    repo: String,
+    #[skip]
+    head: String,
+    #[skip]
+    base: String,
+
+    assignees: Option<Vec<String>>,
+    due_date: Option<Timestamp>,
+    labels: Option<Vec<i64>>,
+    milestone: Option<i64>,
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl AutoFillPullRequestBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        head: impl ToString,
+        base: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            head: head.to_string(),
+            base: base.to_string(),
+            assignees: None,
+            due_date: None,
+            labels: None,
+            milestone: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the requests to compare the branches and create the pull request.
+    /// This will return a [TeatimeError] if there are no commits between `base` and `head`.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullRequest> {
+        let Self {
+            owner,
+            repo,
+            head,
+            base,
+            ..
+        } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/compare/{base}...{head}"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let compare: Compare = client.parse_response(res).await?;
+
+        // Gitea returns the newest commit first
+        let mut subjects = compare
+            .commits
+            .iter()
+            .rev()
+            .map(|c| c.commit.message.lines().next().unwrap_or_default().trim());
+        let title = subjects.next().ok_or_else(|| TeatimeError {
+            message: format!("No commits between {base} and {head}"),
+            kind: TeatimeErrorKind::Other,
+            status_code: StatusCode::UNPROCESSABLE_ENTITY,
+            request_id: None,
+        })?;
+        let body = subjects
+            .map(|s| format!("- {s}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut create = CreatePullRequestBuilder::new(owner, repo, head, base, title);
+        if !body.is_empty() {
+            create = create.body(body);
+        }
+        if let Some(assignees) = &self.assignees {
+            create = create.assignees(assignees.clone());
+        }
+        if let Some(due_date) = &self.due_date {
+            create = create.due_date(due_date.to_owned());
+        }
+        if let Some(labels) = &self.labels {
+            create = create.labels(labels.clone());
+        }
+        if let Some(milestone) = self.milestone {
+            create = create.milestone(milestone);
+        }
+        if let Some(timeout) = self.timeout {
+            create = create.timeout(timeout);
+        }
+        create.send(client).await
+    }
+
+    crate::transport::send_blocking!(PullRequest);
+
+    crate::transport::request_timeout!();
+}
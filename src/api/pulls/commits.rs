@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::repos::Commit,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListPullRequestCommitsBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+
+    /// Page number of results to return (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    /// Whether to include the `verification` field in the response.
+    /// Disable to speed-up the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<bool>,
+    /// Whether to include the `files` field in the response.
+    /// Disable to speed-up the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListPullRequestCommitsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            page: None,
+            limit: None,
+            verification: None,
+            files: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to list the commits of a pull request.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Commit>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Commit>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Commit>> {
+        let Self {
+            owner, repo, id, ..
+        } = self;
+        let req = client
+            .get(format!("/repos/{owner}/{repo}/pulls/{id}/commits"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListPullRequestCommitsBuilder, Commit);
@@ -1,7 +1,12 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::pulls::PullRequest, Client};
+use crate::{
+    error::Result, model::pulls::PullRequest, response::ApiResponse, timestamp::Timestamp,
+    GiteaHttp,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -22,9 +27,12 @@ pub struct CreatePullRequestBuilder {
 
     assignees: Option<Vec<String>>,
     body: Option<String>,
-    due_date: Option<String>,
+    due_date: Option<Timestamp>,
     labels: Option<Vec<i64>>,
     milestone: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreatePullRequestBuilder {
@@ -46,18 +54,32 @@ impl CreatePullRequestBuilder {
             due_date: None,
             labels: None,
             milestone: None,
+            timeout: None,
         }
     }
 
     /// Sends the request to create a pull request
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullRequest> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullRequest);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullRequest>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .post(format!("repos/{owner}/{repo}/pulls",))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
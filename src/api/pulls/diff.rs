@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, GiteaHttp};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct GetPullRequestDiffBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// Either "diff" or "patch"
+    #[skip]
+    #[serde(skip)]
+    diff_type: &'static str,
+
+    /// Whether to include binary file changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    binary: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl GetPullRequestDiffBuilder {
+    pub(crate) fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        id: i64,
+        diff_type: &'static str,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            diff_type,
+            binary: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to get the diff or patch of a pull request.
+    /// This will return the raw text.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<String> {
+        let Self {
+            owner,
+            repo,
+            id,
+            diff_type,
+            ..
+        } = self;
+        let req = client
+            .get(format!("/repos/{owner}/{repo}/pulls/{id}.{diff_type}"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        Ok(res.text().await?)
+    }
+
+    crate::transport::send_blocking!(String);
+
+    crate::transport::request_timeout!();
+}
@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
 use crate::{
     error::Result,
     model::{issues::StateType, pulls::PullRequest},
-    Client,
+    response::ApiResponse,
+    timestamp::Timestamp,
+    GiteaHttp,
 };
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -24,12 +28,15 @@ pub struct EditPullRequestBuilder {
     assignees: Option<Vec<String>>,
     base: Option<String>,
     body: Option<String>,
-    due_date: Option<String>,
+    due_date: Option<Timestamp>,
     labels: Option<Vec<i64>>,
     milestone: Option<i64>,
     state: Option<StateType>,
     title: Option<String>,
     unset_due_date: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditPullRequestBuilder {
@@ -48,11 +55,25 @@ impl EditPullRequestBuilder {
             state: None,
             title: None,
             unset_due_date: None,
+            timeout: None,
         }
     }
 
     /// Sends the request to edit a pull request
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullRequest> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullRequest);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullRequest>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let id = self.id;
@@ -60,7 +81,7 @@ impl EditPullRequestBuilder {
             .patch(format!("repos/{owner}/{repo}/pulls/{id}"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::pulls::{ChangedFile, Whitespace},
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListPullRequestFilesBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+
+    /// Skip to the given file
+    #[serde(rename = "skip-to", skip_serializing_if = "Option::is_none")]
+    skip_to: Option<String>,
+    /// How whitespace changes are treated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whitespace: Option<Whitespace>,
+    /// Page number of results to return (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListPullRequestFilesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            skip_to: None,
+            whitespace: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to list the files changed by a pull request.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<ChangedFile>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<ChangedFile>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<ChangedFile>> {
+        let Self {
+            owner, repo, id, ..
+        } = self;
+        let req = client
+            .get(format!("/repos/{owner}/{repo}/pulls/{id}/files"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListPullRequestFilesBuilder, ChangedFile);
@@ -1,10 +1,13 @@
-use crate::{error::Result, model::pulls::PullRequest, Client};
+use std::time::Duration;
+
+use crate::{error::Result, model::pulls::PullRequest, response::ApiResponse, GiteaHttp};
 
 #[derive(Debug, Clone)]
 pub struct GetPullRequestByIdBuilder {
     owner: String,
     repo: String,
     id: i64,
+    timeout: Option<Duration>,
 }
 
 impl GetPullRequestByIdBuilder {
@@ -13,16 +16,32 @@ impl GetPullRequestByIdBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             id,
+            timeout: None,
         }
     }
     /// Sends the request to get a pull request by its ID.
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
-        let Self { owner, repo, id } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullRequest> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullRequest);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullRequest>> {
+        let Self {
+            owner, repo, id, ..
+        } = self;
         let req = client
             .get(format!("/repos/{owner}/{repo}/pulls/{id}"))
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
 
@@ -32,6 +51,7 @@ pub struct GetPullRequestByBranchesBuilder {
     repo: String,
     head: String,
     base: String,
+    timeout: Option<Duration>,
 }
 
 impl GetPullRequestByBranchesBuilder {
@@ -46,20 +66,35 @@ impl GetPullRequestByBranchesBuilder {
             repo: repo.to_string(),
             head: head.to_string(),
             base: base.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to get a pull request by its head and base branches.
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullRequest> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullRequest);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullRequest>> {
         let Self {
             owner,
             repo,
             head,
             base,
+            ..
         } = self;
         let req = client
             .get(format!("/repos/{owner}/{repo}/pulls/{base}/{head}"))
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
@@ -7,7 +9,8 @@ use crate::{
         issues::State,
         pulls::{PullRequest, Sort},
     },
-    Client,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
 };
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -29,6 +32,9 @@ pub struct ListPullRequestsBuilder {
     page: Option<i64>,
     /// Page size of results
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListPullRequestsBuilder {
@@ -42,17 +48,30 @@ impl ListPullRequestsBuilder {
             labels: None,
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Sends the request to list pull requests.
-    pub async fn send(&self, client: &Client) -> Result<Vec<PullRequest>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<PullRequest>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<PullRequest>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<PullRequest>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .get(format!("/repos/{owner}/{repo}/pulls"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(ListPullRequestsBuilder, PullRequest);
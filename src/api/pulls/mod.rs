@@ -1,5 +1,9 @@
+pub mod autofill;
+pub mod commits;
 pub mod create;
+pub mod diff;
 pub mod edit;
+pub mod files;
 pub mod get;
 pub mod list;
 pub mod pinned;
@@ -41,6 +45,38 @@ impl Pulls {
         create::CreatePullRequestBuilder::new(&self.owner, &self.repo, head, base, title)
     }
 
+    /// Create a [Pull Request](crate::model::pulls::PullRequest) with a title and body derived
+    /// from the commits between `base` and `head`.
+    /// The title will be the subject of the first commit, and the body will list the subjects
+    /// of all remaining commits.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn autofill_pr() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let pr = client
+    ///     .pulls("owner", "repo")
+    ///     .create_from_commits("my-branch", "main")
+    ///     .labels(vec![1])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will create a pull request from the branch "my-branch" to the branch "main" in the
+    /// repository "owner/repo", using the commit messages as title and body.
+    pub fn create_from_commits(
+        &self,
+        head: impl ToString,
+        base: impl ToString,
+    ) -> autofill::AutoFillPullRequestBuilder {
+        autofill::AutoFillPullRequestBuilder::new(&self.owner, &self.repo, head, base)
+    }
+
     /// Edit a [Pull Request](crate::model::pulls::PullRequest) in a repository.
     ///
     /// # Example
@@ -166,6 +202,103 @@ impl Pulls {
         pinned::PinnedPullRequestsBuilder::new(&self.owner, &self.repo)
     }
 
+    /// List the [Commits](crate::model::repos::Commit) of a pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_commits() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let commits = client
+    ///     .pulls("owner", "repo")
+    ///     .commits(1)
+    ///     .files(false)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the commits of the pull request with the ID 1 in the repository "owner/repo".
+    pub fn commits(&self, id: i64) -> commits::ListPullRequestCommitsBuilder {
+        commits::ListPullRequestCommitsBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// List the [files](crate::model::pulls::ChangedFile) changed by a pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::pulls::Whitespace};
+    /// # async fn pr_files() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let files = client
+    ///     .pulls("owner", "repo")
+    ///     .files(1)
+    ///     .whitespace(Whitespace::IgnoreAll)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the files changed by the pull request with the ID 1 in the repository
+    /// "owner/repo", ignoring whitespace changes.
+    pub fn files(&self, id: i64) -> files::ListPullRequestFilesBuilder {
+        files::ListPullRequestFilesBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Download the diff of a pull request as raw text.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_diff() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let diff = client
+    ///     .pulls("owner", "repo")
+    ///     .diff(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the diff of the pull request with the ID 1 in the repository "owner/repo".
+    pub fn diff(&self, id: i64) -> diff::GetPullRequestDiffBuilder {
+        diff::GetPullRequestDiffBuilder::new(&self.owner, &self.repo, id, "diff")
+    }
+
+    /// Download a pull request as a patch, suitable for `git am`.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_patch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let patch = client
+    ///     .pulls("owner", "repo")
+    ///     .patch(1)
+    ///     .binary(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the patch of the pull request with the ID 1 in the repository "owner/repo",
+    /// including binary changes.
+    pub fn patch(&self, id: i64) -> diff::GetPullRequestDiffBuilder {
+        diff::GetPullRequestDiffBuilder::new(&self.owner, &self.repo, id, "patch")
+    }
+
     pub fn reviews(&self) -> reviews::Reviews {
         reviews::Reviews::new(&self.owner, &self.repo)
     }
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use serde::Serialize;
 
-use crate::{error::Result, model::pulls::PullRequest, Client};
+use crate::{error::Result, model::pulls::PullRequest, response::ApiResponse, GiteaHttp};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct PinnedPullRequestsBuilder {
@@ -8,6 +10,8 @@ pub struct PinnedPullRequestsBuilder {
     owner: String,
     #[serde(skip)]
     repo: String,
+    #[serde(skip)]
+    timeout: Option<Duration>,
 }
 
 impl PinnedPullRequestsBuilder {
@@ -15,16 +19,30 @@ impl PinnedPullRequestsBuilder {
         Self {
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to list pinned pull requests.
-    pub async fn send(&self, client: &Client) -> Result<Vec<PullRequest>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<PullRequest>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<PullRequest>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<PullRequest>>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .get(format!("/repos/{owner}/{repo}/pulls/pinned"))
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
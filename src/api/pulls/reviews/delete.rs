@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct DeleteReviewBuilder {
+    owner: String,
+    repo: String,
+    /// Index of the pull request
+    index: i64,
+    /// ID of the review
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            timeout: None,
+        }
+    }
+    /// Sends the request to delete a review.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner,
+            repo,
+            index,
+            id,
+            ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/pulls/{index}/reviews/{id}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::reviews::PullReview, response::ApiResponse, GiteaHttp};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct DismissReviewBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    /// Index of the pull request
+    #[serde(skip)]
+    #[skip]
+    index: i64,
+    /// ID of the review
+    #[serde(skip)]
+    #[skip]
+    id: i64,
+    #[skip]
+    message: String,
+
+    /// Whether to also dismiss prior reviews of the same reviewer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priors: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl DismissReviewBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        index: i64,
+        id: i64,
+        message: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            message: message.to_string(),
+            priors: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to dismiss a review.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullReview> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullReview);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullReview>> {
+        let Self {
+            owner,
+            repo,
+            index,
+            id,
+            ..
+        } = self;
+        let req = client
+            .post(format!(
+                "repos/{owner}/{repo}/pulls/{index}/reviews/{id}/dismissals"
+            ))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UndismissReviewBuilder {
+    owner: String,
+    repo: String,
+    /// Index of the pull request
+    index: i64,
+    /// ID of the review
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl UndismissReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            timeout: None,
+        }
+    }
+    /// Sends the request to cancel the dismissal of a review.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullReview> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullReview);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullReview>> {
+        let Self {
+            owner,
+            repo,
+            index,
+            id,
+            ..
+        } = self;
+        let req = client
+            .post(format!(
+                "repos/{owner}/{repo}/pulls/{index}/reviews/{id}/undismissals"
+            ))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
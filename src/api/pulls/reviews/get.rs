@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
@@ -6,7 +8,8 @@ use crate::{
     model::{
         reviews::PullReview,
     },
-    Client,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
 };
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -27,6 +30,9 @@ pub struct GetReviewsBuilder {
     page: Option<i64>,
     /// Page size of results
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl GetReviewsBuilder {
@@ -37,10 +43,21 @@ impl GetReviewsBuilder {
             index,
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Sends the request to list all reviews for a pull request.
-    pub async fn send(&self, client: &Client) -> Result<Vec<PullReview>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<PullReview>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<PullReview>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<PullReview>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let index = &self.index;
@@ -48,7 +65,9 @@ impl GetReviewsBuilder {
             .get(format!("repos/{owner}/{repo}/pulls/{index}/reviews"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(GetReviewsBuilder, PullReview);
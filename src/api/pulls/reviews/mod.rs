@@ -1,4 +1,9 @@
+pub mod delete;
+pub mod dismiss;
 pub mod get;
+pub mod submit;
+
+use crate::model::reviews::ReviewStateType;
 
 pub struct Reviews {
     pub(crate) owner: String,
@@ -37,4 +42,116 @@ impl Reviews {
     pub fn get(&self, index: i64) -> get::GetReviewsBuilder {
         get::GetReviewsBuilder::new(&self.owner, &self.repo, index)
     }
+
+    /// Submit a pending [review](crate::model::reviews::PullReview).
+    /// The `event` decides whether the review approves, requests changes or only comments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gitea_sdk::{Client, Auth, model::reviews::ReviewStateType};
+    /// async fn submit_review() {
+    ///     let client = Client::new(
+    ///         "https://gitea.example.com",
+    ///         Auth::Token("your-token")
+    ///     );
+    ///     let review = client
+    ///         .pulls("owner", "repo")
+    ///         .reviews()
+    ///         .submit(1, 2, ReviewStateType::Approved)
+    ///         .body("LGTM")
+    ///         .send(&client)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    /// This will approve the pending review with the ID 2 on the pull request #1.
+    pub fn submit(
+        &self,
+        index: i64,
+        review_id: i64,
+        event: ReviewStateType,
+    ) -> submit::SubmitReviewBuilder {
+        submit::SubmitReviewBuilder::new(&self.owner, &self.repo, index, review_id, event)
+    }
+
+    /// Dismiss a [review](crate::model::reviews::PullReview).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gitea_sdk::{Client, Auth};
+    /// async fn dismiss_review() {
+    ///     let client = Client::new(
+    ///         "https://gitea.example.com",
+    ///         Auth::Token("your-token")
+    ///     );
+    ///     let review = client
+    ///         .pulls("owner", "repo")
+    ///         .reviews()
+    ///         .dismiss(1, 2, "outdated")
+    ///         .send(&client)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    /// This will dismiss the review with the ID 2 on the pull request #1.
+    pub fn dismiss(
+        &self,
+        index: i64,
+        review_id: i64,
+        message: impl ToString,
+    ) -> dismiss::DismissReviewBuilder {
+        dismiss::DismissReviewBuilder::new(&self.owner, &self.repo, index, review_id, message)
+    }
+
+    /// Cancel the dismissal of a [review](crate::model::reviews::PullReview).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gitea_sdk::{Client, Auth};
+    /// async fn undismiss_review() {
+    ///     let client = Client::new(
+    ///         "https://gitea.example.com",
+    ///         Auth::Token("your-token")
+    ///     );
+    ///     let review = client
+    ///         .pulls("owner", "repo")
+    ///         .reviews()
+    ///         .undismiss(1, 2)
+    ///         .send(&client)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    /// This will restore the dismissed review with the ID 2 on the pull request #1.
+    pub fn undismiss(&self, index: i64, review_id: i64) -> dismiss::UndismissReviewBuilder {
+        dismiss::UndismissReviewBuilder::new(&self.owner, &self.repo, index, review_id)
+    }
+
+    /// Delete a [review](crate::model::reviews::PullReview).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use gitea_sdk::{Client, Auth};
+    /// async fn delete_review() {
+    ///     let client = Client::new(
+    ///         "https://gitea.example.com",
+    ///         Auth::Token("your-token")
+    ///     );
+    ///     client
+    ///         .pulls("owner", "repo")
+    ///         .reviews()
+    ///         .delete(1, 2)
+    ///         .send(&client)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    /// This will delete the review with the ID 2 on the pull request #1.
+    pub fn delete(&self, index: i64, review_id: i64) -> delete::DeleteReviewBuilder {
+        delete::DeleteReviewBuilder::new(&self.owner, &self.repo, index, review_id)
+    }
 }
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::reviews::{PullReview, ReviewStateType},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SubmitReviewBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    /// Index of the pull request
+    #[serde(skip)]
+    #[skip]
+    index: i64,
+    /// ID of the pending review
+    #[serde(skip)]
+    #[skip]
+    id: i64,
+    #[skip]
+    event: ReviewStateType,
+
+    /// The body of the review
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl SubmitReviewBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        index: i64,
+        id: i64,
+        event: ReviewStateType,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            event,
+            body: None,
+            timeout: None,
+        }
+    }
+    /// Sends the request to submit a pending review.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PullReview> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PullReview);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PullReview>> {
+        let Self {
+            owner,
+            repo,
+            index,
+            id,
+            ..
+        } = self;
+        let req = client
+            .post(format!("repos/{owner}/{repo}/pulls/{index}/reviews/{id}"))
+            .json(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWrite;
+
+use crate::{
+    error::Result,
+    model::actions::ActionArtifact,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    transport, GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListArtifactsBuilder {
+    #[serde(skip)]
+    #[skip]
+    path: String,
+    /// Only list artifacts with this name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListArtifactsBuilder {
+    pub(crate) fn new(path: impl ToString) -> Self {
+        Self {
+            path: path.to_string(),
+            name: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the artifacts.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<ActionArtifact>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<ActionArtifact>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<ActionArtifact>> {
+        let req = client.get(&self.path).query(self).build()?;
+        #[derive(Deserialize)]
+        struct Response {
+            total_count: u64,
+            artifacts: Vec<ActionArtifact>,
+        }
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let Response {
+            total_count,
+            artifacts,
+        } = client.parse_response(res).await?;
+        Ok(Paged::with_total_count(artifacts, &headers, total_count))
+    }
+}
+
+impl_pageable!(ListArtifactsBuilder, ActionArtifact);
+
+#[derive(Debug, Clone)]
+pub struct GetArtifactBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl GetArtifactBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the artifact.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<ActionArtifact> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(ActionArtifact);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<ActionArtifact>> {
+        let Self {
+            owner, repo, id, ..
+        } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/actions/artifacts/{id}"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteArtifactBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteArtifactBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to delete the artifact.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self {
+            owner, repo, id, ..
+        } = self;
+        let req = client
+            .delete(format!("repos/{owner}/{repo}/actions/artifacts/{id}"))
+            .build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadArtifactBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DownloadArtifactBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to download the artifact and writes the zip archive to `writer`.
+    /// The archive is streamed, so it is never held in memory as a whole.
+    /// Returns the number of bytes written.
+    pub async fn send<W>(&self, client: &impl GiteaHttp, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let Self {
+            owner, repo, id, ..
+        } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/actions/artifacts/{id}/zip"))
+            .build()?;
+        let res = client.make_download_request(self.with_timeout(req)).await?;
+        transport::copy_response(res, writer).await
+    }
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use tokio::io::AsyncWrite;
+
+use crate::{error::Result, transport, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct DownloadJobLogsBuilder {
+    owner: String,
+    repo: String,
+    job_id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DownloadJobLogsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, job_id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            job_id,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to download the logs of the job and writes them to `writer`.
+    /// The logs are streamed, so they are never held in memory as a whole.
+    /// Returns the number of bytes written.
+    pub async fn send<W>(&self, client: &impl GiteaHttp, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let Self {
+            owner,
+            repo,
+            job_id,
+            ..
+        } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/actions/jobs/{job_id}/logs"))
+            .build()?;
+        let res = client.make_download_request(self.with_timeout(req)).await?;
+        transport::copy_response(res, writer).await
+    }
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,254 @@
+pub mod artifacts;
+pub mod logs;
+pub mod tasks;
+
+use crate::api::actions::{jobs::ListJobsBuilder, runs::ListRunsBuilder};
+
+pub struct RepoActions {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl RepoActions {
+    /// Lists the workflow runs of the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_runs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let runs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .runs()
+    ///     .status("failure")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn runs(&self) -> ListRunsBuilder {
+        let Self { owner, repo } = self;
+        ListRunsBuilder::new(format!("repos/{owner}/{repo}/actions/runs"))
+    }
+
+    /// Lists the jobs of a workflow run.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_run_jobs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let jobs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .run_jobs(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn run_jobs(&self, run_id: i64) -> ListJobsBuilder {
+        let Self { owner, repo } = self;
+        ListJobsBuilder::new(format!("repos/{owner}/{repo}/actions/runs/{run_id}/jobs"))
+    }
+
+    /// Lists the jobs of all workflow runs of the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_jobs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let jobs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .jobs()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn jobs(&self) -> ListJobsBuilder {
+        let Self { owner, repo } = self;
+        ListJobsBuilder::new(format!("repos/{owner}/{repo}/actions/jobs"))
+    }
+
+    /// Lists the artifacts of the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_artifacts() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let artifacts = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .artifacts()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn artifacts(&self) -> artifacts::ListArtifactsBuilder {
+        let Self { owner, repo } = self;
+        artifacts::ListArtifactsBuilder::new(format!("repos/{owner}/{repo}/actions/artifacts"))
+    }
+
+    /// Lists the artifacts of a workflow run.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_run_artifacts() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let artifacts = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .run_artifacts(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn run_artifacts(&self, run_id: i64) -> artifacts::ListArtifactsBuilder {
+        let Self { owner, repo } = self;
+        artifacts::ListArtifactsBuilder::new(format!(
+            "repos/{owner}/{repo}/actions/runs/{run_id}/artifacts"
+        ))
+    }
+
+    /// Gets an artifact by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_artifact() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let artifact = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .get_artifact(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_artifact(&self, id: i64) -> artifacts::GetArtifactBuilder {
+        artifacts::GetArtifactBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Deletes an artifact.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_artifact() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .delete_artifact(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete_artifact(&self, id: i64) -> artifacts::DeleteArtifactBuilder {
+        artifacts::DeleteArtifactBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Downloads an artifact as a zip archive into any [AsyncWrite].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn download_artifact() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let mut buf = Vec::new();
+    /// let written = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .download_artifact(1)
+    ///     .send(&client, &mut buf)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn download_artifact(&self, id: i64) -> artifacts::DownloadArtifactBuilder {
+        artifacts::DownloadArtifactBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Downloads the logs of a job into any [AsyncWrite].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn download_job_logs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let mut buf = Vec::new();
+    /// let written = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .job_logs(1)
+    ///     .send(&client, &mut buf)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn job_logs(&self, job_id: i64) -> logs::DownloadJobLogsBuilder {
+        logs::DownloadJobLogsBuilder::new(&self.owner, &self.repo, job_id)
+    }
+
+    /// Lists the Actions tasks of the repository.
+    /// Instances without the runs endpoint only provide this listing.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_tasks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tasks = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .tasks()
+    ///     .limit(10)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn tasks(&self) -> tasks::ListTasksBuilder {
+        tasks::ListTasksBuilder::new(&self.owner, &self.repo)
+    }
+}
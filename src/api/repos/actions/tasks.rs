@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Result,
+    model::actions::ActionTask,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+pub struct ListTasksBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListTasksBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list the tasks.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<ActionTask>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<ActionTask>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<ActionTask>> {
+        let Self { owner, repo, .. } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/actions/tasks"))
+            .query(self)
+            .build()?;
+        #[derive(Deserialize)]
+        struct Response {
+            total_count: u64,
+            workflow_runs: Vec<ActionTask>,
+        }
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let Response {
+            total_count,
+            workflow_runs,
+        } = client.parse_response(res).await?;
+        Ok(Paged::with_total_count(workflow_runs, &headers, total_count))
+    }
+}
+
+impl_pageable!(ListTasksBuilder, ActionTask);
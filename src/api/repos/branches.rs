@@ -1,7 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Branch, Client};
+use crate::{
+    error::Result,
+    model::repos::Branch,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -20,6 +28,9 @@ pub struct ListBranchesBuilder {
     /// Page size of results.
     #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -35,6 +46,9 @@ pub struct CreateBranchBuilder {
     new_branch_name: String,
 
     old_ref_name: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Builder)]
@@ -46,6 +60,8 @@ pub struct GetBranchBuilder {
     repo: String,
     #[build_it(skip)]
     branch: String,
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Builder)]
@@ -57,6 +73,8 @@ pub struct DeleteBranchBuilder {
     repo: String,
     #[build_it(skip)]
     branch: String,
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListBranchesBuilder {
@@ -66,21 +84,34 @@ impl ListBranchesBuilder {
             repo: repo.to_string(),
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Sends the request to list a repository's branches.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Branch>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Branch>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Branch>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Branch>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .get(format!("repos/{owner}/{repo}/branches"))
             .query(&self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
 
+impl_pageable!(ListBranchesBuilder, Branch);
+
 impl CreateBranchBuilder {
     pub fn new(owner: impl ToString, repo: impl ToString, new_branch_name: impl ToString) -> Self {
         Self {
@@ -88,18 +119,29 @@ impl CreateBranchBuilder {
             repo: repo.to_string(),
             new_branch_name: new_branch_name.to_string(),
             old_ref_name: None,
+            timeout: None,
         }
     }
     /// Sends the request to create a branch.
-    pub async fn send(&self, client: &Client) -> Result<Branch> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Branch> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Branch);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Branch>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .post(format!("repos/{owner}/{repo}/branches"))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
 
@@ -109,20 +151,32 @@ impl GetBranchBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             branch: branch.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to get a branch.
-    pub async fn send(&self, client: &Client) -> Result<Branch> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Branch> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Branch);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<Branch>> {
         let Self {
             owner,
             repo,
             branch,
+            ..
         } = self;
         let req = client
             .get(format!("repos/{owner}/{repo}/branches/{branch}"))
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
 
@@ -132,19 +186,25 @@ impl DeleteBranchBuilder {
             owner: owner.to_string(),
             repo: repo.to_string(),
             branch: branch.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to get a branch.
-    pub async fn send(&self, client: &Client) -> Result<()> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
         let Self {
             owner,
             repo,
             branch,
+            ..
         } = self;
         let req = client
             .delete(format!("repos/{owner}/{repo}/branches/{branch}"))
             .build()?;
-        let _ = client.make_request(req).await?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
@@ -1,7 +1,14 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Commit};
+use crate::GiteaHttp;
+use crate::{
+    error::Result,
+    model::repos::Commit,
+    pagination::{impl_pageable, Paged},
+};
 
 /// Options for getting a list of commits from a repository.
 /// All fields are optional.
@@ -23,20 +30,18 @@ pub struct GetCommitsBuilder {
     /// File path to a file/directory in the repository.
     /// If provided, only commits affecting this path will be returned.
     pub path: Option<String>,
-    /// Whether to include the `stat` field in the response.
+    /// Whether to include the [Commit::stats] field in the response.
     /// Disable to speed-up the response.
     /// Defaults to true.
-    /// NOTE: Commit verification is not implemented yet, so this setting does nothing.
     pub stat: Option<bool>,
-    /// Whether to include the `verification` field in the response.
+    /// Whether to include the [RepoCommit::verification](crate::model::repos::RepoCommit::verification)
+    /// field in the response.
     /// Disable to speed-up the response.
     /// Defaults to true.
-    /// NOTE: Commit verification is not implemented yet, so this setting does nothing.
     pub verification: Option<bool>,
-    /// Whether to include the `files` field in the response.
+    /// Whether to include the [Commit::files] field in the response.
     /// Disable to speed-up the response.
     /// Defaults to true.
-    /// NOTE: Commit verification is not implemented yet, so this setting does nothing.
     pub files: Option<bool>,
     /// Optional page number of the results to fetch (1-based).
     /// Defaults to 1 if not set.
@@ -46,6 +51,9 @@ pub struct GetCommitsBuilder {
     pub limit: Option<i64>,
     /// Commits that match the given specifier will not be listed.
     pub not: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl GetCommitsBuilder {
@@ -61,11 +69,22 @@ impl GetCommitsBuilder {
             page: None,
             limit: None,
             not: None,
+            timeout: None,
         }
     }
 
     /// Send the request to get the commits.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Commit>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Commit>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Commit>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Commit>> {
         let owner = &self.owner;
         let repo = &self.repo;
 
@@ -73,7 +92,9 @@ impl GetCommitsBuilder {
             .get(format!("repos/{owner}/{repo}/commits"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(GetCommitsBuilder, Commit);
@@ -1,9 +1,13 @@
+use std::time::Duration;
+
+use crate::GiteaHttp;
 use crate::Result;
 
 #[derive(Debug)]
 pub struct DeleteRepoBuilder {
     owner: String,
     repo: String,
+    timeout: Option<Duration>,
 }
 
 impl DeleteRepoBuilder {
@@ -11,14 +15,19 @@ impl DeleteRepoBuilder {
         Self {
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
 
     /// Send the request to delete the repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<()> {
-        let DeleteRepoBuilder { owner, repo } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let DeleteRepoBuilder { owner, repo, .. } = self;
         let req = client.delete(format!("repos/{owner}/{repo}")).build()?;
-        client.make_request(req).await?;
+        client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
+use crate::GiteaHttp;
 use crate::{
     error::Result,
-    model::repos::{ExternalTracker, ExternalWiki, Repository},
+    model::repos::{ExternalTracker, ExternalWiki, MergeStyle, ProjectsMode, Repository},
+    response::ApiResponse,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Builder)]
@@ -41,8 +45,8 @@ pub struct EditRepoBuilder {
     default_branch: Option<String>,
     /// Set to `true` to delete pr branch after merge by default
     default_delete_branch_after_merge: Option<bool>,
-    /// Set to a merge style to be used by this repository: "merge", "rebase", "rebase-merge", "squash", or "fast-forward-only".
-    default_merge_style: Option<String>,
+    /// Set to a merge style to be used by this repository.
+    default_merge_style: Option<MergeStyle>,
     /// A short description of the repository.
     description: Option<String>,
     /// Enable prune - remove obsolete remote-tracking references when mirroring
@@ -76,12 +80,15 @@ pub struct EditRepoBuilder {
     /// NOTE: you will get a 422 error if the organization restricts changing repository visibility
     /// To organization owners and a non-owner tries to change the value of private.
     private: Option<bool>,
-    /// `repo` to only allow repo-level projects, `owner` to only allow owner projects, `all` to allow both.
-    projects_mode: Option<String>,
+    /// Which projects can be used in this repository.
+    projects_mode: Option<ProjectsMode>,
     /// Either `true` to make this repository a template or `false` to make it a normal repository
     template: Option<bool>,
     /// A URL with more information about the repository.
     website: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl EditRepoBuilder {
@@ -120,17 +127,31 @@ impl EditRepoBuilder {
             projects_mode: None,
             template: None,
             website: None,
+            timeout: None,
         }
     }
     /// Send the request to edit the repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .patch(format!("repos/{owner}/{repo}"))
             .json(&self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,7 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result,
+    model::repos::Repository,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
 
 /// Options for forking a repository.
 /// All fields are optional.
@@ -23,6 +31,9 @@ pub struct CreateForkBuilder {
     name: Option<String>,
     /// Organization name, if forking into an organization.
     organization: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateForkBuilder {
@@ -32,18 +43,32 @@ impl CreateForkBuilder {
             repo: repo.to_string(),
             organization: None,
             name: None,
+            timeout: None,
         }
     }
     /// Send the request to fork the repository.
-    pub async fn send(&self, client: &Client) -> Result<Repository> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
         let owner = &self.owner;
         let repo = &self.repo;
         let req = client
             .post(format!("repos/{owner}/{repo}/forks"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
 
@@ -69,6 +94,9 @@ pub struct ListForksBuilder {
     /// Optional number of forks to return per page (page-size).
     /// Defaults to the maximum your instance allows if not set.
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListForksBuilder {
@@ -78,10 +106,21 @@ impl ListForksBuilder {
             repo: repo.to_string(),
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Send the request to list the forks.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
         let owner = &self.owner;
         let repo = &self.repo;
 
@@ -89,7 +128,9 @@ impl ListForksBuilder {
             .get(format!("repos/{owner}/{repo}/forks"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(ListForksBuilder, Repository);
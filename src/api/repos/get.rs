@@ -1,9 +1,13 @@
-use crate::{error::Result, model::repos::Repository};
+use std::time::Duration;
+
+use crate::GiteaHttp;
+use crate::{error::Result, model::repos::Repository, response::ApiResponse};
 
 #[derive(Debug)]
 pub struct GetRepoBuilder {
     owner: String,
     repo: String,
+    timeout: Option<Duration>,
 }
 
 impl GetRepoBuilder {
@@ -11,15 +15,29 @@ impl GetRepoBuilder {
         Self {
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
     /// Send the request to get the repository.
     /// This will return a [Repository] object if the repository exists and is visible to the
     /// currently authenticated user.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
-        let GetRepoBuilder { owner, repo } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
+        let GetRepoBuilder { owner, repo, .. } = self;
         let req = client.get(format!("repos/{owner}/{repo}")).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,9 +1,12 @@
+pub mod actions;
 pub mod branches;
 pub mod commits;
 pub mod delete;
 pub mod edit;
 pub mod forks;
 pub mod get;
+pub mod signing_key;
+pub mod times;
 
 /// The [Repos] struct provides methods for interacting with repositories.
 pub struct Repos {
@@ -307,4 +310,84 @@ impl Repos {
     pub fn delete_branch(&self, branch: impl ToString) -> branches::DeleteBranchBuilder {
         branches::DeleteBranchBuilder::new(&self.owner, &self.repo, branch)
     }
+
+    /// Gets the GPG key used to sign commits in a repository.
+    /// This will return the ASCII-armored public key, which you can import into your keyring to
+    /// verify commits signed by the Gitea instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn signing_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .repos("owner", "repo")
+    ///     .signing_key()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the signing key for the repository "owner/repo".
+    pub fn signing_key(&self) -> signing_key::GetSigningKeyBuilder {
+        signing_key::GetSigningKeyBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Lists the times tracked on all issues and pull requests of a repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, Timestamp};
+    /// # async fn list_times() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let since: Timestamp = "2024-01-01T00:00:00Z".parse().unwrap();
+    /// let times = client
+    ///     .repos("owner", "repo")
+    ///     .times()
+    ///     .user("username")
+    ///     .since(since)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will list the times "username" tracked in the repository "owner/repo" since the
+    /// start of 2024.
+    pub fn times(&self) -> times::ListRepoTimesBuilder {
+        times::ListRepoTimesBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Access the Actions of the repository.
+    /// See [actions::RepoActions] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn repo_actions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let runs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .runs()
+    ///     .branch("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn actions(&self) -> actions::RepoActions {
+        actions::RepoActions {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
 }
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
+
+#[derive(Debug, Clone)]
+pub struct GetSigningKeyBuilder {
+    owner: String,
+    repo: String,
+    timeout: Option<Duration>,
+}
+
+impl GetSigningKeyBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            timeout: None,
+        }
+    }
+    /// Sends the request to get the repository's signing key.
+    /// This will return the ASCII-armored GPG public key used to sign commits in the repository.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<String> {
+        let Self { owner, repo, .. } = self;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/signing-key.gpg"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        Ok(res.text().await?)
+    }
+
+    crate::transport::send_blocking!(String);
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::issues::TrackedTime,
+    pagination::{impl_pageable, Paged},
+    timestamp::Timestamp,
+    GiteaHttp,
+};
+
+/// Options for listing the times tracked in a repository.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListRepoTimesBuilder {
+    #[serde(skip)]
+    #[skip]
+    owner: String,
+    #[serde(skip)]
+    #[skip]
+    repo: String,
+
+    /// Only show times tracked by the given user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+    /// Only show times updated after the given time. This is a timestamp in RFC 3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<Timestamp>,
+    /// Only show times updated before the given time. This is a timestamp in RFC 3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<Timestamp>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListRepoTimesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            user: None,
+            since: None,
+            before: None,
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to list a repository's tracked times.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<TrackedTime>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<TrackedTime>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<TrackedTime>> {
+        let owner = &self.owner;
+        let repo = &self.repo;
+        let req = client
+            .get(format!("repos/{owner}/{repo}/times"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListRepoTimesBuilder, TrackedTime);
@@ -1,8 +1,13 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::issues::{Issue, IssueType, State};
+use crate::pagination::{impl_pageable, Paged};
+use crate::timestamp::Timestamp;
+use crate::GiteaHttp;
 
 /// Options for searching issues.
 /// All fields are optional.
@@ -26,11 +31,9 @@ pub struct SearchIssuesBuilder {
     #[serde(rename = "type")]
     issue_type: Option<IssueType>,
     /// Only show issues updated after the given time. This is a timestamp in RFC 3339 format.
-    // TODO: Make this a DateTime<Utc>
-    since: Option<String>,
+    since: Option<Timestamp>,
     /// Only show issues updated before the given time. This is a timestamp in RFC 3339 format.
-    // TODO: Make this a DateTime<Utc>
-    before: Option<String>,
+    before: Option<Timestamp>,
     /// Filter issues/PRs assigned to the authenticated user, default is false
     assigned: Option<bool>,
     /// Filter issues/PRs created by the authenticated user, default is false
@@ -49,6 +52,9 @@ pub struct SearchIssuesBuilder {
     page: Option<i32>,
     /// Page size of results
     limit: Option<i32>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl SearchIssuesBuilder {
@@ -58,12 +64,24 @@ impl SearchIssuesBuilder {
     /// Send the request to search for issues.
     /// This will return a [Vec<Issue>] of all issues matching the search criteria.
     /// Only shows issues the currently authenticated user can see.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Issue>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Issue>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Issue>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Issue>> {
         let req = client
             .get("repos/issues/search".to_string())
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(SearchIssuesBuilder, Issue);
@@ -1,5 +1,6 @@
 pub mod issues;
 pub mod repos;
+pub mod topics;
 pub mod users;
 
 pub struct Search;
@@ -81,4 +82,29 @@ impl Search {
     pub fn issues(&self) -> issues::SearchIssuesBuilder {
         issues::SearchIssuesBuilder::new()
     }
+
+    /// Searches for repository topics matching the given keyword.
+    /// This method will return a list of topics along with the number of repositories using them.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn search_topics() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let topics = client
+    ///     .search()
+    ///     .topics("rust")
+    ///     .limit(10)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will search for topics matching the keyword "rust".
+    pub fn topics(&self, keyword: impl ToString) -> topics::SearchTopicsBuilder {
+        topics::SearchTopicsBuilder::new(keyword)
+    }
 }
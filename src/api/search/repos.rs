@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
-use crate::model::repos::Repository;
+use crate::model::repos::{RepoSearchMode, Repository, SortKey, SortOrder};
+use crate::pagination::{impl_pageable, Paged};
+use crate::GiteaHttp;
 
 /// Options for searching repositories.
 /// All fields are optional.
@@ -34,25 +38,38 @@ pub struct SearchRepositoriesBuilder {
     pub template: Option<bool>,
     /// Show only archived, non-archived or all repositories (defaults to all)
     pub archived: Option<bool>,
-    /// Type of repository to search for. Supported values are "fork", "source", "mirror" and "collaborative"
-    pub mode: Option<String>,
+    /// Type of repository to search for
+    pub mode: Option<RepoSearchMode>,
     /// If uid is given, search only for repos that the user owns
     pub exclusive: Option<bool>,
-    /// Sort repos by attribute. Supported values are "alpha", "created", "updated", "size", and "id". Default is "alpha"
-    pub sort: Option<String>,
-    /// Sort order, either "asc" (ascending) or "desc" (descending). Default is "asc", ignored if "sort" is not specified.
-    pub order: Option<String>,
+    /// Sort repos by attribute. Default is [SortKey::Alpha]
+    pub sort: Option<SortKey>,
+    /// Sort order. Default is [SortOrder::Asc], ignored if `sort` is not specified.
+    pub order: Option<SortOrder>,
     /// Page number of results to return (1-based)
     pub page: Option<i32>,
     /// Page size of results
     pub limit: Option<i32>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl SearchRepositoriesBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Repository>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
         let req = client.get("repos/search".to_string()).query(self).build()?;
         #[derive(Deserialize)]
         struct Response {
@@ -60,7 +77,11 @@ impl SearchRepositoriesBuilder {
             ok: bool,
             data: Vec<Repository>,
         }
-        let res = client.make_request(req).await?;
-        Ok(client.parse_response::<Response>(res).await?.data)
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let items = client.parse_response::<Response>(res).await?.data;
+        Ok(Paged::new(items, &headers))
     }
 }
+
+impl_pageable!(SearchRepositoriesBuilder, Repository);
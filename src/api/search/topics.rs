@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::model::repos::Topic;
+use crate::pagination::{impl_pageable, Paged};
+use crate::GiteaHttp;
+
+/// Options for searching topics.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SearchTopicsBuilder {
+    /// Keyword to search for
+    #[serde(rename = "q")]
+    #[skip]
+    query: String,
+    /// Page number of results to return (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i32>,
+    /// Page size of results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl SearchTopicsBuilder {
+    pub fn new(query: impl ToString) -> Self {
+        Self {
+            query: query.to_string(),
+            page: None,
+            limit: None,
+            timeout: None,
+        }
+    }
+    /// Send the request to search for topics.
+    /// This will return a [Vec<Topic>] with all topics matching the keyword.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Topic>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Topic>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Topic>> {
+        let req = client
+            .get("topics/search".to_string())
+            .query(self)
+            .build()?;
+        #[derive(Deserialize)]
+        struct Response {
+            topics: Vec<Topic>,
+        }
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let items = client.parse_response::<Response>(res).await?.topics;
+        Ok(Paged::new(items, &headers))
+    }
+}
+
+impl_pageable!(SearchTopicsBuilder, Topic);
@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
 use crate::model::user::User;
+use crate::pagination::{impl_pageable, Paged};
+use crate::GiteaHttp;
 
 /// Options for searching users.
 /// All fields are optional.
@@ -18,6 +22,9 @@ pub struct SearchUsersBuilder {
     page: Option<i32>,
     /// Page size of results
     limit: Option<i32>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl SearchUsersBuilder {
@@ -27,7 +34,17 @@ impl SearchUsersBuilder {
     /// Send the request to get the repository.
     /// This will return a [Vec<User>] object if the repository exists and is visible to the
     /// currently authenticated user.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<User>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<User>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<User>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<User>> {
         let req = client.get("users/search".to_string()).query(self).build()?;
         #[derive(Deserialize)]
         struct Response {
@@ -35,7 +52,11 @@ impl SearchUsersBuilder {
             ok: bool,
             data: Vec<User>,
         }
-        let res = client.make_request(req).await?;
-        Ok(client.parse_response::<Response>(res).await?.data)
+        let res = client.make_request(self.with_timeout(req)).await?;
+        let headers = res.headers().clone();
+        let items = client.parse_response::<Response>(res).await?.data;
+        Ok(Paged::new(items, &headers))
     }
 }
+
+impl_pageable!(SearchUsersBuilder, User);
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use crate::{error::Result, GiteaHttp};
+
+#[derive(Default, Debug, Clone)]
+pub struct GetSigningKeyBuilder {
+    timeout: Option<Duration>,
+}
+
+impl GetSigningKeyBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+    /// Sends the request to get the instance's signing key.
+    /// This will return the ASCII-armored GPG public key used to sign commits on the instance.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<String> {
+        let req = client.get("signing-key.gpg").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        Ok(res.text().await?)
+    }
+
+    crate::transport::send_blocking!(String);
+
+    crate::transport::request_timeout!();
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use crate::{error::Result, model::templates::GitignoreTemplate, response::ApiResponse, GiteaHttp};
+
+#[derive(Default, Debug, Clone)]
+pub struct ListGitignoreTemplatesBuilder {
+    timeout: Option<Duration>,
+}
+
+impl ListGitignoreTemplatesBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to list the names of the gitignore templates.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<String>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<String>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<String>>> {
+        let req = client.get("gitignore/templates").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetGitignoreTemplateBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl GetGitignoreTemplateBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the gitignore template.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<GitignoreTemplate> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(GitignoreTemplate);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<GitignoreTemplate>> {
+        let name = &self.name;
+        let req = client.get(format!("gitignore/templates/{name}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use crate::{error::Result, model::templates::LabelTemplate, response::ApiResponse, GiteaHttp};
+
+#[derive(Default, Debug, Clone)]
+pub struct ListLabelTemplatesBuilder {
+    timeout: Option<Duration>,
+}
+
+impl ListLabelTemplatesBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to list the names of the label template sets.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<String>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<String>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<String>>> {
+        let req = client.get("label/templates").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLabelTemplateBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl GetLabelTemplateBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the labels of the label template set.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<LabelTemplate>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<LabelTemplate>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<LabelTemplate>>> {
+        let name = &self.name;
+        let req = client.get(format!("label/templates/{name}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::{
+    error::Result,
+    model::templates::{LicenseTemplate, LicenseTemplateEntry},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct ListLicenseTemplatesBuilder {
+    timeout: Option<Duration>,
+}
+
+impl ListLicenseTemplatesBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to list the license templates.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<LicenseTemplateEntry>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<LicenseTemplateEntry>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<LicenseTemplateEntry>>> {
+        let req = client.get("licenses").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLicenseTemplateBuilder {
+    name: String,
+    timeout: Option<Duration>,
+}
+
+impl GetLicenseTemplateBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the license template.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<LicenseTemplate> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(LicenseTemplate);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<LicenseTemplate>> {
+        let name = &self.name;
+        let req = client.get(format!("licenses/{name}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
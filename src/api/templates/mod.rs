@@ -0,0 +1,140 @@
+pub mod gitignore;
+pub mod labels;
+pub mod licenses;
+
+/// Templates the instance offers when creating repositories.
+pub struct Templates;
+
+impl Templates {
+    /// Lists the names of the available gitignore templates.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_gitignores() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let names = client
+    ///     .templates()
+    ///     .gitignores()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn gitignores(&self) -> gitignore::ListGitignoreTemplatesBuilder {
+        gitignore::ListGitignoreTemplatesBuilder::new()
+    }
+
+    /// Gets a gitignore template by its name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_gitignore() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let template = client
+    ///     .templates()
+    ///     .gitignore("Rust")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn gitignore(&self, name: impl ToString) -> gitignore::GetGitignoreTemplateBuilder {
+        gitignore::GetGitignoreTemplateBuilder::new(name)
+    }
+
+    /// Lists the available license templates.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_licenses() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let licenses = client
+    ///     .templates()
+    ///     .licenses()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn licenses(&self) -> licenses::ListLicenseTemplatesBuilder {
+        licenses::ListLicenseTemplatesBuilder::new()
+    }
+
+    /// Gets a license template by its key.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_license() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let license = client
+    ///     .templates()
+    ///     .license("MIT")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn license(&self, name: impl ToString) -> licenses::GetLicenseTemplateBuilder {
+        licenses::GetLicenseTemplateBuilder::new(name)
+    }
+
+    /// Lists the names of the available label template sets.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_label_templates() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let names = client
+    ///     .templates()
+    ///     .labels()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn labels(&self) -> labels::ListLabelTemplatesBuilder {
+        labels::ListLabelTemplatesBuilder::new()
+    }
+
+    /// Gets the labels of a label template set by its name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_label_template() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .templates()
+    ///     .label("Default")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn label(&self, name: impl ToString) -> labels::GetLabelTemplateBuilder {
+        labels::GetLabelTemplateBuilder::new(name)
+    }
+}
@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
+use crate::GiteaHttp;
 use crate::{
     model::repos::{ObjectFormatName, Repository, TrustModel},
+    response::ApiResponse,
     Result,
 };
 
@@ -42,6 +46,9 @@ pub struct CreateRepoBuilder {
     template: Option<bool>,
     /// Trust model for verifying commits in the repository.
     trust_model: Option<TrustModel>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl CreateRepoBuilder {
@@ -59,13 +66,27 @@ impl CreateRepoBuilder {
             readme: None,
             template: None,
             trust_model: None,
+            timeout: None,
         }
     }
     /// Send the request to create the repository.
     /// This will return the created repository object.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Repository> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Repository);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Repository>> {
         let req = client.post("user/repos").json(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
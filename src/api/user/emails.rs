@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{error::Result, model::user::Email, response::ApiResponse, GiteaHttp};
+
+pub struct Emails;
+
+impl Emails {
+    /// Lists the email addresses of the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let emails = client
+    ///     .user()
+    ///     .emails()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListEmailsBuilder {
+        ListEmailsBuilder { timeout: None }
+    }
+
+    /// Adds email addresses to the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let emails = client
+    ///     .user()
+    ///     .emails()
+    ///     .add(vec!["me@example.com"])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will return the added [Email] addresses.
+    pub fn add<S: ToString>(&self, emails: impl IntoIterator<Item = S>) -> AddEmailsBuilder {
+        AddEmailsBuilder::new(emails)
+    }
+
+    /// Removes email addresses from the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .user()
+    ///     .emails()
+    ///     .delete(vec!["me@example.com"])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete<S: ToString>(&self, emails: impl IntoIterator<Item = S>) -> DeleteEmailsBuilder {
+        DeleteEmailsBuilder::new(emails)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListEmailsBuilder {
+    timeout: Option<Duration>,
+}
+
+impl ListEmailsBuilder {
+    /// Sends the request to list the authenticated user's email addresses.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Email>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Email>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Email>>> {
+        let req = client.get("user/emails").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AddEmailsBuilder {
+    emails: Vec<String>,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl AddEmailsBuilder {
+    pub fn new<S: ToString>(emails: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            emails: emails.into_iter().map(|e| e.to_string()).collect(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add email addresses to the authenticated user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Email>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<Email>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<Email>>> {
+        let req = client.post("user/emails").json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteEmailsBuilder {
+    emails: Vec<String>,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl DeleteEmailsBuilder {
+    pub fn new<S: ToString>(emails: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            emails: emails.into_iter().map(|e| e.to_string()).collect(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to remove email addresses from the authenticated user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let req = client.delete("user/emails").json(self).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
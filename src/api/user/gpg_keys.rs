@@ -0,0 +1,348 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::GPGKey,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+pub struct GPGKeys;
+
+impl GPGKeys {
+    /// Lists the GPG keys of the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_gpg_keys() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let keys = client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListGPGKeysBuilder {
+        ListGPGKeysBuilder::new()
+    }
+
+    /// Gets a GPG key of the authenticated user by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_gpg_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> GetGPGKeyBuilder {
+        GetGPGKeyBuilder::new(id)
+    }
+
+    /// Adds a GPG key to the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_gpg_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .create("-----BEGIN PGP PUBLIC KEY BLOCK-----...")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, armored_public_key: impl ToString) -> CreateGPGKeyBuilder {
+        CreateGPGKeyBuilder::new(armored_public_key)
+    }
+
+    /// Deletes a GPG key of the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_gpg_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> DeleteGPGKeyBuilder {
+        DeleteGPGKeyBuilder::new(id)
+    }
+
+    /// Gets the token to sign in order to verify the ownership of a GPG key.
+    /// Sign it with `echo "<token>" | gpg -a --default-key <key-id> --detach-sig` and pass the
+    /// signature to [GPGKeys::verify].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_gpg_token() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let token = client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .get_token()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_token(&self) -> GetGPGKeyTokenBuilder {
+        GetGPGKeyTokenBuilder { timeout: None }
+    }
+
+    /// Verifies the ownership of a GPG key using a signature of the token from
+    /// [GPGKeys::get_token].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn verify_gpg_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .verify("3AA5C34371567BD2", "-----BEGIN PGP SIGNATURE-----...")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn verify(
+        &self,
+        key_id: impl ToString,
+        armored_signature: impl ToString,
+    ) -> VerifyGPGKeyBuilder {
+        VerifyGPGKeyBuilder::new(key_id, armored_signature)
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+pub struct ListGPGKeysBuilder {
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListGPGKeysBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to list the authenticated user's GPG keys.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<GPGKey>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<GPGKey>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<GPGKey>> {
+        let req = client.get("user/gpg_keys").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListGPGKeysBuilder, GPGKey);
+
+#[derive(Debug, Clone)]
+pub struct GetGPGKeyBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl GetGPGKeyBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+
+    /// Sends the request to get a GPG key of the authenticated user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<GPGKey> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(GPGKey);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<GPGKey>> {
+        let id = self.id;
+        let req = client.get(format!("user/gpg_keys/{id}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateGPGKeyBuilder {
+    #[skip]
+    armored_public_key: String,
+    /// A signature of the token from [GPGKeys::get_token], to verify the key right away.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    armored_signature: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateGPGKeyBuilder {
+    pub fn new(armored_public_key: impl ToString) -> Self {
+        Self {
+            armored_public_key: armored_public_key.to_string(),
+            armored_signature: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add a GPG key to the authenticated user.
+    /// This will return the created [GPGKey].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<GPGKey> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(GPGKey);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<GPGKey>> {
+        let req = client.post("user/gpg_keys").json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteGPGKeyBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteGPGKeyBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+
+    /// Sends the request to delete a GPG key of the authenticated user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let id = self.id;
+        let req = client.delete(format!("user/gpg_keys/{id}")).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone)]
+pub struct GetGPGKeyTokenBuilder {
+    timeout: Option<Duration>,
+}
+
+impl GetGPGKeyTokenBuilder {
+    /// Sends the request to get the GPG key verification token.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<String> {
+        let req = client.get("user/gpg_key_token").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        Ok(res.text().await?)
+    }
+
+    crate::transport::send_blocking!(String);
+
+    crate::transport::request_timeout!();
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyGPGKeyBuilder {
+    key_id: String,
+    armored_signature: String,
+    #[serde(skip)]
+    timeout: Option<Duration>,
+}
+
+impl VerifyGPGKeyBuilder {
+    pub fn new(key_id: impl ToString, armored_signature: impl ToString) -> Self {
+        Self {
+            key_id: key_id.to_string(),
+            armored_signature: armored_signature.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to verify a GPG key.
+    /// This will return the verified [GPGKey].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<GPGKey> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(GPGKey);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<GPGKey>> {
+        let req = client.post("user/gpg_key_verify").json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
@@ -0,0 +1,254 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::PublicKey,
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+pub struct Keys;
+
+impl Keys {
+    /// Lists the SSH keys of the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_keys() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let keys = client
+    ///     .user()
+    ///     .keys()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListKeysBuilder {
+        ListKeysBuilder::new()
+    }
+
+    /// Gets an SSH key of the authenticated user by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .user()
+    ///     .keys()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> GetKeyBuilder {
+        GetKeyBuilder::new(id)
+    }
+
+    /// Adds an SSH key to the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let key = client
+    ///     .user()
+    ///     .keys()
+    ///     .create("laptop", "ssh-ed25519 AAAA...")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, title: impl ToString, key: impl ToString) -> CreateKeyBuilder {
+        CreateKeyBuilder::new(title, key)
+    }
+
+    /// Deletes an SSH key of the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_key() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .user()
+    ///     .keys()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> DeleteKeyBuilder {
+        DeleteKeyBuilder::new(id)
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListKeysBuilder {
+    /// Only list the key with this fingerprint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fingerprint: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListKeysBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to list the authenticated user's SSH keys.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<PublicKey>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<PublicKey>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<PublicKey>> {
+        let req = client.get("user/keys").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListKeysBuilder, PublicKey);
+
+#[derive(Debug, Clone)]
+pub struct GetKeyBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl GetKeyBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+
+    /// Sends the request to get an SSH key of the authenticated user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PublicKey> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PublicKey);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PublicKey>> {
+        let id = self.id;
+        let req = client.get(format!("user/keys/{id}")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateKeyBuilder {
+    #[skip]
+    title: String,
+    #[skip]
+    key: String,
+    /// Whether the key only grants read access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    read_only: Option<bool>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl CreateKeyBuilder {
+    pub fn new(title: impl ToString, key: impl ToString) -> Self {
+        Self {
+            title: title.to_string(),
+            key: key.to_string(),
+            read_only: None,
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to add an SSH key to the authenticated user.
+    /// This will return the created [PublicKey].
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<PublicKey> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(PublicKey);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<PublicKey>> {
+        let req = client.post("user/keys").json(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteKeyBuilder {
+    id: i64,
+    timeout: Option<Duration>,
+}
+
+impl DeleteKeyBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id, timeout: None }
+    }
+
+    /// Sends the request to delete an SSH key of the authenticated user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let id = self.id;
+        let req = client.delete(format!("user/keys/{id}")).build()?;
+        client.make_request(self.with_timeout(req)).await?;
+        Ok(())
+    }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
+}
@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::repos::Repository;
+use crate::pagination::{impl_pageable, Paged};
+use crate::GiteaHttp;
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -11,6 +15,9 @@ pub struct ListReposBuilder {
     page: Option<i64>,
     /// Page size of results
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListReposBuilder {
@@ -18,17 +25,30 @@ impl ListReposBuilder {
         Self {
             page: None,
             limit: None,
+            timeout: None,
         }
     }
 
     /// Send the request to list repositories.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Repository>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
         let req = client.get("user/repos").query(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
 
+impl_pageable!(ListReposBuilder, Repository);
+
 impl Default for ListReposBuilder {
     fn default() -> Self {
         Self::new()
@@ -1,11 +1,22 @@
 pub mod create_repo;
 pub mod current;
+pub mod emails;
+pub mod follow;
+pub mod gpg_keys;
+pub mod keys;
 pub mod list_repos;
 pub mod orgs;
+pub mod quota;
 pub mod settings;
 pub mod starred;
+pub mod stopwatches;
+pub mod subscriptions;
+pub mod teams;
+pub mod times;
 pub mod tokens;
 
+use crate::model::user::TokenScopes;
+
 pub struct User;
 
 impl User {
@@ -106,12 +117,38 @@ impl User {
         orgs::Orgs::new()
     }
 
+    /// Lists the organizations of the currently authenticated user.
+    /// This is the same as [User::orgs], named after
+    /// [Users::list_orgs](crate::api::users::Users::list_orgs) so the current user can be
+    /// queried without knowing their username up front.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_orgs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let orgs = client
+    ///     .user()
+    ///     .list_orgs()
+    ///     .limit(10)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_orgs(&self) -> orgs::Orgs {
+        orgs::Orgs::new()
+    }
+
     /// Creates a new access token for a user.
     /// NOTE: This endpoint requires basic authentication and will fail otherwise.
     ///
     /// # Example
     /// ```
-    /// # use gitea_sdk::{Client, CreateAccessTokenOption, Auth};
+    /// # use gitea_sdk::{Client, Auth, model::user::TokenScope};
     /// # async fn create_token() {
     /// let client = Client::new(
     ///     "https://gitea.example.com",
@@ -119,14 +156,18 @@ impl User {
     /// );
     /// let token = client
     ///     .user()
-    ///     .create_access_token("username", "my-new-token", vec!["write:repository", "read:user"])
+    ///     .create_access_token(
+    ///         "username",
+    ///         "my-new-token",
+    ///         TokenScope::WriteRepository | TokenScope::ReadUser,
+    ///     )
     ///     .send(&client)
     ///     .await
     ///     .unwrap();
-    /// println!("Token {} created: {}", token.name, token.sha1);
+    /// println!("Token {} created", token.name);
     /// let new_client = Client::new(
     ///     "https://gitea.example.com",
-    ///     Auth::Token(token.sha1)
+    ///     Auth::Token(token.sha1.expose_secret())
     /// );
     /// # }
     /// ```
@@ -141,7 +182,7 @@ impl User {
         &self,
         user: impl ToString,
         name: impl ToString,
-        scopes: Vec<impl ToString>,
+        scopes: impl Into<TokenScopes>,
     ) -> tokens::CreateAccessTokenBuilder {
         tokens::CreateAccessTokenBuilder::new(user, name, scopes)
     }
@@ -356,4 +397,326 @@ impl User {
     ) -> starred::UnstarRepoBuilder {
         starred::UnstarRepoBuilder::new(owner, repo)
     }
+
+    /// Lists the authenticated user's running stopwatches.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_stopwatches() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let stopwatches = client
+    ///     .user()
+    ///     .stopwatches()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn stopwatches(&self) -> stopwatches::ListStopwatchesBuilder {
+        stopwatches::ListStopwatchesBuilder::new()
+    }
+
+    /// Lists the teams the authenticated user belongs to, across all organizations.
+    /// Each returned [Team](crate::model::team::Team) includes the organization it belongs to.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .user()
+    ///     .teams()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn teams(&self) -> teams::ListTeamsBuilder {
+        teams::ListTeamsBuilder::new()
+    }
+
+    /// Manages the SSH keys of the authenticated user.
+    /// See [keys::Keys] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn keys() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let keys = client
+    ///     .user()
+    ///     .keys()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn keys(&self) -> keys::Keys {
+        keys::Keys
+    }
+
+    /// Manages the GPG keys of the authenticated user.
+    /// See [gpg_keys::GPGKeys] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn gpg_keys() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let keys = client
+    ///     .user()
+    ///     .gpg_keys()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn gpg_keys(&self) -> gpg_keys::GPGKeys {
+        gpg_keys::GPGKeys
+    }
+
+    /// Manages the email addresses of the authenticated user.
+    /// See [emails::Emails] for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn emails() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let emails = client
+    ///     .user()
+    ///     .emails()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn emails(&self) -> emails::Emails {
+        emails::Emails
+    }
+
+    /// Lists the users following the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_followers() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let users = client
+    ///     .user()
+    ///     .followers()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn followers(&self) -> follow::ListFollowersBuilder {
+        follow::ListFollowersBuilder::new()
+    }
+
+    /// Lists the users the authenticated user is following.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_following() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let users = client
+    ///     .user()
+    ///     .following()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn following(&self) -> follow::ListFollowingBuilder {
+        follow::ListFollowingBuilder::new()
+    }
+
+    /// Follows a user as the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn follow() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .user()
+    ///     .follow("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn follow(&self, username: impl ToString) -> follow::FollowBuilder {
+        follow::FollowBuilder::new(username)
+    }
+
+    /// Unfollows a user as the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn unfollow() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .user()
+    ///     .unfollow("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn unfollow(&self, username: impl ToString) -> follow::UnfollowBuilder {
+        follow::UnfollowBuilder::new(username)
+    }
+
+    /// Checks if the authenticated user follows a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_following() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let is_following = client
+    ///     .user()
+    ///     .is_following("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_following(&self, username: impl ToString) -> follow::IsFollowingBuilder {
+        follow::IsFollowingBuilder::new(username)
+    }
+
+    /// Manages the webhooks of the authenticated user.
+    /// See [Hooks](crate::api::hooks::Hooks) for the available operations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .user()
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> crate::api::hooks::Hooks {
+        crate::api::hooks::Hooks {
+            path: "user/hooks".to_string(),
+        }
+    }
+
+    /// Lists the repositories watched by the authenticated user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_subscriptions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let repos = client
+    ///     .user()
+    ///     .subscriptions()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn subscriptions(&self) -> subscriptions::ListSubscriptionsBuilder {
+        subscriptions::ListSubscriptionsBuilder::new()
+    }
+
+    /// Lists the times tracked by the authenticated user across all repositories.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, Timestamp};
+    /// # async fn list_times() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let since: Timestamp = "2024-01-01T00:00:00Z".parse().unwrap();
+    /// let times = client
+    ///     .user()
+    ///     .times()
+    ///     .since(since)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn times(&self) -> times::ListTimesBuilder {
+        times::ListTimesBuilder::new()
+    }
+
+    /// Gets the quota of the authenticated user, including their usage and the groups they are in.
+    /// This is only available on Forgejo instances with quota support enabled.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_quota() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let quota = client
+    ///     .user()
+    ///     .quota()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn quota(&self) -> quota::GetQuotaBuilder {
+        quota::GetQuotaBuilder::new()
+    }
 }
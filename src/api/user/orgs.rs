@@ -1,12 +1,21 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{model::orgs::Organization, Client};
+use crate::{
+    model::orgs::Organization,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
 
-#[derive(Debug, Default, Builder, Serialize)]
+#[derive(Debug, Default, Clone, Builder, Serialize)]
 pub struct Orgs {
     page: Option<i64>,
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl Orgs {
@@ -14,9 +23,21 @@ impl Orgs {
         Self::default()
     }
     /// Send the request to get the current user's organizations.
-    pub async fn send(&self, client: &Client) -> crate::Result<Vec<Organization>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> crate::Result<Vec<Organization>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Organization>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> crate::Result<Paged<Organization>> {
         let req = client.get("user/orgs").query(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(Orgs, Organization);
@@ -0,0 +1,36 @@
+//! The quota of the authenticated user.
+//! Quotas are a Forgejo feature: Gitea (as of the 1.23 API) has no such endpoint and answers 404.
+use std::time::Duration;
+
+use crate::{error::Result, model::quota::QuotaInfo, response::ApiResponse, GiteaHttp};
+
+#[derive(Default, Debug, Clone)]
+pub struct GetQuotaBuilder {
+    timeout: Option<Duration>,
+}
+
+impl GetQuotaBuilder {
+    pub fn new() -> Self {
+        Self { timeout: None }
+    }
+
+    /// Sends the request to get the authenticated user's quota.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<QuotaInfo> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(QuotaInfo);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<QuotaInfo>> {
+        let req = client.get("user/quota").build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
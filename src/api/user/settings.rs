@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::user::UserSettings, Client};
+use crate::{error::Result, model::user::UserSettings, response::ApiResponse, GiteaHttp};
 
 #[derive(Default, Debug)]
-pub struct GetSettingsBuilder;
+pub struct GetSettingsBuilder {
+    timeout: Option<Duration>,
+}
 
 #[derive(Default, Debug, Builder, Serialize)]
 #[build_it(into)]
@@ -31,18 +35,34 @@ pub struct UpdateSettingsBuilder {
     theme: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     website: Option<String>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl GetSettingsBuilder {
     pub fn new() -> Self {
-        Self
+        Self { timeout: None }
     }
 
     /// Sends the request to get the user's settings.
-    pub async fn send(self, client: &Client) -> Result<UserSettings> {
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<UserSettings> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(self, UserSettings);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<UserSettings>> {
         let req = client.get("user/settings").build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
 
@@ -52,9 +72,22 @@ impl UpdateSettingsBuilder {
     }
 
     /// Sends the request to update the user's settings.
-    pub async fn send(self, client: &Client) -> Result<UserSettings> {
+    pub async fn send(self, client: &impl GiteaHttp) -> Result<UserSettings> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(self, UserSettings);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<UserSettings>> {
         let req = client.patch("user/settings").json(&self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,8 +1,15 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use reqwest::StatusCode;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result,
+    model::repos::Repository,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
 
 #[derive(Default, Debug, Clone, Serialize, Builder)]
 pub struct ListStarredBuilder {
@@ -10,24 +17,30 @@ pub struct ListStarredBuilder {
     page: Option<i64>,
     /// Page size of results
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
 pub struct StarRepoBuilder {
     owner: String,
     repo: String,
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
 pub struct UnstarRepoBuilder {
     owner: String,
     repo: String,
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
 pub struct IsStarredBuilder {
     owner: String,
     repo: String,
+    timeout: Option<Duration>,
 }
 
 impl ListStarredBuilder {
@@ -36,30 +49,47 @@ impl ListStarredBuilder {
     }
 
     /// Sends the request to list the user's starred repos.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
         let req = client.get("/user/starred").query(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
 
+impl_pageable!(ListStarredBuilder, Repository);
+
 impl StarRepoBuilder {
     pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
         Self {
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
 
     /// Sends the request to star the repo.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { owner, repo } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { owner, repo, .. } = self;
         let req = client
             .put(format!("/user/starred/{owner}/{repo}"))
             .build()?;
-        let _ = client.make_request(req).await?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
 
 impl UnstarRepoBuilder {
@@ -67,18 +97,23 @@ impl UnstarRepoBuilder {
         Self {
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
 
     /// Sends the request to star the repo.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { owner, repo } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let Self { owner, repo, .. } = self;
         let req = client
             .delete(format!("/user/starred/{owner}/{repo}"))
             .build()?;
-        let _ = client.make_request(req).await?;
+        let _ = client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
 
 impl IsStarredBuilder {
@@ -86,16 +121,17 @@ impl IsStarredBuilder {
         Self {
             owner: owner.to_string(),
             repo: repo.to_string(),
+            timeout: None,
         }
     }
 
     /// Sends the request to star the repo.
-    pub async fn send(&self, client: &Client) -> Result<bool> {
-        let Self { owner, repo } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<bool> {
+        let Self { owner, repo, .. } = self;
         let req = client
             .get(format!("/user/starred/{owner}/{repo}"))
             .build()?;
-        match client.make_request(req).await {
+        match client.make_request(self.with_timeout(req)).await {
             Ok(_) => Ok(true),
             Err(e) => {
                 if e.status_code == StatusCode::NOT_FOUND {
@@ -106,4 +142,8 @@ impl IsStarredBuilder {
             }
         }
     }
+
+    crate::transport::send_blocking!(bool);
+
+    crate::transport::request_timeout!();
 }
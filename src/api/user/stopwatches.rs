@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::issues::StopWatch,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+pub struct ListStopwatchesBuilder {
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListStopwatchesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to list the authenticated user's running stopwatches.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<StopWatch>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<StopWatch>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<StopWatch>> {
+        let req = client.get("user/stopwatches").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListStopwatchesBuilder, StopWatch);
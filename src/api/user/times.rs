@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::issues::TrackedTime,
+    pagination::{impl_pageable, Paged},
+    timestamp::Timestamp,
+    GiteaHttp,
+};
+
+/// Options for listing the times tracked by the authenticated user.
+/// All fields are optional.
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTimesBuilder {
+    /// Only show times updated after the given time. This is a timestamp in RFC 3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<Timestamp>,
+    /// Only show times updated before the given time. This is a timestamp in RFC 3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<Timestamp>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListTimesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to list the authenticated user's tracked times.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<TrackedTime>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<TrackedTime>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<TrackedTime>> {
+        let req = client.get("user/times").query(self).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListTimesBuilder, TrackedTime);
@@ -1,8 +1,13 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
 use crate::error::Result;
-use crate::model::user::AccessToken;
+use crate::model::user::{AccessToken, TokenScopes};
+use crate::pagination::{impl_pageable, Paged};
+use crate::response::ApiResponse;
+use crate::GiteaHttp;
 
 #[derive(Debug, Clone, Builder, Serialize)]
 pub struct ListAccessTokensBuilder {
@@ -14,6 +19,9 @@ pub struct ListAccessTokensBuilder {
     page: Option<i64>,
     /// Page size of results.
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -24,7 +32,9 @@ pub struct CreateAccessTokenBuilder {
     /// Access token name.
     pub name: String,
     /// Optional scopes for the access token.
-    pub scopes: Vec<String>,
+    pub scopes: TokenScopes,
+    #[serde(skip)]
+    timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +43,7 @@ pub struct DeleteAccessTokenBuilder {
     pub user: String,
     /// The access token to delete.
     pub token: String,
+    timeout: Option<Duration>,
 }
 
 impl ListAccessTokensBuilder {
@@ -41,38 +52,65 @@ impl ListAccessTokensBuilder {
             username: username.to_string(),
             page: None,
             limit: None,
+            timeout: None,
         }
     }
     /// Sends the request to list access tokens.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<AccessToken>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<AccessToken>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<AccessToken>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<AccessToken>> {
         let username = &self.username;
         let req = client
             .get(format!("users/{username}/tokens"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
 
+impl_pageable!(ListAccessTokensBuilder, AccessToken);
+
 impl CreateAccessTokenBuilder {
-    pub fn new(user: impl ToString, name: impl ToString, scopes: Vec<impl ToString>) -> Self {
+    pub fn new(user: impl ToString, name: impl ToString, scopes: impl Into<TokenScopes>) -> Self {
         Self {
             user: user.to_string(),
             name: name.to_string(),
-            scopes: scopes.into_iter().map(|s| s.to_string()).collect(),
+            scopes: scopes.into(),
+            timeout: None,
         }
     }
 
     /// Sends the request to create the access token.
-    pub async fn send(&self, client: &crate::Client) -> Result<AccessToken> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<AccessToken> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(AccessToken);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<AccessToken>> {
         let username = &self.user;
         let req = client
             .post(format!("users/{username}/tokens"))
             .json(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
 
@@ -81,15 +119,20 @@ impl DeleteAccessTokenBuilder {
         Self {
             user: user.to_string(),
             token: token.to_string(),
+            timeout: None,
         }
     }
     /// Sends the request to delete the access token.
-    pub async fn send(&self, client: &crate::Client) -> Result<()> {
-        let DeleteAccessTokenBuilder { user, token } = self;
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<()> {
+        let DeleteAccessTokenBuilder { user, token, .. } = self;
         let req = client
             .delete(format!("users/{user}/tokens/{token}"))
             .build()?;
-        client.make_request(req).await?;
+        client.make_request(self.with_timeout(req)).await?;
         Ok(())
     }
+
+    crate::transport::send_blocking!(());
+
+    crate::transport::request_timeout!();
 }
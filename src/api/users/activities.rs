@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::{Activity, HeatmapData},
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListActivitiesBuilder {
+    #[build_it(skip)]
+    #[serde(skip)]
+    username: String,
+
+    /// Only list activities performed by the user, not by others on their repositories.
+    #[serde(rename = "only-performed-by", skip_serializing_if = "Option::is_none")]
+    only_performed_by: Option<bool>,
+    /// Only list activities of the given date (YYYY-MM-DD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListActivitiesBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sends the request to list the user's activity feed.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Activity>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Activity>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Activity>> {
+        let username = &self.username;
+        let req = client
+            .get(format!("users/{username}/activities/feeds"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListActivitiesBuilder, Activity);
+
+#[derive(Debug, Clone)]
+pub struct GetHeatmapBuilder {
+    username: String,
+    timeout: Option<Duration>,
+}
+
+impl GetHeatmapBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to get the user's contribution heatmap.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<HeatmapData>> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(Vec<HeatmapData>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> Result<ApiResponse<Vec<HeatmapData>>> {
+        let username = &self.username;
+        let req = client.get(format!("users/{username}/heatmap")).build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
+    }
+}
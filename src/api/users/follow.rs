@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::User,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListFollowersBuilder {
+    #[build_it(skip)]
+    #[serde(skip)]
+    username: String,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListFollowersBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sends the request to list the user's followers.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<User>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<User>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<User>> {
+        let username = &self.username;
+        let req = client
+            .get(format!("users/{username}/followers"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListFollowersBuilder, User);
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListFollowingBuilder {
+    #[build_it(skip)]
+    #[serde(skip)]
+    username: String,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListFollowingBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sends the request to list the users the user is following.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<User>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<User>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<User>> {
+        let username = &self.username;
+        let req = client
+            .get(format!("users/{username}/following"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListFollowingBuilder, User);
+
+#[derive(Debug, Clone)]
+pub struct IsFollowingBuilder {
+    username: String,
+    target: String,
+    timeout: Option<Duration>,
+}
+
+impl IsFollowingBuilder {
+    pub fn new(username: impl ToString, target: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            target: target.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Sends the request to check if the user follows the target user.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<bool> {
+        let Self {
+            username, target, ..
+        } = self;
+        let req = client
+            .get(format!("users/{username}/following/{target}"))
+            .build()?;
+        match client.make_request(self.with_timeout(req)).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.status_code == StatusCode::NOT_FOUND {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    crate::transport::send_blocking!(bool);
+
+    crate::transport::request_timeout!();
+}
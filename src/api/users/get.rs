@@ -1,19 +1,35 @@
+use std::time::Duration;
+
 use crate::error::Result;
 use crate::model::user::User;
+use crate::response::ApiResponse;
+use crate::GiteaHttp;
 
 pub struct GetUserBuilder {
     username: String,
+    timeout: Option<Duration>,
 }
 
 impl GetUserBuilder {
     pub fn new(username: impl ToString) -> Self {
         Self {
             username: username.to_string(),
+            timeout: None,
         }
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<User> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<User> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(User);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(&self, client: &impl GiteaHttp) -> Result<ApiResponse<User>> {
         let req = client.get(format!("users/{}", self.username)).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
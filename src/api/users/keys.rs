@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::user::{GPGKey, PublicKey},
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListKeysBuilder {
+    #[build_it(skip)]
+    #[serde(skip)]
+    username: String,
+
+    /// Only list the key with this fingerprint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fingerprint: Option<String>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListKeysBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sends the request to list the user's public SSH keys.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<PublicKey>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<PublicKey>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<PublicKey>> {
+        let username = &self.username;
+        let req = client
+            .get(format!("users/{username}/keys"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListKeysBuilder, PublicKey);
+
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListGPGKeysBuilder {
+    #[build_it(skip)]
+    #[serde(skip)]
+    username: String,
+
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
+}
+
+impl ListGPGKeysBuilder {
+    pub fn new(username: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sends the request to list the user's GPG keys.
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<GPGKey>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<GPGKey>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<GPGKey>> {
+        let username = &self.username;
+        let req = client
+            .get(format!("users/{username}/gpg_keys"))
+            .query(self)
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(ListGPGKeysBuilder, GPGKey);
@@ -1,7 +1,11 @@
+pub mod activities;
+pub mod follow;
 pub mod get;
+pub mod keys;
 pub mod orgs;
 pub mod repos;
 pub mod stars;
+pub mod subscriptions;
 
 pub struct Users {
     pub username: String,
@@ -104,4 +108,205 @@ impl Users {
     pub fn list_orgs(&self) -> orgs::Orgs {
         orgs::Orgs::new(&self.username)
     }
+
+    /// Gets the permissions the user has in an organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn org_permissions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let permissions = client
+    ///     .users("username")
+    ///     .org_permissions("org-name")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will get the permissions of the user "username" in the organization "org-name".
+    pub fn org_permissions(&self, org: impl ToString) -> orgs::OrgPermissionsBuilder {
+        orgs::OrgPermissionsBuilder::new(&self.username, org)
+    }
+
+    /// Lists the users following a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_followers() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let users = client
+    ///     .users("username")
+    ///     .followers()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn followers(&self) -> follow::ListFollowersBuilder {
+        follow::ListFollowersBuilder::new(&self.username)
+    }
+
+    /// Lists the users a user is following.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_following() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let users = client
+    ///     .users("username")
+    ///     .following()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn following(&self) -> follow::ListFollowingBuilder {
+        follow::ListFollowingBuilder::new(&self.username)
+    }
+
+    /// Checks if a user follows another user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_following() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let is_following = client
+    ///     .users("username")
+    ///     .is_following("other-user")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_following(&self, target: impl ToString) -> follow::IsFollowingBuilder {
+        follow::IsFollowingBuilder::new(&self.username, target)
+    }
+
+    /// Lists the activity feed of a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn activities() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let activities = client
+    ///     .users("username")
+    ///     .activities()
+    ///     .only_performed_by(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn activities(&self) -> activities::ListActivitiesBuilder {
+        activities::ListActivitiesBuilder::new(&self.username)
+    }
+
+    /// Gets the contribution heatmap of a user.
+    /// Each entry holds the number of contributions at a point in time.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn heatmap() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let heatmap = client
+    ///     .users("username")
+    ///     .heatmap()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn heatmap(&self) -> activities::GetHeatmapBuilder {
+        activities::GetHeatmapBuilder::new(&self.username)
+    }
+
+    /// Lists the repositories watched by a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_subscriptions() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let repos = client
+    ///     .users("username")
+    ///     .subscriptions()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn subscriptions(&self) -> subscriptions::ListSubscriptionsBuilder {
+        subscriptions::ListSubscriptionsBuilder::new(&self.username)
+    }
+
+    /// Lists the public SSH keys of a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_keys() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let keys = client
+    ///     .users("username")
+    ///     .keys()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn keys(&self) -> keys::ListKeysBuilder {
+        keys::ListKeysBuilder::new(&self.username)
+    }
+
+    /// Lists the GPG keys of a user.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_gpg_keys() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let keys = client
+    ///     .users("username")
+    ///     .gpg_keys()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn gpg_keys(&self) -> keys::ListGPGKeysBuilder {
+        keys::ListGPGKeysBuilder::new(&self.username)
+    }
 }
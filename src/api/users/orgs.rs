@@ -1,9 +1,16 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{model::orgs::Organization, Client};
+use crate::{
+    model::orgs::{OrgPermissions, Organization},
+    pagination::{impl_pageable, Paged},
+    response::ApiResponse,
+    GiteaHttp,
+};
 
-#[derive(Debug, Default, Builder, Serialize)]
+#[derive(Debug, Default, Clone, Builder, Serialize)]
 #[build_it(into)]
 pub struct Orgs {
     #[serde(skip)]
@@ -11,6 +18,9 @@ pub struct Orgs {
     username: String,
     page: Option<i64>,
     limit: Option<i64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl Orgs {
@@ -19,16 +29,68 @@ impl Orgs {
             page: None,
             limit: None,
             username: username.to_string(),
+            timeout: None,
         }
     }
     /// Send the request to get the user's organizations.
-    pub async fn send(&self, client: &Client) -> crate::Result<Vec<Organization>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> crate::Result<Vec<Organization>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Organization>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> crate::Result<Paged<Organization>> {
         let username = &self.username;
         let req = client
             .get(format!("users/{username}/orgs"))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
+    }
+}
+
+impl_pageable!(Orgs, Organization);
+
+#[derive(Debug, Clone)]
+pub struct OrgPermissionsBuilder {
+    username: String,
+    org: String,
+    timeout: Option<Duration>,
+}
+
+impl OrgPermissionsBuilder {
+    pub fn new(username: impl ToString, org: impl ToString) -> Self {
+        Self {
+            username: username.to_string(),
+            org: org.to_string(),
+            timeout: None,
+        }
+    }
+    /// Send the request to get the user's permissions in an organization.
+    pub async fn send(&self, client: &impl GiteaHttp) -> crate::Result<OrgPermissions> {
+        Ok(self.send_with_response(client).await?.value)
+    }
+
+    crate::transport::send_blocking!(OrgPermissions);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the status code and headers of the response.
+    /// See [ApiResponse] for details.
+    pub async fn send_with_response(
+        &self,
+        client: &impl GiteaHttp,
+    ) -> crate::Result<ApiResponse<OrgPermissions>> {
+        let Self { username, org, .. } = self;
+        let req = client
+            .get(format!("users/{username}/orgs/{org}/permissions"))
+            .build()?;
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_api_response(res).await
     }
 }
@@ -1,9 +1,16 @@
+use std::time::Duration;
+
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result,
+    model::repos::Repository,
+    pagination::{impl_pageable, Paged},
+    GiteaHttp,
+};
 
-#[derive(Default, Debug, Serialize, Builder)]
+#[derive(Default, Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
 pub struct ListStarredBuilder {
     #[build_it(skip)]
@@ -17,6 +24,9 @@ pub struct ListStarredBuilder {
     /// Page size of results.
     #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<u64>,
+    #[serde(skip)]
+    #[skip]
+    timeout: Option<Duration>,
 }
 
 impl ListStarredBuilder {
@@ -28,12 +38,24 @@ impl ListStarredBuilder {
     }
 
     /// Sends the request to get the user's stars.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
+    pub async fn send(&self, client: &impl GiteaHttp) -> Result<Vec<Repository>> {
+        Ok(self.send_paged(client).await?.items)
+    }
+
+    crate::transport::send_blocking!(Vec<Repository>);
+
+    crate::transport::request_timeout!();
+
+    /// Same as `send`, but also returns the total count and page links of the response.
+    /// See [Paged] for details.
+    pub async fn send_paged(&self, client: &impl GiteaHttp) -> Result<Paged<Repository>> {
         let req = client
             .get(format!("/users/{}/starred", self.username))
             .query(self)
             .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        let res = client.make_request(self.with_timeout(req)).await?;
+        client.parse_paged_response(res).await
     }
 }
+
+impl_pageable!(ListStarredBuilder, Repository);
@@ -0,0 +1,61 @@
+//! Running many requests concurrently.
+use std::future::Future;
+
+use futures_util::{stream, StreamExt};
+
+use crate::error::Result;
+
+/// Runs many requests concurrently, with a limit on how many are in flight at once.
+/// Created by [Client::batch](crate::Client::batch).
+///
+/// Requests are passed as the futures returned by the `send` methods of the builders. They are
+/// only started once there is room for them, and a failing request doesn't stop the others.
+///
+/// # Example
+/// ```
+/// # use gitea_sdk::{Client, Auth, model::issues::StateType};
+/// # async fn close_issues() {
+/// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+/// let issues = client.issues("owner", "repo");
+/// let requests = (1..=100).map(|index| {
+///     let builder = issues.edit(index).state(StateType::Closed);
+///     let client = &client;
+///     async move { builder.send(client).await }
+/// });
+/// let results = client.batch().concurrency(8).run(requests).await;
+/// for (index, result) in (1..=100).zip(results) {
+///     if let Err(e) = result {
+///         eprintln!("Failed to close issue #{index}: {e}");
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Batch {
+    concurrency: usize,
+}
+
+impl Default for Batch {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+impl Batch {
+    /// The maximum number of requests to run at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Runs all requests and returns their results in the order the requests were given.
+    pub async fn run<T, F>(self, requests: impl IntoIterator<Item = F>) -> Vec<Result<T>>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        stream::iter(requests)
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
@@ -0,0 +1,101 @@
+//! A synchronous client for applications that don't want to set up an async runtime, enabled by
+//! the `blocking` feature.
+//!
+//! The [blocking::Client](Client) dereferences to the regular [Client](crate::Client), so the
+//! whole builder API is available as usual. Every builder with a `send` method also has a
+//! `send_blocking` method that takes the blocking client and returns the result directly:
+//! ```no_run
+//! # use gitea_sdk::{Auth, blocking::Client};
+//! # fn x() -> gitea_sdk::error::Result<()> {
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+//! let repo = client.repos("owner", "repo").get().send_blocking(&client)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Other futures, e.g. the ones returned by `send_paged` or
+//! [send_all](crate::pagination::Pageable::send_all), can be run to completion with
+//! [Client::block_on] or `.wait(&client)`:
+//! ```no_run
+//! # use gitea_sdk::{Auth, blocking::{Client, Wait}, pagination::Pageable};
+//! # fn x() -> gitea_sdk::error::Result<()> {
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+//! let page = client.block_on(client.user().list_repos().send_paged(&client))?;
+//! let repos = client.user().list_repos().send_all(&client).wait(&client)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! NOTE: The blocking client must not be used from within an async runtime, as blocking the
+//! runtime's threads would panic.
+use std::{fmt::Display, future::Future, ops::Deref};
+
+use reqwest::{Method, Request, RequestBuilder, Response};
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{error::Result, pagination::BoxFuture, Auth, GiteaHttp};
+
+/// A Gitea client whose requests are run to completion on an internal runtime.
+/// See the [module documentation](self) for details.
+pub struct Client {
+    client: crate::Client,
+    runtime: Runtime,
+}
+
+impl Client {
+    /// Creates a new blocking Gitea client with the given base URL and authentication.
+    /// See [Client::new](crate::Client::new) for details.
+    pub fn new(base_url: impl ToString, auth: Auth<impl ToString>) -> Self {
+        Self::from(crate::Client::new(base_url, auth))
+    }
+
+    /// Runs the given future (usually returned by a `send` method) to completion, blocking the
+    /// current thread until it finished.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// Wraps an already configured async client, e.g. one with a [RetryPolicy](crate::retry::RetryPolicy).
+impl From<crate::Client> for Client {
+    fn from(client: crate::Client) -> Self {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("runtime build error");
+        Self { client, runtime }
+    }
+}
+
+impl Deref for Client {
+    type Target = crate::Client;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+/// Lets builders be sent with a blocking client as well, e.g. `send(&client)`.
+impl GiteaHttp for Client {
+    fn request_base(&self, method: Method, path: impl Display) -> RequestBuilder {
+        self.client.request_base(method, path)
+    }
+
+    fn make_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        GiteaHttp::make_request(&self.client, req)
+    }
+
+    fn make_download_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        self.client.make_download_request(req)
+    }
+}
+
+/// Runs a future to completion using a blocking [Client].
+pub trait Wait: Future + Sized {
+    /// Blocks the current thread until the future finished and returns its output.
+    fn wait(self, client: &Client) -> Self::Output {
+        client.block_on(self)
+    }
+}
+
+impl<F: Future> Wait for F {}
@@ -0,0 +1,163 @@
+//! Caching of responses using conditional requests.
+//!
+//! Caching is disabled by default. Enable it by passing a [ResponseCache] to
+//! [Client::with_cache](crate::Client::with_cache):
+//! ```
+//! # use gitea_sdk::{Client, Auth, cache::ResponseCache};
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+//!     .with_cache(ResponseCache::new());
+//! ```
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Mutex,
+};
+
+use reqwest::{
+    header::{
+        HeaderMap, HeaderValue, AUTHORIZATION, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+        LAST_MODIFIED,
+    },
+    Method, Request, Response, StatusCode,
+};
+
+/// Remembers the `ETag` and `Last-Modified` headers of responses to GET requests.
+///
+/// When the same URL is requested again, the client sends `If-None-Match` and
+/// `If-Modified-Since` headers. If the server responds with 304 Not Modified, the cached
+/// response is returned instead, so unchanged resources don't need to be transferred again.
+/// This is especially useful for polling, e.g. for new notifications or issues.
+///
+/// Cached bodies are kept in memory, so the number of cached URLs can be limited with
+/// [ResponseCache::max_entries]. Once the limit is reached, no new responses are cached.
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    max_entries: Option<usize>,
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl CachedResponse {
+    fn to_response(&self) -> Response {
+        let mut res = http::Response::new(self.body.clone());
+        *res.status_mut() = self.status;
+        *res.headers_mut() = self.headers.clone();
+        Response::from(res)
+    }
+}
+
+impl ResponseCache {
+    /// Creates an empty cache without a limit on the number of entries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of URLs to cache responses for.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, CachedResponse>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Adds conditional headers to the request if a response for its URL is cached.
+    pub(crate) fn prepare(&self, req: &mut Request) {
+        if req.method() != Method::GET {
+            return;
+        }
+        let entries = self.entries();
+        let Some(cached) = entries.get(&Self::key(req)) else {
+            return;
+        };
+        let headers = req.headers_mut();
+        if headers.contains_key(IF_NONE_MATCH) || headers.contains_key(IF_MODIFIED_SINCE) {
+            return;
+        }
+        if let Some(etag) = &cached.etag {
+            headers.insert(IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+        }
+    }
+
+    /// Returns the key the response to the request is cached under.
+    /// Requests made with different credentials, or on behalf of another user (see
+    /// [Client::sudo](crate::Client::sudo)), are cached separately, so clients sharing a cache
+    /// never see each other's private responses.
+    pub(crate) fn key(req: &Request) -> String {
+        let mut key = req.url().to_string();
+        if let Some(auth) = req.headers().get(AUTHORIZATION) {
+            // Only a hash of the credentials is kept, not the credentials themselves.
+            let mut hasher = DefaultHasher::new();
+            auth.as_bytes().hash(&mut hasher);
+            key.push_str(&format!(" (auth {:016x})", hasher.finish()));
+        }
+        if let Some(sudo) = req
+            .headers()
+            .get("Sudo")
+            .and_then(|sudo| sudo.to_str().ok())
+        {
+            key.push_str(&format!(" (sudo {sudo})"));
+        }
+        key
+    }
+
+    /// Returns the cached response if the server responded with 304 Not Modified, and caches
+    /// the response if it carries an `ETag` or `Last-Modified` header.
+    pub(crate) async fn process(
+        &self,
+        method: &Method,
+        key: &str,
+        res: Response,
+    ) -> reqwest::Result<Response> {
+        if method != Method::GET {
+            return Ok(res);
+        }
+        if res.status() == StatusCode::NOT_MODIFIED {
+            return Ok(match self.entries().get(key) {
+                Some(cached) => cached.to_response(),
+                None => res,
+            });
+        }
+        let etag = res.headers().get(ETAG).cloned();
+        let last_modified = res.headers().get(LAST_MODIFIED).cloned();
+        if !res.status().is_success() || (etag.is_none() && last_modified.is_none()) {
+            return Ok(res);
+        }
+        {
+            let entries = self.entries();
+            let full = self
+                .max_entries
+                .is_some_and(|max| entries.len() >= max && !entries.contains_key(key));
+            if full {
+                return Ok(res);
+            }
+        }
+        let cached = CachedResponse {
+            etag,
+            last_modified,
+            status: res.status(),
+            headers: res.headers().clone(),
+            body: res.bytes().await?.to_vec(),
+        };
+        let res = cached.to_response();
+        self.entries().insert(key.to_string(), cached);
+        Ok(res)
+    }
+}
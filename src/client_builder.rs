@@ -0,0 +1,505 @@
+//! Configuration of the HTTP client used by a [Client].
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{net::SocketAddr, time::Duration};
+
+use base64::{
+    alphabet,
+    engine::{GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use reqwest::header::{self, HeaderMap, HeaderValue};
+
+use crate::{
+    error::{Result, TeatimeError, TeatimeErrorKind},
+    Auth, Client, TokenProvider,
+};
+
+/// Builds a [Client] with custom HTTP options, like timeouts, proxies or TLS settings.
+/// Unlike [Client::new], building the client returns an error instead of panicking if the
+/// configuration is invalid.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use gitea_sdk::{Client, Auth};
+/// # fn x() -> gitea_sdk::error::Result<()> {
+/// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientBuilder {
+    base_url: String,
+    headers: HeaderMap,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    http: reqwest::ClientBuilder,
+    error: Option<TeatimeError>,
+}
+
+/// The authentication header is marked as sensitive, so printing a builder with `{:?}` doesn't
+/// leak it.
+impl std::fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("base_url", &self.base_url)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClientBuilder {
+    pub(crate) fn new(base_url: impl ToString, auth: Auth<impl ToString>) -> Self {
+        let mut builder = Self {
+            base_url: base_url.to_string(),
+            headers: HeaderMap::new(),
+            token_provider: None,
+            http: reqwest::ClientBuilder::new(),
+            error: None,
+        };
+        match auth {
+            Auth::Token(token) => builder.set_header(
+                header::AUTHORIZATION,
+                format!("token {}", token.to_string()),
+            ),
+            Auth::Basic(user, pass) => {
+                let engine = GeneralPurpose::new(&alphabet::STANDARD, GeneralPurposeConfig::new());
+                let base = engine.encode(format!("{}:{}", user.to_string(), pass.to_string()));
+                builder.set_header(header::AUTHORIZATION, format!("Basic {base}"));
+            }
+            Auth::Dynamic(provider) => builder.token_provider = Some(provider),
+            Auth::None => {}
+        };
+        builder.set_header(header::ACCEPT, "application/json".to_string());
+        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        builder.set_header(header::USER_AGENT, user_agent);
+        builder
+    }
+
+    /// Sets a header sent with every request, remembering the error if the value is invalid.
+    fn set_header(&mut self, name: header::HeaderName, value: String) {
+        match HeaderValue::from_str(&value) {
+            Ok(mut value) => {
+                mark_sensitive(&name, &mut value);
+                self.headers.insert(name, value);
+            }
+            Err(e) => {
+                self.error.get_or_insert(TeatimeError {
+                    message: format!("Invalid value for header {name}: {e}"),
+                    kind: TeatimeErrorKind::Other,
+                    status_code: reqwest::StatusCode::BAD_REQUEST,
+                    request_id: None,
+                });
+            }
+        }
+    }
+
+    /// Sets a header that is sent with every request, e.g. a header required by a corporate
+    /// proxy. Headers set on a request itself take precedence.
+    ///
+    /// Setting the `Authorization` header replaces the authentication passed to
+    /// [Client::builder].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # fn x() -> gitea_sdk::error::Result<()> {
+    /// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .default_header("X-Forwarded-For", "203.0.113.7")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_header(mut self, name: impl AsRef<str>, value: impl ToString) -> Self {
+        let name = name.as_ref();
+        match header::HeaderName::from_bytes(name.as_bytes()) {
+            Ok(name) => self.set_header(name, value.to_string()),
+            Err(e) => {
+                self.error.get_or_insert(TeatimeError {
+                    message: format!("Invalid header name {name}: {e}"),
+                    kind: TeatimeErrorKind::Other,
+                    status_code: reqwest::StatusCode::BAD_REQUEST,
+                    request_id: None,
+                });
+            }
+        }
+        self
+    }
+
+    /// Sets multiple headers that are sent with every request.
+    /// See [ClientBuilder::default_header] for details.
+    ///
+    /// All values of a header are sent, and they replace the values previously set for it.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        let mut current = None;
+        for (name, mut value) in headers {
+            // Only the first value of a header carries its name, the others belong to the same one.
+            if let Some(name) = name {
+                self.headers.remove(&name);
+                current = Some(name);
+            }
+            if let Some(name) = &current {
+                mark_sensitive(name, &mut value);
+                self.headers.append(name, value);
+            }
+        }
+        self
+    }
+
+    /// Replaces the `User-Agent` header, which defaults to `gitea-sdk/<version>`.
+    pub fn user_agent(mut self, user_agent: impl ToString) -> Self {
+        self.set_header(header::USER_AGENT, user_agent.to_string());
+        self
+    }
+
+    /// Appends the given product, e.g. `my-app/1.0`, to the `User-Agent` header, so the server
+    /// can tell which application is using the SDK.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # fn x() -> gitea_sdk::error::Result<()> {
+    /// // Sends `User-Agent: gitea-sdk/<version> my-app/1.0`.
+    /// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .user_agent_suffix("my-app/1.0")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_agent_suffix(mut self, suffix: impl ToString) -> Self {
+        let user_agent = self
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        let user_agent = format!("{user_agent} {}", suffix.to_string());
+        self.set_header(header::USER_AGENT, user_agent.trim_start().to_string());
+        self
+    }
+
+    /// Sets the timeout for connecting to the server.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.connect_timeout(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read from the connection.
+    /// The timeout is reset after every successful read, so long downloads don't time out.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.read_timeout(timeout);
+        self
+    }
+
+    /// Sets the timeout for a whole request, from connecting until the response body has been
+    /// read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.timeout(timeout);
+        self
+    }
+
+    /// Sends all requests through the given proxy. Can be called multiple times to add
+    /// proxies for different schemes.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http = self.http.proxy(proxy);
+        self
+    }
+
+    /// Disables the proxies configured through the environment (e.g. `HTTPS_PROXY`).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn no_proxy(mut self) -> Self {
+        self.http = self.http.no_proxy();
+        self
+    }
+
+    /// Trusts an additional root certificate, e.g. for instances using a self-signed or
+    /// company-internal certificate authority.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_root_certificate(mut self, cert: reqwest::Certificate) -> Self {
+        self.http = self.http.add_root_certificate(cert);
+        self
+    }
+
+    /// Disables certificate validation.
+    ///
+    /// WARNING: This makes the connection vulnerable to man-in-the-middle attacks. Prefer
+    /// [ClientBuilder::add_root_certificate] and only use this for local testing.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.http = self.http.danger_accept_invalid_certs(accept_invalid_certs);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host. Defaults to no limit.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http = self.http.pool_max_idle_per_host(max);
+        self
+    }
+
+    /// Sets how long idle connections are kept open, or `None` to keep them open forever.
+    /// Defaults to 90 seconds.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http = self.http.pool_idle_timeout(timeout);
+        self
+    }
+
+    /// Sets the interval of TCP keepalive probes, or `None` to disable them (the default).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        self.http = self.http.tcp_keepalive(interval);
+        self
+    }
+
+    /// Sets whether `TCP_NODELAY` is set on connections. Defaults to `true`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tcp_nodelay(mut self, enabled: bool) -> Self {
+        self.http = self.http.tcp_nodelay(enabled);
+        self
+    }
+
+    /// Only uses HTTP/1.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http1_only(mut self) -> Self {
+        self.http = self.http.http1_only();
+        self
+    }
+
+    /// Only uses HTTP/2, without negotiating the protocol first. Only use this if the server is
+    /// known to support HTTP/2, e.g. with h2c behind a proxy.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http = self.http.http2_prior_knowledge();
+        self
+    }
+
+    /// Sets the interval of HTTP/2 keepalive pings, or `None` to disable them (the default).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http2_keep_alive_interval(mut self, interval: Option<Duration>) -> Self {
+        self.http = self.http.http2_keep_alive_interval(interval);
+        self
+    }
+
+    /// Sets how long to wait for the answer to an HTTP/2 keepalive ping before closing the
+    /// connection. Defaults to 20 seconds.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http2_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.http2_keep_alive_timeout(timeout);
+        self
+    }
+
+    /// Sets whether HTTP/2 keepalive pings are also sent while no requests are in flight.
+    /// Defaults to `false`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http2_keep_alive_while_idle(mut self, enabled: bool) -> Self {
+        self.http = self.http.http2_keep_alive_while_idle(enabled);
+        self
+    }
+
+    /// Sets whether the HTTP/2 flow control window adapts to the bandwidth of the connection.
+    /// Defaults to `false`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http = self.http.http2_adaptive_window(enabled);
+        self
+    }
+
+    /// Resolves the given domain to the given address instead of using DNS.
+    /// The port of the address is ignored in favor of the port of the base URL.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # fn x() -> gitea_sdk::error::Result<()> {
+    /// // Talk to a local instance while still sending the right `Host` header and SNI.
+    /// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .resolve("gitea.example.com", "127.0.0.1:0".parse().unwrap())
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn resolve(mut self, domain: &str, addr: SocketAddr) -> Self {
+        self.http = self.http.resolve(domain, addr);
+        self
+    }
+
+    /// Resolves the given domain to the given addresses instead of using DNS.
+    /// The ports of the addresses are ignored in favor of the port of the base URL.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn resolve_to_addrs(mut self, domain: &str, addrs: &[SocketAddr]) -> Self {
+        self.http = self.http.resolve_to_addrs(domain, addrs);
+        self
+    }
+
+    /// Uses the given resolver to look up domains instead of the system resolver.
+    /// Overrides set with [ClientBuilder::resolve] still take precedence.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn dns_resolver<R: reqwest::dns::Resolve + 'static>(mut self, resolver: Arc<R>) -> Self {
+        self.http = self.http.dns_resolver(resolver);
+        self
+    }
+
+    /// Sends all requests through the given Unix domain socket, e.g. to talk to a sidecar proxy
+    /// or a Gitea instance listening on a socket. The base URL is still used for the `Host`
+    /// header, and TLS is used if its scheme is `https`.
+    ///
+    /// This ignores all proxy and TCP options.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # fn x() -> gitea_sdk::error::Result<()> {
+    /// let client = Client::builder("http://localhost", Auth::Token("your-token"))
+    ///     .unix_socket("/run/gitea/gitea.sock")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub fn unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.http = self.http.unix_socket(path.into());
+        self
+    }
+
+    /// Authenticates with the given client certificate during the TLS handshake, e.g. for
+    /// instances behind a proxy that requires mutual TLS.
+    ///
+    /// Requires the `native-tls` or `rustls-tls` feature. With `native-tls`, the identity can be
+    /// loaded from a PKCS#12 archive ([reqwest::Identity::from_pkcs12_der]) or a PEM certificate
+    /// and PKCS#8 key ([reqwest::Identity::from_pkcs8_pem]). With `rustls-tls`, it is loaded from a
+    /// PEM file containing both ([reqwest::Identity::from_pem]).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use gitea_sdk::{Client, Auth};
+    /// # #[cfg(feature = "native-tls")]
+    /// # fn x() -> Result<(), Box<dyn std::error::Error>> {
+    /// let der = std::fs::read("client.p12")?;
+    /// let identity = reqwest::Identity::from_pkcs12_der(&der, "password")?;
+    /// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .identity(identity)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(
+        any(feature = "native-tls", feature = "rustls-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.http = self.http.identity(identity);
+        self
+    }
+
+    /// Enables or disables accepting and decompressing gzip-encoded responses.
+    /// Requires the `gzip` feature, which enables it by default.
+    ///
+    /// Downloads, like artifacts, are always requested uncompressed so they can be streamed.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # #[cfg(feature = "gzip")]
+    /// # fn x() -> gitea_sdk::error::Result<()> {
+    /// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .gzip(false)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.http = self.http.gzip(enable);
+        self
+    }
+
+    /// Enables or disables accepting and decompressing brotli-encoded responses.
+    /// Requires the `brotli` feature, which enables it by default.
+    #[cfg(all(feature = "brotli", not(target_arch = "wasm32")))]
+    pub fn brotli(mut self, enable: bool) -> Self {
+        self.http = self.http.brotli(enable);
+        self
+    }
+
+    /// Enables or disables accepting and decompressing zstd-encoded responses.
+    /// Requires the `zstd` feature, which enables it by default.
+    #[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+    pub fn zstd(mut self, enable: bool) -> Self {
+        self.http = self.http.zstd(enable);
+        self
+    }
+
+    /// Enables or disables accepting and decompressing deflate-encoded responses.
+    /// Requires the `deflate` feature, which enables it by default.
+    #[cfg(all(feature = "deflate", not(target_arch = "wasm32")))]
+    pub fn deflate(mut self, enable: bool) -> Self {
+        self.http = self.http.deflate(enable);
+        self
+    }
+
+    /// Sets the policy for following redirects. By default, up to 10 redirects are followed.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn redirect(mut self, policy: reqwest::redirect::Policy) -> Self {
+        self.http = self.http.redirect(policy);
+        self
+    }
+
+    /// Builds the [Client].
+    /// This will return a [TeatimeError] if the authentication is not a valid header value or
+    /// the HTTP client could not be created, e.g. because a certificate is invalid.
+    pub fn build(mut self) -> Result<Client> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        let http = std::mem::take(&mut self.http);
+        let cli = http.default_headers(self.headers.clone()).build()?;
+        self.build_with(cli)
+    }
+
+    /// Builds the [Client] around an existing HTTP client, ignoring the HTTP options of this
+    /// builder.
+    pub(crate) fn build_with(self, cli: reqwest::Client) -> Result<Client> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        Ok(Client {
+            cli,
+            #[cfg(feature = "middleware")]
+            middleware: None,
+            headers: self.headers,
+            base_url: self.base_url,
+            token_provider: self.token_provider,
+            retry: None,
+            limiter: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            cache: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: None,
+            observers: Default::default(),
+            request_id_header: None,
+            #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+            cassette: None,
+        })
+    }
+}
+
+/// Keeps credentials out of the Debug output of the headers.
+fn mark_sensitive(name: &header::HeaderName, value: &mut HeaderValue) {
+    let sensitive = [
+        header::AUTHORIZATION,
+        header::PROXY_AUTHORIZATION,
+        header::COOKIE,
+    ];
+    if sensitive.contains(name) {
+        value.set_sensitive(true);
+    }
+}
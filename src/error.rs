@@ -1,5 +1,5 @@
 use core::fmt;
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, time::Duration};
 
 use reqwest::StatusCode;
 
@@ -7,6 +7,11 @@ use reqwest::StatusCode;
 pub enum TeatimeErrorKind {
     HttpError,
     SerializationError,
+    /// The server rejected the request because too many requests were sent.
+    /// `retry_after` holds the delay the server asked for in its `Retry-After` header, if any.
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
     Other,
 }
 
@@ -15,6 +20,7 @@ impl Display for TeatimeErrorKind {
         match self {
             TeatimeErrorKind::HttpError => write!(f, "HTTP error"),
             TeatimeErrorKind::SerializationError => write!(f, "Serialization error"),
+            TeatimeErrorKind::RateLimited { .. } => write!(f, "Rate limited"),
             TeatimeErrorKind::Other => write!(f, "error"),
         }
     }
@@ -27,11 +33,18 @@ pub struct TeatimeError {
     pub message: String,
     pub kind: TeatimeErrorKind,
     pub status_code: reqwest::StatusCode,
+    /// The correlation ID the failed request was sent with, if request IDs are enabled with
+    /// [crate::Client::with_request_id]. Search the server logs for it to find the request.
+    pub request_id: Option<String>,
 }
 impl Error for TeatimeError {}
 impl Display for TeatimeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.message)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request ID {request_id})")?;
+        }
+        Ok(())
     }
 }
 
@@ -42,8 +55,10 @@ pub type Result<T> = std::result::Result<T, TeatimeError>;
 /// Converts a [reqwest::Error] into a [TeatimeError].
 /// This method exists for us to be able to directly call the unwrap operator (`?`) on the result
 /// of a [reqwest::Result].
+/// Credentials passed as query parameters (e.g. `?token=...`) are redacted from the message.
 impl From<reqwest::Error> for TeatimeError {
     fn from(err: reqwest::Error) -> Self {
+        let err = redact_url(err);
         let mut kind = TeatimeErrorKind::HttpError;
         if err.is_decode() {
             kind = TeatimeErrorKind::SerializationError;
@@ -52,16 +67,75 @@ impl From<reqwest::Error> for TeatimeError {
             message: format!("{}", err),
             status_code: err.status().unwrap_or(StatusCode::BAD_REQUEST),
             kind,
+            request_id: None,
+        }
+    }
+}
+
+/// Converts a [reqwest_middleware::Error] into a [TeatimeError].
+/// Errors raised by a middleware are reported as [TeatimeErrorKind::Other].
+#[cfg(feature = "middleware")]
+impl From<reqwest_middleware::Error> for TeatimeError {
+    fn from(err: reqwest_middleware::Error) -> Self {
+        match err {
+            reqwest_middleware::Error::Reqwest(err) => err.into(),
+            reqwest_middleware::Error::Middleware(err) => TeatimeError {
+                message: format!("{:#}", err),
+                status_code: StatusCode::BAD_REQUEST,
+                kind: TeatimeErrorKind::Other,
+                request_id: None,
+            },
         }
     }
 }
 
+/// Query parameters that Gitea accepts credentials in.
+pub(crate) const SENSITIVE_PARAMS: &[&str] = &["token", "access_token", "password"];
+
+/// Replaces the values of sensitive query parameters in the URL of the error.
+fn redact_url(err: reqwest::Error) -> reqwest::Error {
+    let Some(url) = err.url() else {
+        return err;
+    };
+    let is_sensitive = |key: &str| SENSITIVE_PARAMS.contains(&key.to_lowercase().as_str());
+    if !url.query_pairs().any(|(key, _)| is_sensitive(&key)) {
+        return err;
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = match is_sensitive(&key) {
+                true => "[REDACTED]".to_string(),
+                false => value.into_owned(),
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    let mut url = url.clone();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    err.with_url(url)
+}
+
 impl From<Box<dyn Error>> for TeatimeError {
     fn from(err: Box<dyn Error>) -> Self {
         TeatimeError {
             message: format!("{}", err),
             status_code: StatusCode::BAD_REQUEST,
             kind: TeatimeErrorKind::Other,
+            request_id: None,
+        }
+    }
+}
+
+/// Converts a [std::io::Error] into a [TeatimeError].
+/// This happens when writing a downloaded file to its destination fails.
+impl From<std::io::Error> for TeatimeError {
+    fn from(err: std::io::Error) -> Self {
+        TeatimeError {
+            message: format!("{}", err),
+            status_code: StatusCode::BAD_REQUEST,
+            kind: TeatimeErrorKind::Other,
+            request_id: None,
         }
     }
 }
@@ -45,59 +45,180 @@
 //!
 //! If you want to create a new access token for a user, you can call:
 //! ```
-//! # use gitea_sdk::{Client, CreateAccessTokenOption, Auth};
+//! # use gitea_sdk::{Client, Auth, model::user::TokenScope};
 //! # async fn create_access_token() {
 //! let basic = Auth::Basic("username", "password");
 //! let client = Client::new("https://gitea.example.com", basic);
 //! let token = client
 //!     .user()
-//!     .create_access_token("username", "my-new-token", vec!["write:repo"])
+//!     .create_access_token("username", "my-new-token", TokenScope::WriteRepository)
 //!     .send(&client)
 //!     .await
 //!     .unwrap();
-//! println!("Token {} created: {}", token.name, token.sha1);
+//! println!("Token {} created", token.name);
 //! // You can now create a new client with the token and use it to interact with the API.
-//! let new_client = Client::new("https://gitea.example.com", Auth::Token(token.sha1));
+//! let token = token.sha1.expose_secret();
+//! let new_client = Client::new("https://gitea.example.com", Auth::Token(token));
 //! # }
+//! ```
 //!
+//! [Client::login] does both steps in one call.
 //!
-use base64::engine::{GeneralPurpose, GeneralPurposeConfig};
-use base64::{alphabet, Engine};
+#[cfg(not(target_arch = "wasm32"))]
+use cache::ResponseCache;
+use client_builder::ClientBuilder;
 use error::{Result, TeatimeError};
-use std::fmt::Display;
+use model::user::TokenScopes;
+use observe::{Observers, RequestEvent, ResponseEvent};
+use pagination::{Pageable, Paged};
+use response::ApiResponse;
+use retry::RetryPolicy;
+use std::collections::hash_map::RandomState;
+use std::fmt::{self, Display};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use throttle::{Limiter, Throttle};
+use tokio::io::AsyncWrite;
 
+use bytes::Bytes;
+use futures_util::{stream, Stream, TryStreamExt};
 use reqwest::header::{self, HeaderMap, HeaderValue};
 use reqwest::{Method, Response};
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::de::DeserializeOwned;
 
 pub mod error;
 
+/// The error of sending a single request, which depends on whether middleware is supported.
+#[cfg(feature = "middleware")]
+type HttpError = reqwest_middleware::Error;
+#[cfg(not(feature = "middleware"))]
+type HttpError = reqwest::Error;
+type HttpResult = std::result::Result<Response, HttpError>;
+
 pub mod api;
+pub mod batch;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cache;
+pub mod client_builder;
 pub mod model;
+pub mod observe;
+pub mod pagination;
+pub mod response;
+pub mod retry;
+pub mod secret;
+#[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+pub mod test_util;
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub mod testing;
+pub mod throttle;
+mod time;
+pub mod timestamp;
+#[cfg(feature = "tracing")]
+mod trace;
+pub mod transport;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod upload;
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
-pub struct CreateAccessTokenOption {
-    /// Access token name.
-    pub name: String,
-    /// Optional scopes for the access token.
-    pub scopes: Option<Vec<String>>,
-}
+pub use timestamp::Timestamp;
+pub use transport::GiteaHttp;
 
 /// Represents the authentication method to use with the Gitea API.
 pub enum Auth<D: ToString> {
     Token(D),
     Basic(D, D),
+    /// Asks the [TokenProvider] for a token before every request, so expiring tokens can be
+    /// refreshed without creating a new [Client].
+    Dynamic(Arc<dyn TokenProvider>),
     None,
 }
 
+/// The credentials are redacted, so printing them with `{:?}` doesn't leak them.
+impl<D: ToString> fmt::Debug for Auth<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = format_args!("[REDACTED]");
+        match self {
+            Auth::Token(_) => f.debug_tuple("Token").field(&redacted).finish(),
+            Auth::Basic(user, _) => f
+                .debug_tuple("Basic")
+                .field(&user.to_string())
+                .field(&redacted)
+                .finish(),
+            Auth::Dynamic(_) => f.debug_tuple("Dynamic").finish_non_exhaustive(),
+            Auth::None => write!(f, "None"),
+        }
+    }
+}
+
+/// Supplies the token used to authenticate a request.
+/// The token is sent as a bearer token, so both access tokens and OAuth2 tokens work.
+///
+/// [TokenProvider::token] is called before every request. Implementations are expected to cache
+/// the token and only refresh it when it is about to expire.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use gitea_sdk::{error::Result, pagination::BoxFuture, Client, Auth, TokenProvider};
+/// struct EnvToken;
+///
+/// impl TokenProvider for EnvToken {
+///     fn token(&self) -> BoxFuture<'_, Result<String>> {
+///         Box::pin(async { Ok(std::env::var("GITEA_TOKEN").unwrap_or_default()) })
+///     }
+/// }
+///
+/// let client = Client::new(
+///     "https://gitea.example.com",
+///     Auth::Dynamic::<String>(Arc::new(EnvToken)),
+/// );
+/// ```
+pub trait TokenProvider: Send + Sync {
+    /// Returns the token to use for the next request.
+    /// On `wasm32`, the returned future doesn't need to be [Send].
+    fn token(&self) -> pagination::BoxFuture<'_, Result<String>>;
+}
+
 /// Represents a Gitea client.
 ///
 /// This struct is the main way to interact with the Gitea API.
 /// It provides methods for creating repositories, getting repositories, deleting repositories,
 /// and listing a repo's commits.
+///
+/// Cloning a client is cheap: clones share the connection pool, throttle and cache.
+#[derive(Clone)]
 pub struct Client {
     cli: reqwest::Client,
+    #[cfg(feature = "middleware")]
+    middleware: Option<reqwest_middleware::ClientWithMiddleware>,
+    headers: HeaderMap,
     base_url: String,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    retry: Option<RetryPolicy>,
+    limiter: Option<Arc<Limiter>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cache: Option<Arc<ResponseCache>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    observers: Observers,
+    request_id_header: Option<header::HeaderName>,
+    #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+    cassette: Option<test_util::Cassette>,
+}
+
+/// The authentication header is marked as sensitive, so printing a client with `{:?}` doesn't
+/// leak it.
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .field("headers", &self.headers)
+            .field("retry", &self.retry)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Client {
@@ -105,39 +226,312 @@ impl Client {
     /// NOTE: The base URL MUST not include the `/api/v1` path and should not contain any trailing
     /// slashes. For example, `https://gitea.example.com` is a valid base URL, but
     /// `https://gitea.example.com/` or `https://gitea.example.com/api/v1` are not.
+    ///
+    /// # Panics
+    /// Panics if the authentication is not a valid header value or the HTTP client could not be
+    /// created. Use [Client::builder] to handle these errors instead.
     pub fn new(base_url: impl ToString, auth: Auth<impl ToString>) -> Self {
-        let mut headers = HeaderMap::new();
-        match auth {
-            Auth::Token(token) => {
-                let token = HeaderValue::from_str(&format!("token {}", token.to_string()))
-                    .expect("token error");
-                headers.insert(header::AUTHORIZATION, token);
-            }
-            Auth::Basic(user, pass) => {
-                let engine = GeneralPurpose::new(&alphabet::STANDARD, GeneralPurposeConfig::new());
-                let base = engine.encode(format!("{}:{}", user.to_string(), pass.to_string()));
-                let basic =
-                    HeaderValue::from_str(&format!("Basic {base}")).expect("basic auth error");
-                headers.insert(header::AUTHORIZATION, basic);
-            }
-            Auth::None => {}
-        };
-        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
-
-        let cli = reqwest::ClientBuilder::new()
-            .default_headers(headers)
-            .user_agent(format!(
-                "{}/{}",
-                env!("CARGO_PKG_NAME"),
-                env!("CARGO_PKG_VERSION")
-            ))
+        Self::builder(base_url, auth)
             .build()
-            .expect("client build error");
+            .expect("client build error")
+    }
 
-        Self {
-            cli,
-            base_url: base_url.to_string(),
+    /// Creates a new Gitea client that sends its requests through the given [reqwest::Client].
+    /// This allows sharing a single connection pool and HTTP configuration between multiple
+    /// clients and the rest of your application.
+    ///
+    /// The authentication and default headers are added to each request, so the given client
+    /// doesn't need to be configured for Gitea. The same rules for the base URL as for
+    /// [Client::new] apply.
+    ///
+    /// # Panics
+    /// Panics if the authentication is not a valid header value.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// let http = reqwest::Client::new();
+    /// let client = Client::with_http_client(
+    ///     http.clone(),
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token"),
+    /// );
+    /// let other = Client::with_http_client(http, "https://codeberg.org", Auth::Token("other-token"));
+    /// ```
+    pub fn with_http_client(
+        http_client: reqwest::Client,
+        base_url: impl ToString,
+        auth: Auth<impl ToString>,
+    ) -> Self {
+        Self::builder(base_url, auth)
+            .build_with(http_client)
+            .expect("client build error")
+    }
+
+    /// Creates a [ClientBuilder] to configure the HTTP client, e.g. with timeouts or a proxy.
+    /// The same rules for the base URL as for [Client::new] apply.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # fn x() -> gitea_sdk::error::Result<()> {
+    /// let client = Client::builder("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .danger_accept_invalid_certs(true)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder(base_url: impl ToString, auth: Auth<impl ToString>) -> ClientBuilder {
+        ClientBuilder::new(base_url, auth)
+    }
+
+    /// Logs in with a username and password and returns a client that authenticates with a
+    /// newly created access token instead. The same rules for the base URL as for
+    /// [Client::new] apply.
+    ///
+    /// Gitea only reveals the secret of a token when it is created, so if the user already has
+    /// a token with the given name, it is deleted and created again with the given scopes.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, model::user::TokenScope};
+    /// # async fn login() -> gitea_sdk::error::Result<()> {
+    /// let client = Client::login(
+    ///     "https://gitea.example.com",
+    ///     "username",
+    ///     "password",
+    ///     "my-app",
+    ///     TokenScope::WriteRepository | TokenScope::ReadUser,
+    /// )
+    /// .await?;
+    /// let user = client.user().current().send(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login(
+        base_url: impl ToString,
+        username: impl ToString,
+        password: impl ToString,
+        token_name: impl ToString,
+        scopes: impl Into<TokenScopes>,
+    ) -> Result<Self> {
+        let base_url = base_url.to_string();
+        let username = username.to_string();
+        let token_name = token_name.to_string();
+        let auth = Auth::Basic(username.clone(), password.to_string());
+        let basic = Self::builder(&base_url, auth).build()?;
+
+        let tokens = basic
+            .user()
+            .list_access_tokens(&username)
+            .send_all(&basic)
+            .await?;
+        if let Some(existing) = tokens.iter().find(|t| t.name == token_name) {
+            basic
+                .user()
+                .delete_access_token(&username, existing.id)
+                .send(&basic)
+                .await?;
         }
+
+        let token = basic
+            .user()
+            .create_access_token(&username, &token_name, scopes)
+            .send(&basic)
+            .await?;
+        Self::builder(base_url, Auth::Token(token.sha1.expose_secret())).build()
+    }
+
+    /// Enables retrying failed requests according to the given [RetryPolicy].
+    /// See the [retry] module for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, retry::RetryPolicy};
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .with_retry(RetryPolicy::new().max_attempts(5));
+    /// ```
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Limits how fast requests are sent according to the given [Throttle].
+    /// See the [throttle] module for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, throttle::Throttle};
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .with_throttle(Throttle::new().requests_per_second(10));
+    /// ```
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.limiter = Some(Arc::new(Limiter::new(throttle)));
+        self
+    }
+
+    /// Caches responses to GET requests and revalidates them using conditional requests.
+    /// See the [cache] module for details.
+    ///
+    /// The cache is passed as an [Arc], so it can be shared between clients or cleared later on.
+    /// Responses are cached per set of credentials, so clients authenticating as different users
+    /// can safely share a cache.
+    ///
+    /// This is not available on `wasm32`, where the browser already takes care of caching.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use gitea_sdk::{Client, Auth, cache::ResponseCache};
+    /// let cache = Arc::new(ResponseCache::new().max_entries(100));
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .with_cache(cache.clone());
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_cache(mut self, cache: impl Into<Arc<ResponseCache>>) -> Self {
+        self.cache = Some(cache.into());
+        self
+    }
+
+    /// Sends all requests through the given [reqwest_middleware::ClientWithMiddleware], so you
+    /// can plug in your own middleware for tracing, caching or retries.
+    ///
+    /// The authentication and default headers of this client are added to every request before
+    /// it is passed to the middleware. Retries, throttling and caching configured on this client
+    /// still apply.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// let middleware = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .with_middleware(middleware);
+    /// ```
+    #[cfg(feature = "middleware")]
+    pub fn with_middleware(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.middleware = Some(client);
+        self
+    }
+
+    /// Sets the timeout for each request sent by this client, from sending the request until the
+    /// response body has been read. This takes precedence over
+    /// [ClientBuilder::timeout](client_builder::ClientBuilder::timeout).
+    ///
+    /// Downloads and file uploads stream their body and can take arbitrarily long, so this
+    /// timeout doesn't apply to them. A single request can be given its own timeout with the
+    /// `timeout` method of its builder, which also applies to downloads and uploads. To cancel
+    /// a request, simply drop its future.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn migrate_repo() {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .with_timeout(Duration::from_secs(10));
+    /// // Migrations can take a while, so give this request more time.
+    /// let repo = client
+    ///     .migrate_repo("https://example.git.com/owner/repo", "repo")
+    ///     .timeout(Duration::from_secs(600))
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Attaches a random `X-Request-Id` header to every request, so failures can be correlated
+    /// with the server-side logs. The ID is available in [TeatimeError::request_id] if the
+    /// request fails. Requests that already carry the header keep their ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_user() {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+    ///     .with_request_id();
+    /// if let Err(e) = client.user().current().send(&client).await {
+    ///     eprintln!("request {} failed: {}", e.request_id.unwrap_or_default(), e.message);
+    /// }
+    /// # }
+    /// ```
+    pub fn with_request_id(self) -> Self {
+        self.with_request_id_header(header::HeaderName::from_static("x-request-id"))
+    }
+
+    /// Same as [Client::with_request_id], but sends the ID in the given header, e.g. if a proxy
+    /// in front of Gitea expects `X-Correlation-Id`.
+    pub fn with_request_id_header(mut self, name: header::HeaderName) -> Self {
+        self.request_id_header = Some(name);
+        self
+    }
+
+    /// Records the responses of all requests to the given [Cassette](test_util::Cassette), or
+    /// answers them from it without sending them. See the [test_util] module for details.
+    #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+    pub fn with_cassette(mut self, cassette: test_util::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Calls the given function before every request is sent.
+    /// See the [observe] module for details.
+    pub fn on_request(
+        mut self,
+        callback: impl Fn(&RequestEvent<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.observers.on_request.push(Arc::new(callback));
+        self
+    }
+
+    /// Calls the given function after every request has been completed, with the status code of
+    /// the response and the time it took. See the [observe] module for details.
+    pub fn on_response(
+        mut self,
+        callback: impl Fn(&ResponseEvent<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.observers.on_response.push(Arc::new(callback));
+        self
+    }
+
+    /// Returns a client that performs all requests on behalf of the given user by sending the
+    /// `Sudo` header. This requires the client to be authenticated as an admin.
+    ///
+    /// The returned client shares the connection pool, throttle and cache with this one.
+    ///
+    /// # Panics
+    /// Panics if the username contains characters that are not allowed in a header.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn sudo() {
+    /// let admin = Client::new("https://gitea.example.com", Auth::Token("admin-token"));
+    /// let as_user = admin.sudo("username");
+    /// // This creates the repository for "username", not for the admin.
+    /// let repo = as_user
+    ///     .user()
+    ///     .create_repo("my-new-repo")
+    ///     .send(&as_user)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn sudo(&self, username: impl ToString) -> Self {
+        let mut client = self.clone();
+        let username = HeaderValue::from_str(&username.to_string()).expect("invalid username");
+        client.headers.insert("Sudo", username);
+        client
+    }
+
+    /// Creates a [Batch](batch::Batch) to run many requests concurrently, e.g. to label hundreds
+    /// of issues at once. See [Batch](batch::Batch) for an example.
+    pub fn batch(&self) -> batch::Batch {
+        batch::Batch::default()
     }
 
     pub fn repos(&self, owner: impl ToString, repo: impl ToString) -> api::repos::Repos {
@@ -183,6 +577,23 @@ impl Client {
         api::migrate::MigrateRepoBuilder::new(clone_addr, repo_name)
     }
 
+    /// Gets the GPG key the instance uses to sign commits.
+    /// This will return the ASCII-armored public key, which you can import into your keyring to
+    /// verify commits signed by the Gitea instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn signing_key() {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+    /// let key = client.signing_key().send(&client).await.unwrap();
+    /// # }
+    /// ```
+    pub fn signing_key(&self) -> api::signing_key::GetSigningKeyBuilder {
+        api::signing_key::GetSigningKeyBuilder::new()
+    }
+
     pub fn issues(&self, owner: impl ToString, repo: impl ToString) -> api::issues::Issues {
         api::issues::Issues {
             owner: owner.to_string(),
@@ -217,6 +628,24 @@ impl Client {
         }
     }
 
+    pub fn admin(&self) -> api::admin::Admin {
+        api::admin::Admin
+    }
+
+    pub fn notifications(&self) -> api::notifications::Notifications {
+        api::notifications::Notifications
+    }
+
+    pub fn templates(&self) -> api::templates::Templates {
+        api::templates::Templates
+    }
+
+    pub fn packages(&self, owner: impl ToString) -> api::packages::Packages {
+        api::packages::Packages {
+            owner: owner.to_string(),
+        }
+    }
+
     /// Creates a new DELETE-request builder with the given path.
     /// You may use this method to talk to the Gitea API directly if you need to.
     /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
@@ -255,6 +684,101 @@ impl Client {
         self.cli
             .request(method, format!("{}/api/v1/{}", self.base_url, path))
     }
+
+    /// Downloads the response body of a GET request to the given path chunk by chunk, so large
+    /// payloads like archives, raw files or release assets are never held in memory as a whole.
+    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    ///
+    /// The request is sent when the stream is first polled. If it fails, the error is the only
+    /// item of the stream.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # use futures_util::TryStreamExt;
+    /// # async fn download_readme() {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+    /// let mut readme = std::pin::pin!(client.download("repos/owner/repo/raw/README.md"));
+    /// while let Some(chunk) = readme.try_next().await.unwrap() {
+    ///     println!("received {} bytes", chunk.len());
+    /// }
+    /// # }
+    /// ```
+    pub fn download(&self, path: impl Display) -> impl Stream<Item = Result<Bytes>> + '_ {
+        let req = self.get(path).build();
+        stream::once(async move {
+            let res = GiteaHttp::make_download_request(self, req?).await?;
+            Result::Ok(transport::body_stream(res))
+        })
+        .try_flatten()
+    }
+
+    /// Same as [Client::download], but writes the response body to `writer` as it arrives.
+    /// Returns the number of bytes written.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn download_archive() {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+    /// let mut file = tokio::fs::File::create("repo.tar.gz").await.unwrap();
+    /// let written = client
+    ///     .download_to("repos/owner/repo/archive/main.tar.gz", &mut file)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn download_to<W>(&self, path: impl Display, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let req = self.get(path).build()?;
+        let res = GiteaHttp::make_download_request(self, req).await?;
+        transport::copy_response(res, writer).await
+    }
+
+    /// Uploads a file as multipart form data in the given field with a POST request to the
+    /// given path, streaming it instead of holding it in memory. Returns the response, which
+    /// can be parsed with [Client::parse_response].
+    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    ///
+    /// The attachment builders (e.g.
+    /// [Attachments::upload](api::issues::attachments::Attachments::upload)) use this to
+    /// upload files; use it directly for endpoints that don't have a builder.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use gitea_sdk::{Client, Auth, model::issues::Attachment, upload::FileUpload};
+    /// # async fn upload_release_asset() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+    /// let file = tokio::fs::File::open("release.tar.gz").await?;
+    /// let length = file.metadata().await?.len();
+    /// let res = client
+    ///     .upload(
+    ///         "repos/owner/repo/releases/1/assets",
+    ///         "attachment",
+    ///         FileUpload::new("release.tar.gz", file, length),
+    ///     )
+    ///     .await?;
+    /// let asset: Attachment = client.parse_response(res).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn upload(
+        &self,
+        path: impl Display,
+        field: impl Into<std::borrow::Cow<'static, str>>,
+        file: upload::FileUpload,
+    ) -> Result<Response> {
+        let name = file.file_name().to_string();
+        let req = self
+            .post(path)
+            .query(&[("name", name)])
+            .multipart(file.into_form(field))
+            .build()?;
+        self.make_request(req).await
+    }
     /// Sends a request and checks the response for errors.
     /// You may use this method to talk to the Gitea API directly if you need to.
     /// This method will return a [TeatimeError] if the request fails.
@@ -262,17 +786,157 @@ impl Client {
     /// provided by the [Client] struct if they exist.
     /// You are responsible for providing the correct Model for the response.
     pub async fn make_request(&self, req: reqwest::Request) -> Result<Response> {
-        let res = self.cli.execute(req).await?;
+        #[cfg(feature = "tracing")]
+        return trace::record(trace::request_span(&req), self.send_request(req)).await;
+        #[cfg(not(feature = "tracing"))]
+        self.send_request(req).await
+    }
+
+    /// Adds the default headers and authentication to the request, sends it and turns error
+    /// responses into a [TeatimeError].
+    async fn send_request(&self, mut req: reqwest::Request) -> Result<Response> {
+        for name in self.headers.keys() {
+            if !req.headers().contains_key(name) {
+                for value in self.headers.get_all(name) {
+                    req.headers_mut().append(name, value.clone());
+                }
+            }
+        }
+        if let Some(provider) = &self.token_provider {
+            let token = provider.token().await?;
+            let token =
+                HeaderValue::from_str(&format!("Bearer {token}")).map_err(|e| TeatimeError {
+                    message: format!("Invalid token: {e}"),
+                    kind: error::TeatimeErrorKind::Other,
+                    status_code: reqwest::StatusCode::UNAUTHORIZED,
+                    request_id: None,
+                })?;
+            let mut token = token;
+            token.set_sensitive(true);
+            req.headers_mut().insert(header::AUTHORIZATION, token);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (Some(timeout), None) = (self.timeout, req.timeout()) {
+            if !is_download(&req) && !is_streaming_upload(&req) {
+                *req.timeout_mut() = Some(timeout);
+            }
+        }
+        let request_id = self.request_id_header.as_ref().and_then(|name| {
+            let id = req
+                .headers()
+                .get(name)
+                .cloned()
+                .unwrap_or_else(generate_request_id);
+            req.headers_mut().insert(name, id.clone());
+            id.to_str().ok().map(String::from)
+        });
+        let with_id = |mut err: TeatimeError| {
+            err.request_id.clone_from(&request_id);
+            err
+        };
+        let res = if self.observers.is_empty() {
+            self.fetch(req).await
+        } else {
+            self.observers.observe(req, |req| self.fetch(req)).await
+        };
+        let res = res.map_err(with_id)?;
         let status = res.status();
         if status.is_client_error() || status.is_server_error() {
+            let retry_after = retry::retry_after(res.headers());
+            let kind = match status {
+                reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    error::TeatimeErrorKind::RateLimited { retry_after }
+                }
+                reqwest::StatusCode::SERVICE_UNAVAILABLE if retry_after.is_some() => {
+                    error::TeatimeErrorKind::RateLimited { retry_after }
+                }
+                _ => error::TeatimeErrorKind::HttpError,
+            };
             return Err(TeatimeError {
                 message: res.text().await.unwrap_or_default(),
-                kind: error::TeatimeErrorKind::HttpError,
+                kind,
                 status_code: status,
+                request_id,
             });
         }
         Ok(res)
     }
+    /// Sends the request, answering it from the response cache if possible.
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    async fn fetch(&self, mut req: reqwest::Request) -> Result<Response> {
+        #[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+        if let Some(cassette) = &self.cassette {
+            return cassette
+                .fetch(req, |req| self.execute_with_retry(req))
+                .await;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(cache) = self.cache.as_ref().filter(|_| !is_download(&req)) {
+            cache.prepare(&mut req);
+            let method = req.method().clone();
+            let key = ResponseCache::key(&req);
+            let res = self.execute_with_retry(req).await?;
+            return Ok(cache.process(&method, &key, res).await?);
+        }
+        Ok(self.execute_with_retry(req).await?)
+    }
+
+    /// Sends a single request once the [Throttle] of the client allows it.
+    async fn execute(&self, req: reqwest::Request) -> HttpResult {
+        let _permit = match &self.limiter {
+            Some(limiter) => limiter.acquire().await,
+            None => None,
+        };
+        #[cfg(feature = "middleware")]
+        if let Some(middleware) = &self.middleware {
+            return middleware.execute(req).await;
+        }
+        #[cfg(feature = "middleware")]
+        return Ok(self.cli.execute(req).await?);
+        #[cfg(not(feature = "middleware"))]
+        self.cli.execute(req).await
+    }
+
+    /// Sends a request, retrying it according to the [RetryPolicy] of the client.
+    /// Requests with a streaming body can't be cloned and are never retried.
+    async fn execute_with_retry(&self, mut req: reqwest::Request) -> HttpResult {
+        let idempotent = req.method().is_idempotent();
+        let mut attempt = 1;
+        loop {
+            let retry = self
+                .retry
+                .as_ref()
+                .filter(|policy| attempt < policy.max_attempts)
+                .and_then(|policy| Some((policy, req.try_clone()?)));
+            let res = self.execute(req).await;
+            let Some((policy, next)) = retry else {
+                return res;
+            };
+            let idempotent = idempotent || policy.retry_non_idempotent;
+            let delay = match &res {
+                Ok(response) if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    if !policy.retry_rate_limited {
+                        return res;
+                    }
+                    retry::retry_after(response.headers())
+                }
+                Ok(response) if response.status().is_server_error() && idempotent => {
+                    retry::retry_after(response.headers())
+                }
+                Ok(_) => return res,
+                Err(e) if is_retryable(e, idempotent) => None,
+                Err(_) => return res,
+            };
+            let delay = match delay {
+                Some(delay) if delay > policy.max_backoff => return res,
+                Some(delay) => delay,
+                None => policy.backoff(attempt),
+            };
+            time::sleep(delay).await;
+            attempt += 1;
+            req = next;
+        }
+    }
     /// Parses a json response into a given model.
     /// You may use this method to talk to the Gitea API directly if you need to.
     /// This method will return a [TeatimeError] if the response cannot be deserialized.
@@ -280,12 +944,61 @@ impl Client {
     /// provided by the [Client] struct if they exist.
     /// You are responsible for providing the correct Model for the response.
     pub async fn parse_response<T: DeserializeOwned>(&self, res: reqwest::Response) -> Result<T> {
-        let status_code = res.status();
-        let text = res.text().await?;
-        serde_json::from_str(&text).map_err(|e| TeatimeError {
-            message: format!("Error parsing response: {}", e),
-            kind: error::TeatimeErrorKind::SerializationError,
-            status_code,
-        })
+        transport::parse_response(res).await
+    }
+    /// Parses a json response into a given model, keeping the status code and headers of the
+    /// response in an [ApiResponse].
+    /// You may use this method to talk to the Gitea API directly if you need to.
+    /// This method will return a [TeatimeError] if the response cannot be deserialized.
+    /// You are responsible for providing the correct Model for the response.
+    pub async fn parse_api_response<T: DeserializeOwned>(
+        &self,
+        res: reqwest::Response,
+    ) -> Result<ApiResponse<T>> {
+        transport::parse_api_response(res).await
     }
+    /// Parses a json list response into a [Paged] collection, keeping the total count and the
+    /// page links the server sent along with it.
+    /// You may use this method to talk to the Gitea API directly if you need to.
+    /// This method will return a [TeatimeError] if the response cannot be deserialized.
+    /// You are responsible for providing the correct Model for the response.
+    pub async fn parse_paged_response<T: DeserializeOwned>(
+        &self,
+        res: reqwest::Response,
+    ) -> Result<Paged<T>> {
+        transport::parse_paged_response(res).await
+    }
+}
+
+/// Generates a random ID to correlate a request with the server logs.
+fn generate_request_id() -> HeaderValue {
+    // RandomState is seeded randomly, so two hashers give 128 random-enough bits.
+    let random = || RandomState::new().build_hasher().finish();
+    let id = format!("{:016x}{:016x}", random(), random());
+    HeaderValue::from_str(&id).expect("hex is a valid header value")
+}
+
+/// Whether the request was sent by [GiteaHttp::make_download_request], so its response must not
+/// be buffered.
+#[cfg(not(target_arch = "wasm32"))]
+fn is_download(req: &reqwest::Request) -> bool {
+    req.headers()
+        .get(header::ACCEPT_ENCODING)
+        .is_some_and(|encoding| encoding == "identity")
+}
+
+/// Whether the request body is streamed, e.g. a file uploaded with [FileUpload](upload::FileUpload).
+#[cfg(not(target_arch = "wasm32"))]
+fn is_streaming_upload(req: &reqwest::Request) -> bool {
+    req.body().is_some_and(|body| body.as_bytes().is_none())
+}
+
+/// Whether sending a request failed in a way that might go away when trying again.
+/// Requests that might have reached the server are only retried if they are idempotent.
+fn is_retryable(err: &HttpError, idempotent: bool) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    return err.is_connect() || (idempotent && err.is_timeout());
+    // The browser doesn't tell us why a request failed, so any error while sending it counts.
+    #[cfg(target_arch = "wasm32")]
+    return idempotent && (err.is_request() || err.is_timeout());
 }
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+use super::{repos::Repository, user::User};
+use crate::timestamp::Timestamp;
+
+/// Represents an Actions secret.
+/// The value of a secret can never be read back from the API.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Secret {
+    /// The name of the secret.
+    pub name: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+}
+
+/// Represents a workflow run.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionRun {
+    pub id: i64,
+    pub url: String,
+    pub html_url: String,
+    pub display_title: String,
+    /// The path of the workflow file, e.g. "build.yml@refs/heads/main".
+    pub path: String,
+    pub event: String,
+    pub run_attempt: i64,
+    pub run_number: i64,
+    pub head_sha: String,
+    pub head_branch: String,
+    /// The status of the run, e.g. "queued", "in_progress" or "completed".
+    pub status: String,
+    /// The conclusion of a completed run, e.g. "success" or "failure".
+    pub conclusion: String,
+    pub actor: User,
+    pub trigger_actor: User,
+    pub repository: Repository,
+    pub head_repository: Repository,
+    pub repository_id: i64,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub started_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub completed_at: Timestamp,
+}
+
+/// Represents a job of a workflow run.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionJob {
+    pub id: i64,
+    pub url: String,
+    pub html_url: String,
+    pub run_id: i64,
+    pub run_url: String,
+    pub name: String,
+    pub labels: Vec<String>,
+    pub run_attempt: i64,
+    pub head_sha: String,
+    pub head_branch: String,
+    /// The status of the job, e.g. "queued", "in_progress" or "completed".
+    pub status: String,
+    /// The conclusion of a completed job, e.g. "success" or "failure".
+    pub conclusion: String,
+    pub runner_id: i64,
+    pub runner_name: String,
+    pub steps: Vec<ActionStep>,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub started_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub completed_at: Timestamp,
+}
+
+/// Represents a step of a job.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionStep {
+    pub name: String,
+    pub number: i64,
+    pub status: String,
+    pub conclusion: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub started_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub completed_at: Timestamp,
+}
+
+/// Represents an artifact uploaded by a workflow run.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionArtifact {
+    pub id: i64,
+    pub name: String,
+    pub size_in_bytes: i64,
+    pub url: String,
+    pub archive_download_url: String,
+    pub expired: bool,
+    pub workflow_run: ActionArtifactRun,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub expires_at: Timestamp,
+}
+
+/// Represents the workflow run an artifact belongs to.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionArtifactRun {
+    pub id: i64,
+    pub repository_id: i64,
+    pub head_sha: String,
+}
+
+/// Represents an Actions task, a single job execution as seen by the older tasks API.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionTask {
+    pub id: i64,
+    pub name: String,
+    pub display_title: String,
+    pub event: String,
+    pub head_branch: String,
+    pub head_sha: String,
+    pub run_number: i64,
+    /// The status of the task, e.g. "running", "success" or "failure".
+    pub status: String,
+    pub url: String,
+    /// The workflow file the task belongs to, e.g. "build.yml".
+    pub workflow_id: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub run_started_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::timestamp::Timestamp;
+
+/// Represents a cron task of the Gitea instance.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Cron {
+    /// The name of the task, used to run it manually.
+    pub name: String,
+    pub schedule: String,
+    /// How often the task has been executed since the instance started.
+    pub exec_times: i64,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub next: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub prev: Timestamp,
+}
@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::timestamp::Timestamp;
+
+/// Represents a webhook.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hook {
+    pub id: i64,
+    pub r#type: HookType,
+    /// The configuration of the hook, e.g. `url` and `content_type`.
+    pub config: BTreeMap<String, String>,
+    /// The events that trigger the hook, e.g. "push" or "pull_request".
+    pub events: Vec<String>,
+    pub active: bool,
+    pub branch_filter: String,
+    pub authorization_header: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
+}
+
+/// The kind of service a webhook delivers to.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum HookType {
+    Dingtalk,
+    Discord,
+    #[default]
+    Gitea,
+    Gogs,
+    Msteams,
+    Slack,
+    Telegram,
+    Feishu,
+    Wechatwork,
+    Packagist,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
@@ -3,6 +3,7 @@ use std::fmt::{self, Display};
 use serde::{Deserialize, Serialize};
 
 use crate::model::user::User;
+use crate::timestamp::Timestamp;
 
 /// Represents an attachment.
 /// Attachments are used in issues, pull requests, and releases.
@@ -10,7 +11,8 @@ use crate::model::user::User;
 #[serde(default)]
 pub struct Attachment {
     pub browser_download_url: String,
-    pub created_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
     pub download_count: i64,
     pub id: i64,
     pub name: String,
@@ -34,6 +36,7 @@ pub struct Label {
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 /// Represents the state of an issue.
+#[non_exhaustive]
 pub enum State {
     #[serde(rename = "open")]
     Open,
@@ -61,51 +64,172 @@ pub struct Issue {
     pub assignee: Option<User>,
     pub assignees: Option<Vec<User>>,
     pub body: Option<String>,
-    pub closed_at: Option<String>,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub closed_at: Option<Timestamp>,
     pub comments: i64,
-    pub created_at: String,
-    pub due_date: Option<String>,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub due_date: Option<Timestamp>,
     pub html_url: String,
     pub id: i64,
     pub is_locked: bool,
     pub labels: Vec<Label>,
+    pub milestone: Option<Milestone>,
     pub number: i64,
     pub original_author: String,
     pub original_author_id: i64,
     pub pin_order: i64,
+    /// Set if the issue is a pull request.
+    pub pull_request: Option<PullRequestMeta>,
     pub r#ref: String,
+    /// The repository the issue belongs to.
+    pub repository: Option<RepositoryMeta>,
     pub state: StateType,
-    pub updated_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
     pub title: String,
     pub url: String,
     pub user: User,
 }
 
+impl Issue {
+    /// Whether the issue is a pull request. Issue endpoints like search return both.
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+}
+
+/// Represents a milestone in a repository.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Milestone {
+    pub id: i64,
+    pub title: String,
+    pub description: String,
+    pub state: StateType,
+    pub open_issues: i64,
+    pub closed_issues: i64,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub updated_at: Option<Timestamp>,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub closed_at: Option<Timestamp>,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub due_on: Option<Timestamp>,
+}
+
+/// The repository an [Issue] belongs to.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepositoryMeta {
+    pub id: i64,
+    pub name: String,
+    /// The login of the repository owner.
+    pub owner: String,
+    /// The name of the repository including its owner, e.g. "owner/repo".
+    pub full_name: String,
+}
+
+/// Marks an [Issue] as a pull request.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PullRequestMeta {
+    pub draft: bool,
+    pub merged: bool,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub merged_at: Option<Timestamp>,
+    pub html_url: String,
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Comment {
     pub assets: Vec<Attachment>,
     pub body: String,
-    pub created_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
     pub html_url: String,
     pub id: i64,
     pub issue_url: String,
     pub original_author: String,
     pub original_author_id: i64,
     pub pull_request_url: String,
-    pub updated_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
     pub user: User,
 }
 
+/// Represents a reaction to an issue or a comment.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reaction {
+    /// The reaction itself, e.g. "+1", "heart" or "laugh".
+    pub content: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    /// The user who reacted.
+    pub user: Option<User>,
+}
+
+/// Represents time tracked on an issue or pull request.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrackedTime {
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created: Timestamp,
+    pub id: i64,
+    pub issue: Option<Issue>,
+    /// Deprecated, only kept for backwards compatibility. Use `issue` instead.
+    pub issue_id: i64,
+    /// The tracked time in seconds.
+    pub time: i64,
+    /// Deprecated, only kept for backwards compatibility. Use `user_name` instead.
+    pub user_id: i64,
+    pub user_name: String,
+}
+
+/// Represents a running stopwatch on an issue or pull request.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StopWatch {
+    /// Date the stopwatch was started at.
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created: Timestamp,
+    /// Human-readable duration the stopwatch has been running for.
+    pub duration: String,
+    pub issue_index: i64,
+    pub issue_title: String,
+    pub repo_name: String,
+    pub repo_owner_name: String,
+    /// Number of seconds the stopwatch has been running for.
+    pub seconds: i64,
+}
+
+/// Represents the deadline of an issue.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IssueDeadline {
+    /// The deadline, or `None` if the issue has no deadline.
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub due_date: Option<Timestamp>,
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum StateType {
     #[default]
     #[serde(rename = "open")]
     Open,
     #[serde(rename = "closed")]
     Closed,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum IssueType {
     #[serde(rename = "issues")]
     Issues,
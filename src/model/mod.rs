@@ -1,7 +1,14 @@
+pub mod actions;
+pub mod admin;
+pub mod hooks;
 pub mod issues;
+pub mod notifications;
 pub mod orgs;
+pub mod packages;
 pub mod pulls;
+pub mod quota;
 pub mod repos;
 pub mod user;
 pub mod reviews;
 pub mod team;
+pub mod templates;
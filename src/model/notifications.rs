@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use super::{issues::StateType, repos::Repository};
+use crate::timestamp::Timestamp;
+
+/// Represents a notification thread.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationThread {
+    pub id: i64,
+    pub pinned: bool,
+    pub repository: Repository,
+    pub subject: NotificationSubject,
+    pub unread: bool,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
+    pub url: String,
+}
+
+/// Represents the subject of a notification thread.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSubject {
+    pub html_url: String,
+    pub latest_comment_html_url: String,
+    pub latest_comment_url: String,
+    pub state: StateType,
+    pub title: String,
+    pub r#type: NotifySubjectType,
+    pub url: String,
+}
+
+/// The kind of subject a notification is about.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum NotifySubjectType {
+    #[default]
+    Issue,
+    Pull,
+    Commit,
+    Repository,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+/// The status a notification thread can be set to.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum NotificationStatus {
+    #[default]
+    Read,
+    Unread,
+    Pinned,
+}
+
+/// Represents the number of unread notifications.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationCount {
+    pub new: i64,
+}
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 /// Represents a Gitea organization.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Organization {
     pub id: i64,
     pub name: String,
     pub visibility: Visibility,
+    /// Whether repository admins can add and remove teams' access to their repositories.
     pub repo_admin_change_team_access: bool,
     pub avatar_url: Option<String>,
     pub description: Option<String>,
@@ -15,11 +17,27 @@ pub struct Organization {
     pub website: Option<String>,
 }
 
-/// Represents the visibility of an organization.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Represents the visibility of a user or an organization.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum Visibility {
+    #[default]
     Public,
     Limited,
     Private,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+/// Represents the permissions a user has in an organization.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrgPermissions {
+    pub is_owner: bool,
+    pub is_admin: bool,
+    pub can_write: bool,
+    pub can_read: bool,
+    pub can_create_repository: bool,
 }
@@ -0,0 +1,99 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use super::{repos::Repository, user::User};
+use crate::timestamp::Timestamp;
+
+/// Represents a package.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Package {
+    pub id: i64,
+    pub owner: User,
+    pub creator: User,
+    pub r#type: PackageType,
+    pub name: String,
+    pub version: String,
+    pub html_url: String,
+    /// The repository the package is linked to, if any.
+    pub repository: Option<Repository>,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+}
+
+/// The registry a package belongs to.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PackageType {
+    Alpine,
+    Cargo,
+    Chef,
+    Composer,
+    Conan,
+    Conda,
+    Container,
+    Cran,
+    Debian,
+    #[default]
+    Generic,
+    Go,
+    Helm,
+    Maven,
+    Npm,
+    Nuget,
+    Pub,
+    Pypi,
+    Rpm,
+    Rubygems,
+    Swift,
+    Vagrant,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl Display for PackageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            PackageType::Alpine => "alpine",
+            PackageType::Cargo => "cargo",
+            PackageType::Chef => "chef",
+            PackageType::Composer => "composer",
+            PackageType::Conan => "conan",
+            PackageType::Conda => "conda",
+            PackageType::Container => "container",
+            PackageType::Cran => "cran",
+            PackageType::Debian => "debian",
+            PackageType::Generic => "generic",
+            PackageType::Go => "go",
+            PackageType::Helm => "helm",
+            PackageType::Maven => "maven",
+            PackageType::Npm => "npm",
+            PackageType::Nuget => "nuget",
+            PackageType::Pub => "pub",
+            PackageType::Pypi => "pypi",
+            PackageType::Rpm => "rpm",
+            PackageType::Rubygems => "rubygems",
+            PackageType::Swift => "swift",
+            PackageType::Vagrant => "vagrant",
+            PackageType::Unknown => "unknown",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Represents a file belonging to a package version.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PackageFile {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "Size")]
+    pub size: i64,
+    pub md5: String,
+    pub sha1: String,
+    pub sha256: String,
+    pub sha512: String,
+}
@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    issues::{Label, StateType},
+    issues::{Label, Milestone, StateType},
     repos::Repository,
     user::User,
 };
+use crate::timestamp::Timestamp;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PullRequest {
@@ -14,13 +15,16 @@ pub struct PullRequest {
     pub base: PrBranchInfo,
     pub body: String,
     pub changed_files: i64,
-    pub closed_at: Option<String>,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub closed_at: Option<Timestamp>,
     pub comments: i64,
-    pub created_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
     pub deletions: i64,
     pub diff_url: String,
     pub draft: bool,
-    pub due_date: Option<String>,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub due_date: Option<Timestamp>,
     pub head: PrBranchInfo,
     pub html_url: String,
     pub id: i64,
@@ -30,9 +34,10 @@ pub struct PullRequest {
     pub merge_commit_sha: Option<String>,
     pub mergeable: bool,
     pub merged: bool,
-    pub merged_at: Option<String>,
+    #[serde(with = "crate::timestamp::rfc3339::option")]
+    pub merged_at: Option<Timestamp>,
     pub merged_by: Option<User>,
-    // TODO: pub milestone: Option<Milestone>,
+    pub milestone: Option<Milestone>,
     pub number: i64,
     pub patch_url: String,
     pub pin_order: i64,
@@ -41,8 +46,8 @@ pub struct PullRequest {
     pub review_comments: i64,
     pub state: StateType,
     pub title: String,
-    // TODO: Make this a DateTime<Utc>
-    pub updated_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
     pub url: String,
     pub user: User,
 }
@@ -58,6 +63,7 @@ pub struct PrBranchInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum Sort {
     Oldest,
     RecentUpdate,
@@ -66,3 +72,29 @@ pub enum Sort {
     LeastComment,
     Priority,
 }
+
+/// Information about a file affected by a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangedFile {
+    pub filename: String,
+    pub previous_filename: Option<String>,
+    /// The kind of change, e.g. "added", "changed", "deleted" or "renamed".
+    pub status: String,
+    pub additions: i64,
+    pub deletions: i64,
+    pub changes: i64,
+    pub html_url: String,
+    pub contents_url: String,
+    pub raw_url: String,
+}
+
+/// How whitespace changes are treated when computing a diff.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Whitespace {
+    IgnoreAll,
+    IgnoreChange,
+    IgnoreEol,
+    ShowAll,
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a quota rule.
+/// A rule limits the combined size of its subjects.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaRule {
+    pub name: String,
+    /// The limit in bytes. `-1` means unlimited.
+    pub limit: i64,
+    /// The subjects the rule applies to, e.g. "size:repos:all" or "size:assets:packages:all".
+    pub subjects: Vec<String>,
+}
+
+/// Represents a quota group, a named set of rules users can be assigned to.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaGroup {
+    pub name: String,
+    pub rules: Vec<QuotaRule>,
+}
+
+/// Represents the quota of a user: the groups they are in and the space they use.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaInfo {
+    pub used: QuotaUsed,
+    pub groups: Vec<QuotaGroup>,
+}
+
+/// Represents the space used by a user, in bytes.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsed {
+    pub size: QuotaUsedSize,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsedSize {
+    pub repos: QuotaUsedSizeRepos,
+    pub git: QuotaUsedSizeGit,
+    pub assets: QuotaUsedSizeAssets,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsedSizeRepos {
+    pub public: i64,
+    pub private: i64,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsedSizeGit {
+    #[serde(rename = "LFS")]
+    pub lfs: i64,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsedSizeAssets {
+    pub attachments: QuotaUsedSizeAssetsAttachments,
+    pub artifacts: i64,
+    pub packages: QuotaUsedSizeAssetsPackages,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsedSizeAssetsAttachments {
+    pub issues: i64,
+    pub releases: i64,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaUsedSizeAssetsPackages {
+    pub all: i64,
+}
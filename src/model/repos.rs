@@ -1,23 +1,29 @@
 use serde::{Deserialize, Serialize};
 
-use crate::model::user::User;
+use crate::model::{team::Team, user::User};
+use crate::timestamp::Timestamp;
 
 /// Represents the format of the object in the repository.
 /// Defaults to [ObjectFormatName::SHA1].
 /// SHA1 is more widely supported, but SHA256 is more secure.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ObjectFormatName {
     #[default]
     #[serde(rename = "sha1")]
     SHA1,
     #[serde(rename = "sha256")]
     SHA256,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 /// Represents the trust model for verifying commits in the repository.
 /// Defaults to [TrustModel::Default] (obviously).
 /// This determines when signatures are considered "trusted".
 #[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TrustModel {
     /// Use the default repository trust model for this installation.
     #[serde(rename = "default")]
@@ -48,10 +54,12 @@ pub struct Repository {
     pub allow_rebase_update: bool,
     pub allow_squash_merge: bool,
     pub archived: bool,
-    pub archived_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub archived_at: Timestamp,
     pub avatar_url: String,
     pub clone_url: String,
-    pub created_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
     pub default_allow_maintainer_edit: bool,
     pub default_branch: String,
     pub default_delete_branch_after_merge: bool,
@@ -79,26 +87,55 @@ pub struct Repository {
     pub link: String,
     pub mirror: bool,
     pub mirror_interval: String,
-    pub mirror_updated: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub mirror_updated: Timestamp,
     pub name: String,
     pub object_format_name: ObjectFormatName,
     pub open_issues_count: i64,
     pub open_pr_counter: i64,
     pub original_url: String,
     pub owner: User,
+    /// What the authenticated user is allowed to do in the repository.
+    /// Not set for anonymous requests.
+    pub permissions: Option<RepoPermissions>,
     pub private: bool,
     pub release_counter: i64,
+    /// The pending transfer of the repository to a new owner, if any.
+    pub repo_transfer: Option<RepoTransfer>,
     pub size: i64,
     pub ssh_url: String,
     pub stars_count: i64,
     pub template: bool,
-    pub updated_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
     pub url: String,
     pub watchers_count: i64,
     pub website: String,
     pub wiki_branch: String,
 }
 
+/// Represents a pending transfer of a repository to a new owner.
+/// The transfer is completed once the recipient accepts it.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepoTransfer {
+    /// The user who started the transfer.
+    pub doer: Option<User>,
+    /// The user or organization the repository is transferred to.
+    pub recipient: Option<User>,
+    /// The teams of the recipient organization that will get access to the repository.
+    pub teams: Option<Vec<Team>>,
+}
+
+/// Represents the permissions a user has in a repository.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepoPermissions {
+    pub admin: bool,
+    pub push: bool,
+    pub pull: bool,
+}
+
 /// Represents information about a user in the context of a commit.
 ///
 /// NOTE: This is not the same as the [User] struct.
@@ -108,7 +145,8 @@ pub struct Repository {
 #[serde(default)]
 pub struct CommitUser {
     /// Date the commit was authored.
-    pub date: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub date: Timestamp,
     /// Email of the user.
     pub email: String,
     /// Full name of the user.
@@ -117,7 +155,7 @@ pub struct CommitUser {
 
 /// Represents the actual commit object in the underlying git repository.
 /// This struct is a subset of the full commit object.
-/// It does not include the full commit tree or commit verification.
+/// It does not include the full commit tree.
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RepoCommit {
@@ -130,12 +168,12 @@ pub struct RepoCommit {
     /// The API endpoint for the commit
     /// (https://gitea-host.com/api/v1/repos/{user}/{repo}/git/commits/{sha}.
     pub url: String,
+    /// The signature verification of the commit.
+    /// Only set if requested, see [GetCommitsBuilder::verification](crate::api::repos::commits::GetCommitsBuilder::verification).
+    pub verification: Option<PayloadCommitVerification>,
 }
 
 /// Represents a commit in a repository.
-/// This struct is a subset of the full commit object.
-/// It does not include the affected files, parent commits or commit stats (additions and
-/// deletions).
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Commit {
@@ -150,14 +188,60 @@ pub struct Commit {
     /// NOTE: This is not guaranteed to be a valid Gitea user.
     /// Because of the nature of Git, this field can be null
     pub committer: Option<User>,
+    /// The files changed by the commit.
+    /// Only set if requested, see [GetCommitsBuilder::files](crate::api::repos::commits::GetCommitsBuilder::files).
+    pub files: Option<Vec<CommitAffectedFile>>,
     /// The URL to the commit on the Gitea instance.
     pub html_url: String,
+    /// The parent commits. Merge commits have more than one.
+    pub parents: Vec<CommitMeta>,
     /// The SHA of the commit.
     pub sha: String,
+    /// The number of changed lines.
+    /// Only set if requested, see [GetCommitsBuilder::stat](crate::api::repos::commits::GetCommitsBuilder::stat).
+    pub stats: Option<CommitStats>,
     /// The API endpoint URL for the commit.
     pub url: String,
 }
 
+/// A reference to another commit, e.g. a parent.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommitMeta {
+    /// The SHA of the commit.
+    pub sha: String,
+    /// The API endpoint URL for the commit.
+    pub url: String,
+}
+
+/// The number of lines a commit changes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommitStats {
+    pub additions: i64,
+    pub deletions: i64,
+    /// The sum of additions and deletions.
+    pub total: i64,
+}
+
+/// A file changed by a commit.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommitAffectedFile {
+    pub filename: String,
+    /// The kind of change, e.g. "added", "modified" or "removed".
+    pub status: String,
+}
+
+/// Represents a comparison between two commits.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Compare {
+    /// The commits between the two refs, newest first.
+    pub commits: Vec<Commit>,
+    pub total_commits: i64,
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct PayloadUser {
     pub email: String,
@@ -176,9 +260,26 @@ pub struct PayloadCommit {
     pub added: Option<Vec<String>>,
     pub modified: Option<Vec<String>>,
     pub removed: Option<Vec<String>>,
-    pub timestamp: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub timestamp: Timestamp,
     pub url: String,
-    // TODO: pub verification: PayloadCommitVerification,
+    pub verification: Option<PayloadCommitVerification>,
+}
+
+/// The result of verifying the signature of a commit.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PayloadCommitVerification {
+    /// Whether the signature is valid and trusted.
+    pub verified: bool,
+    /// Why the commit is (not) verified, e.g. "gpg.error.not_signed_commit" for unsigned commits.
+    pub reason: String,
+    /// The armored signature of the commit, empty if it isn't signed.
+    pub signature: String,
+    /// The user the signing key belongs to.
+    pub signer: Option<PayloadUser>,
+    /// The signed data, i.e. the commit without its signature.
+    pub payload: String,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -215,3 +316,85 @@ pub struct ExternalWiki {
     /// URL of external wiki.
     pub external_wiki_url: String,
 }
+
+/// Represents a repository topic.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Topic {
+    pub id: i64,
+    pub topic_name: String,
+    /// The number of repositories tagged with the topic.
+    pub repo_count: i64,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated: Timestamp,
+}
+
+/// The attribute to sort repositories by when searching them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SortKey {
+    /// Alphabetically by name.
+    Alpha,
+    Created,
+    Updated,
+    /// By total size, including LFS objects.
+    Size,
+    GitSize,
+    LfsSize,
+    Stars,
+    Forks,
+    Id,
+}
+
+/// The direction to sort results in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// The kind of repositories to search for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RepoSearchMode {
+    Fork,
+    Source,
+    Mirror,
+    Collaborative,
+}
+
+/// How the commits of a pull request are merged into the base branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum MergeStyle {
+    /// Create a merge commit.
+    Merge,
+    /// Rebase the commits onto the base branch and fast-forward it.
+    Rebase,
+    /// Rebase the commits onto the base branch, then create a merge commit.
+    RebaseMerge,
+    /// Squash all commits into a single one.
+    Squash,
+    /// Only fast-forward the base branch, failing if that isn't possible.
+    FastForwardOnly,
+}
+
+/// Which projects can be used in a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ProjectsMode {
+    /// Only projects of the repository itself.
+    Repo,
+    /// Only projects of the repository owner.
+    Owner,
+    /// Both repository and owner projects.
+    All,
+}
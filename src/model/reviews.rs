@@ -3,6 +3,7 @@ use super::{
     team::Team,
     user::User,
 };
+use crate::timestamp::Timestamp;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PullReview {
@@ -16,13 +17,16 @@ pub struct PullReview {
     pub pull_request_url: String,
     pub stale: bool,
     pub state: ReviewStateType,
-    pub submitted_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub submitted_at: Timestamp,
     pub team: Option<Team>,
-    pub updated_at: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub updated_at: Timestamp,
     pub user: Option<User>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ReviewStateType {
     #[default]
     #[serde(rename = "APPROVED")]
@@ -36,5 +40,6 @@ pub enum ReviewStateType {
     #[serde(rename = "REQUEST_REVIEW")]
     RequestReview,
     #[serde(rename = "UNKNOWN")]
+    #[serde(other)]
     Unknown,
 }
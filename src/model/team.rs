@@ -1,23 +1,32 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use super::{
     orgs::Organization,
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Represents a team in an organization.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Team {
+    /// Whether members of the team can create repositories in the organization.
     pub can_create_org_repo: bool,
     pub description: String,
     pub id: i64,
+    /// Whether the team has access to all repositories of the organization.
     pub includes_all_repositories: bool,
     pub name: String,
+    /// The organization the team belongs to.
     pub organization: Option<Organization>,
     pub permission: Permission,
+    /// The units the team has access to, e.g. "repo.code" or "repo.issues".
     pub units: Vec<String>,
-    pub units_map: HashMap<String, String>,
+    /// The permission of the team per unit, e.g. "repo.code" => "write".
+    pub units_map: BTreeMap<String, String>,
 }
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+/// Represents the permission a team has in the repositories of its organization.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum Permission {
     #[default]
     #[serde(rename = "none")]
@@ -30,4 +39,7 @@ pub enum Permission {
     Admin,
     #[serde(rename = "owner")]
     Owner,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
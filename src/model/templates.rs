@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a gitignore template.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitignoreTemplate {
+    pub name: String,
+    /// The content of the template.
+    pub source: String,
+}
+
+/// Represents a license template as returned when listing licenses.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LicenseTemplateEntry {
+    /// The key to get the license by, e.g. "MIT".
+    pub key: String,
+    pub name: String,
+    pub url: String,
+}
+
+/// Represents a license template.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LicenseTemplate {
+    pub key: String,
+    pub name: String,
+    pub url: String,
+    /// The text of the license.
+    pub body: String,
+    pub implementation: String,
+}
+
+/// Represents a label of a label template set.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LabelTemplate {
+    pub name: String,
+    /// The color of the label as a hex string without the leading `#`, e.g. "00aabb".
+    pub color: String,
+    pub description: String,
+    pub exclusive: bool,
+}
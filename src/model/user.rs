@@ -1,5 +1,10 @@
+use std::{collections::BTreeSet, ops::BitOr};
+
 use serde::{Deserialize, Serialize};
 
+use super::{issues::Comment, orgs::Visibility, repos::Repository};
+use crate::{secret::SecretString, timestamp::Timestamp};
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 /// Represents a user's settings.
 pub struct UserSettings {
@@ -24,13 +29,149 @@ pub struct AccessToken {
     /// Name of the access token.
     pub name: String,
     /// The token's scopes.
-    pub scopes: Option<Vec<String>>,
+    pub scopes: Option<TokenScopes>,
     /// The token's SHA1 hash. This is probably what you want to store to access the API.
-    pub sha1: String,
+    /// It is only returned when the token is created.
+    pub sha1: SecretString,
     /// The token's last eight characters. Useful for verifying the token.
     pub token_last_eight: String,
 }
 
+/// A permission granted to an access token.
+///
+/// Scopes can be combined into [TokenScopes] with `|`:
+/// ```
+/// # use gitea_sdk::model::user::TokenScope;
+/// let scopes = TokenScope::WriteRepository | TokenScope::ReadUser;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum TokenScope {
+    /// Full access to everything the user can access.
+    #[serde(rename = "all")]
+    All,
+    /// Restricts the token to public resources, in addition to its other scopes.
+    #[serde(rename = "public-only")]
+    PublicOnly,
+    #[serde(rename = "read:activitypub")]
+    ReadActivityPub,
+    #[serde(rename = "write:activitypub")]
+    WriteActivityPub,
+    #[serde(rename = "read:admin")]
+    ReadAdmin,
+    #[serde(rename = "write:admin")]
+    WriteAdmin,
+    #[serde(rename = "read:issue")]
+    ReadIssue,
+    #[serde(rename = "write:issue")]
+    WriteIssue,
+    #[serde(rename = "read:misc")]
+    ReadMisc,
+    #[serde(rename = "write:misc")]
+    WriteMisc,
+    #[serde(rename = "read:notification")]
+    ReadNotification,
+    #[serde(rename = "write:notification")]
+    WriteNotification,
+    #[serde(rename = "read:organization")]
+    ReadOrganization,
+    #[serde(rename = "write:organization")]
+    WriteOrganization,
+    #[serde(rename = "read:package")]
+    ReadPackage,
+    #[serde(rename = "write:package")]
+    WritePackage,
+    #[serde(rename = "read:repository")]
+    ReadRepository,
+    #[serde(rename = "write:repository")]
+    WriteRepository,
+    #[serde(rename = "read:user")]
+    ReadUser,
+    #[serde(rename = "write:user")]
+    WriteUser,
+    /// A value this version of the crate doesn't know, e.g. one added in a newer Gitea version.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl BitOr for TokenScope {
+    type Output = TokenScopes;
+
+    fn bitor(self, rhs: Self) -> TokenScopes {
+        TokenScopes::new().with(self).with(rhs)
+    }
+}
+
+/// A set of [TokenScope]s to create an access token with.
+///
+/// It can be built with `|`, [TokenScopes::with], or from a list of scopes:
+/// ```
+/// # use gitea_sdk::model::user::{TokenScope, TokenScopes};
+/// let scopes = TokenScopes::new()
+///     .with(TokenScope::WriteRepository)
+///     .with(TokenScope::ReadUser);
+/// assert_eq!(scopes, TokenScope::WriteRepository | TokenScope::ReadUser);
+/// assert_eq!(scopes, [TokenScope::ReadUser, TokenScope::WriteRepository].into());
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TokenScopes(BTreeSet<TokenScope>);
+
+impl TokenScopes {
+    /// Creates an empty set of scopes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a scope to the set.
+    pub fn with(mut self, scope: TokenScope) -> Self {
+        self.0.insert(scope);
+        self
+    }
+
+    /// Whether the set contains the given scope.
+    pub fn contains(&self, scope: TokenScope) -> bool {
+        self.0.contains(&scope)
+    }
+
+    /// Iterates over the scopes in the set.
+    pub fn iter(&self) -> impl Iterator<Item = TokenScope> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl BitOr<TokenScope> for TokenScopes {
+    type Output = TokenScopes;
+
+    fn bitor(self, rhs: TokenScope) -> TokenScopes {
+        self.with(rhs)
+    }
+}
+
+impl From<TokenScope> for TokenScopes {
+    fn from(scope: TokenScope) -> Self {
+        Self::new().with(scope)
+    }
+}
+
+impl<const N: usize> From<[TokenScope; N]> for TokenScopes {
+    fn from(scopes: [TokenScope; N]) -> Self {
+        scopes.into_iter().collect()
+    }
+}
+
+impl From<Vec<TokenScope>> for TokenScopes {
+    fn from(scopes: Vec<TokenScope>) -> Self {
+        scopes.into_iter().collect()
+    }
+}
+
+impl FromIterator<TokenScope> for TokenScopes {
+    fn from_iter<I: IntoIterator<Item = TokenScope>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 /// Represents a Gitea user.
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -40,7 +181,8 @@ pub struct User {
     /// URL to the user's avatar.
     pub avatar_url: String,
     /// Date the user was created at.
-    pub created: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created: Timestamp,
     /// Description of the user (empty string if the user did not provide a description).
     pub description: String,
     /// Email of the user.
@@ -58,7 +200,8 @@ pub struct User {
     /// Language the user speaks (empty string if the user did not specify any languaged).
     pub language: String,
     /// Date the user last logged in.
-    pub last_login: String,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub last_login: Timestamp,
     /// Location of the user (empty string if the user did not provide a location).
     pub location: String,
     /// The user's username
@@ -74,8 +217,100 @@ pub struct User {
     /// Number of repositories the user has starred.
     pub starred_repos_count: i64,
     /// User visibility.
-    /// Can be one of "public", "limited", or "private".
-    pub visibility: String,
+    pub visibility: Visibility,
     /// The user's website (empty string if the user did not provide a website).
     pub website: String,
 }
+
+/// Represents a public SSH key.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PublicKey {
+    pub id: i64,
+    pub title: String,
+    /// The public key itself, e.g. "ssh-ed25519 AAAA...".
+    pub key: String,
+    pub key_type: String,
+    pub fingerprint: String,
+    /// Whether the key only grants read access.
+    /// This is only relevant for deploy keys.
+    pub read_only: bool,
+    pub url: String,
+    pub user: Option<User>,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+}
+
+/// Represents a GPG key.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GPGKey {
+    pub id: i64,
+    pub key_id: String,
+    pub primary_key_id: String,
+    pub public_key: String,
+    pub emails: Vec<GPGKeyEmail>,
+    pub subkeys: Vec<GPGKey>,
+    pub can_sign: bool,
+    pub can_certify: bool,
+    pub can_encrypt_comms: bool,
+    pub can_encrypt_storage: bool,
+    /// Whether the ownership of the key has been verified.
+    pub verified: bool,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created_at: Timestamp,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub expires_at: Timestamp,
+}
+
+/// Represents an email address associated with a GPG key.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GPGKeyEmail {
+    pub email: String,
+    pub verified: bool,
+}
+
+/// Represents an email address of a user.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Email {
+    pub email: String,
+    pub verified: bool,
+    /// Whether this is the user's primary email address.
+    pub primary: bool,
+    pub user_id: i64,
+    pub username: String,
+}
+
+/// Represents an entry in a user's activity feed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Activity {
+    pub id: i64,
+    /// The type of action, e.g. "create_repo", "commit_repo" or "merge_pull_request".
+    pub op_type: String,
+    /// The user who performed the action.
+    pub act_user: Option<User>,
+    pub act_user_id: i64,
+    /// The user whose feed this entry belongs to.
+    pub user_id: i64,
+    pub repo: Option<Repository>,
+    pub repo_id: i64,
+    pub comment: Option<Comment>,
+    pub comment_id: i64,
+    pub content: String,
+    pub ref_name: String,
+    pub is_private: bool,
+    #[serde(with = "crate::timestamp::rfc3339")]
+    pub created: Timestamp,
+}
+
+/// Represents the number of contributions of a user at a point in time.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeatmapData {
+    /// Unix timestamp in seconds.
+    pub timestamp: i64,
+    pub contributions: i64,
+}
@@ -0,0 +1,85 @@
+//! Callbacks that are notified about every request sent by a [Client](crate::Client).
+//!
+//! Register them with [Client::on_request](crate::Client::on_request) and
+//! [Client::on_response](crate::Client::on_response), e.g. to record metrics or audit logs:
+//! ```
+//! # use gitea_sdk::{Client, Auth};
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+//!     .on_request(|event| println!("--> {} {}", event.method, event.url))
+//!     .on_response(|event| {
+//!         println!("<-- {} {} {:?} in {:?}", event.method, event.url, event.status, event.elapsed)
+//!     });
+//! ```
+use std::{future::Future, sync::Arc, time::Duration};
+
+use reqwest::{Method, Request, Response, StatusCode, Url};
+
+use crate::{error::Result, time::Instant};
+
+/// A request that is about to be sent.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestEvent<'a> {
+    pub method: &'a Method,
+    pub url: &'a Url,
+}
+
+/// A request that has been completed.
+///
+/// Retries are not reported separately, so `elapsed` includes the time spent on all attempts.
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseEvent<'a> {
+    pub method: &'a Method,
+    pub url: &'a Url,
+    /// The status code of the response, or `None` if no response was received, e.g. because the
+    /// server couldn't be reached.
+    pub status: Option<StatusCode>,
+    /// The time from sending the request until the response headers were received.
+    pub elapsed: Duration,
+}
+
+type RequestCallback = Arc<dyn Fn(&RequestEvent<'_>) + Send + Sync>;
+type ResponseCallback = Arc<dyn Fn(&ResponseEvent<'_>) + Send + Sync>;
+
+/// The callbacks registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct Observers {
+    pub(crate) on_request: Vec<RequestCallback>,
+    pub(crate) on_response: Vec<ResponseCallback>,
+}
+
+impl Observers {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.on_request.is_empty() && self.on_response.is_empty()
+    }
+
+    /// Sends the request using `send`, notifying the callbacks before and after.
+    pub(crate) async fn observe<F>(
+        &self,
+        req: Request,
+        send: impl FnOnce(Request) -> F,
+    ) -> Result<Response>
+    where
+        F: Future<Output = Result<Response>>,
+    {
+        let method = req.method().clone();
+        let url = req.url().clone();
+        let event = RequestEvent {
+            method: &method,
+            url: &url,
+        };
+        self.on_request.iter().for_each(|callback| callback(&event));
+
+        let start = Instant::now();
+        let res = send(req).await;
+        let event = ResponseEvent {
+            method: &method,
+            url: &url,
+            status: res.as_ref().ok().map(Response::status),
+            elapsed: start.elapsed(),
+        };
+        self.on_response
+            .iter()
+            .for_each(|callback| callback(&event));
+        res
+    }
+}
@@ -0,0 +1,241 @@
+//! Helpers for walking paginated list endpoints.
+//!
+//! Every list builder that supports `page`/`limit` implements [Pageable]. Instead of requesting
+//! one page at a time, you can use [Pageable::into_stream] to get every item as a
+//! [Stream], or [Pageable::send_all] to collect all of them at once.
+//!
+//! ```
+//! # use gitea_sdk::{Client, Auth, pagination::Pageable};
+//! # use futures_util::TryStreamExt;
+//! # async fn list_all_issues() {
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+//! let mut issues = client
+//!     .issues("owner", "repo")
+//!     .list()
+//!     .limit(50)
+//!     .into_stream(&client);
+//! while let Some(issue) = issues.try_next().await.unwrap() {
+//!     println!("#{}: {}", issue.number, issue.title);
+//! }
+//! # }
+//! ```
+use std::{future::Future, pin::Pin};
+
+use futures_util::{stream, Stream, TryStreamExt};
+use reqwest::header::HeaderMap;
+
+use crate::{error::Result, response, GiteaHttp};
+
+/// A boxed future, as returned by the methods of [Pageable].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+/// A boxed stream, as returned by [Pageable::into_stream].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxStream<'a, T> = Pin<Box<dyn Stream<Item = T> + Send + 'a>>;
+/// A boxed future, as returned by the methods of [Pageable].
+/// Futures in the browser are not [Send], so neither is this one.
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+/// A boxed stream, as returned by [Pageable::into_stream].
+/// Futures in the browser are not [Send], so neither is this one.
+#[cfg(target_arch = "wasm32")]
+pub type BoxStream<'a, T> = Pin<Box<dyn Stream<Item = T> + 'a>>;
+
+/// A single page of a list response, along with the pagination info the server sent.
+///
+/// The total count is taken from the `X-Total-Count` header (or the response body, for endpoints
+/// that send it there) and the page numbers from the `Link` header. They are `None` if the
+/// server didn't send them, e.g. there is no next page on the last page.
+#[derive(Default, Debug, Clone)]
+pub struct Paged<T> {
+    /// The items of the page.
+    pub items: Vec<T>,
+    /// The total number of items across all pages.
+    pub total_count: Option<u64>,
+    pub next_page: Option<i64>,
+    pub prev_page: Option<i64>,
+    pub first_page: Option<i64>,
+    pub last_page: Option<i64>,
+}
+
+impl<T> Paged<T> {
+    /// Creates a page from its items and the headers of the response they were read from.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::pagination::Paged;
+    /// # use reqwest::header::{HeaderMap, HeaderValue};
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-total-count", HeaderValue::from_static("25"));
+    /// headers.insert(
+    ///     "link",
+    ///     HeaderValue::from_static(
+    ///         "<https://gitea.example.com/api/v1/user/repos?limit=10&page=3>; rel=\"next\",\
+    ///          <https://gitea.example.com/api/v1/user/repos?limit=10&page=3>; rel=\"last\",\
+    ///          <https://gitea.example.com/api/v1/user/repos?limit=10&page=1>; rel=\"first\",\
+    ///          <https://gitea.example.com/api/v1/user/repos?limit=10&page=1>; rel=\"prev\"",
+    ///     ),
+    /// );
+    /// let page = Paged::new(vec![1, 2, 3], &headers);
+    /// assert_eq!(page.total_count, Some(25));
+    /// assert_eq!(page.next_page, Some(3));
+    /// assert_eq!(page.prev_page, Some(1));
+    /// assert_eq!(page.first_page, Some(1));
+    /// assert_eq!(page.last_page, Some(3));
+    /// ```
+    pub fn new(items: Vec<T>, headers: &HeaderMap) -> Self {
+        let mut paged = Self {
+            items,
+            total_count: response::total_count(headers),
+            next_page: None,
+            prev_page: None,
+            first_page: None,
+            last_page: None,
+        };
+        let links = headers
+            .get_all(reqwest::header::LINK)
+            .iter()
+            .filter_map(|v| v.to_str().ok());
+        // A link header looks like `<https://.../api/v1/...?page=2&limit=10>; rel="next", ...`
+        for link in links.flat_map(|v| v.split(',')) {
+            let Some((url, params)) = link.split_once(';') else {
+                continue;
+            };
+            let url = url.trim().trim_start_matches('<').trim_end_matches('>');
+            let page = url
+                .split_once('?')
+                .map(|(_, query)| query)
+                .unwrap_or_default()
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("page="))
+                .and_then(|page| page.parse().ok());
+            let rel = params
+                .split(';')
+                .find_map(|param| param.trim().strip_prefix("rel="))
+                .map(|rel| rel.trim_matches('"'));
+            match rel {
+                Some("next") => paged.next_page = page,
+                Some("prev") => paged.prev_page = page,
+                Some("first") => paged.first_page = page,
+                Some("last") => paged.last_page = page,
+                _ => {}
+            }
+        }
+        paged
+    }
+
+    /// Like [Paged::new], for endpoints that send the total count in the response body.
+    /// The count from the body is used even if the headers carry one too.
+    pub fn with_total_count(items: Vec<T>, headers: &HeaderMap, total_count: u64) -> Self {
+        Self {
+            total_count: Some(total_count),
+            ..Self::new(items, headers)
+        }
+    }
+}
+
+/// A list builder that can request its results page by page.
+pub trait Pageable: Clone + Send + Sync {
+    /// The type of the items in a page.
+    type Item: Send;
+
+    /// Returns the page the builder will request, if one is set.
+    fn current_page(&self) -> Option<i64>;
+    /// Sets the page the builder will request.
+    fn set_page(&mut self, page: i64);
+    /// Returns the page size set on the builder, if any.
+    fn page_limit(&self) -> Option<i64>;
+    /// Requests the page the builder currently points at.
+    fn fetch_page<'a, C: GiteaHttp>(
+        &'a self,
+        client: &'a C,
+    ) -> BoxFuture<'a, Result<Paged<Self::Item>>>;
+
+    /// Returns a [Stream] over the items of all pages, starting at the page set on the builder
+    /// (or the first page). The page size is the `limit` set on the builder, or the server
+    /// default.
+    ///
+    /// Pages are requested lazily until the last one: the page with no `next` link, or the one
+    /// the total count is reached on. If the server sends neither, the stream ends after a page
+    /// with fewer items than the limit, or an empty one.
+    fn into_stream<'a, C: GiteaHttp>(self, client: &'a C) -> BoxStream<'a, Result<Self::Item>>
+    where
+        Self: 'a,
+    {
+        let page = self.current_page().unwrap_or(1);
+        // Items before the starting page are unknown, so the total count can't be compared to.
+        let seen = (page == 1).then_some(0);
+        let state = Some((self, page, seen));
+        let pages = stream::try_unfold(state, move |state| async move {
+            let Some((mut builder, page, seen)) = state else {
+                return Result::Ok(None);
+            };
+            builder.set_page(page);
+            let paged = builder.fetch_page(client).await?;
+            let count = paged.items.len();
+            if count == 0 {
+                return Ok(None);
+            }
+            let seen = seen.map(|seen| seen + count as u64);
+            let has_links = paged.next_page.is_some()
+                || paged.prev_page.is_some()
+                || paged.first_page.is_some()
+                || paged.last_page.is_some();
+            let reached = seen
+                .zip(paged.total_count)
+                .is_some_and(|(seen, total)| seen >= total);
+            let last = if has_links || paged.total_count.is_some() {
+                (has_links && paged.next_page.is_none()) || reached
+            } else {
+                builder
+                    .page_limit()
+                    .is_some_and(|limit| (count as i64) < limit)
+            };
+            let next = (!last).then_some((builder, page + 1, seen));
+            let items = stream::iter(paged.items.into_iter().map(Result::Ok));
+            Ok(Some((items, next)))
+        });
+        Box::pin(pages.try_flatten())
+    }
+
+    /// Requests all pages and collects their items.
+    fn send_all<'a, C: GiteaHttp>(self, client: &'a C) -> BoxFuture<'a, Result<Vec<Self::Item>>>
+    where
+        Self: 'a,
+    {
+        Box::pin(self.into_stream(client).try_collect())
+    }
+}
+
+/// Implements [Pageable] for a list builder with `page` and `limit` fields and a
+/// `send_paged(&self, &impl GiteaHttp) -> Result<Paged<Item>>` method.
+macro_rules! impl_pageable {
+    ($builder:ty, $item:ty) => {
+        impl $crate::pagination::Pageable for $builder {
+            type Item = $item;
+
+            fn current_page(&self) -> Option<i64> {
+                self.page.map(|page| page as i64)
+            }
+
+            fn set_page(&mut self, page: i64) {
+                self.page = Some(page as _);
+            }
+
+            fn page_limit(&self) -> Option<i64> {
+                self.limit.map(|limit| limit as i64)
+            }
+
+            fn fetch_page<'a, C: $crate::GiteaHttp>(
+                &'a self,
+                client: &'a C,
+            ) -> $crate::pagination::BoxFuture<
+                'a,
+                $crate::error::Result<$crate::pagination::Paged<$item>>,
+            > {
+                Box::pin(self.send_paged(client))
+            }
+        }
+    };
+}
+pub(crate) use impl_pageable;
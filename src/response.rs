@@ -0,0 +1,70 @@
+//! Responses along with their status code and headers.
+use reqwest::{
+    header::{HeaderMap, LOCATION},
+    StatusCode,
+};
+
+/// A model parsed from a response, along with the status code and headers of the response.
+/// Returned by the `send_with_response` methods of the request builders.
+///
+/// # Example
+/// ```
+/// # use gitea_sdk::{Client, Auth};
+/// # async fn get_repo() {
+/// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+/// let res = client
+///     .repos("owner", "repo")
+///     .get()
+///     .send_with_response(&client)
+///     .await
+///     .unwrap();
+/// println!("{} ({})", res.value.full_name, res.status);
+/// if let Some(limit) = res.header("x-ratelimit-remaining") {
+///     println!("{limit} requests left");
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The parsed body of the response.
+    pub value: T,
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// All headers of the response.
+    pub headers: HeaderMap,
+}
+
+impl<T> ApiResponse<T> {
+    /// Returns the value of the given header, if it is present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Returns the `Location` header, which points to a newly created resource.
+    pub fn location(&self) -> Option<&str> {
+        self.headers.get(LOCATION).and_then(|v| v.to_str().ok())
+    }
+
+    /// Returns the `X-Total-Count` header, which holds the total number of items of a list.
+    /// This is the same count as [Paged::total_count](crate::pagination::Paged::total_count).
+    pub fn total_count(&self) -> Option<u64> {
+        total_count(&self.headers)
+    }
+
+    /// Converts the value of the response, keeping the status code and headers.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ApiResponse<U> {
+        ApiResponse {
+            value: f(self.value),
+            status: self.status,
+            headers: self.headers,
+        }
+    }
+}
+
+/// Parses the `X-Total-Count` header, which holds the total number of items of a list.
+pub(crate) fn total_count(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get("x-total-count")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
@@ -0,0 +1,132 @@
+//! Automatic retries for failed requests.
+//!
+//! Retries are disabled by default. Enable them by passing a [RetryPolicy] to
+//! [Client::with_retry](crate::Client::with_retry):
+//! ```
+//! # use std::time::Duration;
+//! # use gitea_sdk::{Client, Auth, retry::RetryPolicy};
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+//!     .with_retry(
+//!         RetryPolicy::new()
+//!             .max_attempts(5)
+//!             .initial_backoff(Duration::from_millis(500)),
+//!     );
+//! ```
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+/// Describes when and how often a failed request is retried.
+///
+/// A request is retried if it could not be sent (e.g. the connection was refused or timed out)
+/// or if the server responded with a 5xx status code. Between attempts, the client waits for an
+/// exponentially growing backoff: `initial_backoff * 2^(attempt - 1)`, capped at `max_backoff`.
+/// With jitter enabled, a random duration of up to the backoff is waited instead, so many clients
+/// failing at once don't retry in lockstep.
+///
+/// Rate limited requests (429 Too Many Requests) are retried as well, unless disabled with
+/// [RetryPolicy::retry_rate_limited]. If the server sends a `Retry-After` header with a 429 or
+/// 503 response, the client waits for that long instead of the backoff. A request is not retried
+/// if the server asks for a longer wait than `max_backoff`.
+///
+/// By default, only idempotent requests (e.g. GET, PUT and DELETE) are retried after a timeout or
+/// a 5xx response, as the server might have processed the request anyway. Retrying a POST
+/// request in that case could e.g. create an issue twice. Requests that never reached the server
+/// (the connection was refused) and rate limited requests are always retried. Use
+/// [RetryPolicy::retry_non_idempotent] to retry all requests.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) jitter: bool,
+    pub(crate) retry_rate_limited: bool,
+    pub(crate) retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retry_rate_limited: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy with 3 attempts, an initial backoff of 200ms, a maximum backoff of 10s,
+    /// jitter enabled, rate limited requests being retried and non-idempotent requests only being
+    /// retried if they never reached the server.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of attempts, including the first one.
+    /// A value of 1 disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// The backoff before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// The upper bound for the backoff between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Whether to randomize the backoff.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether to retry requests that were rejected with 429 Too Many Requests.
+    pub fn retry_rate_limited(mut self, retry_rate_limited: bool) -> Self {
+        self.retry_rate_limited = retry_rate_limited;
+        self
+    }
+
+    /// Whether to retry non-idempotent requests (POST and PATCH) after a timeout or a 5xx
+    /// response. Only enable this if creating a resource twice is not a problem.
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Returns how long to wait after the given (1-based) attempt failed.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+        // RandomState is seeded randomly, which is good enough for spreading out retries.
+        let random = RandomState::new().build_hasher().finish();
+        let nanos = backoff.as_nanos() as u64;
+        Duration::from_nanos(random % nanos.max(1))
+    }
+}
+
+/// Parses the `Retry-After` header of a response.
+/// Only the delay-seconds form is supported; HTTP dates are ignored.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse().ok().map(Duration::from_secs)
+}
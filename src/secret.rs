@@ -0,0 +1,63 @@
+//! A wrapper for credentials that keeps them out of logs.
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A string holding a secret, like a password or an access token.
+///
+/// Its [Debug] output is redacted, so printing a struct containing a secret with `{:?}` doesn't
+/// leak it. It deliberately doesn't implement [Display](std::fmt::Display); use
+/// [SecretString::expose_secret] to access the value. The secret is (de)serialized as a plain
+/// string.
+///
+/// # Example
+/// ```
+/// # use gitea_sdk::secret::SecretString;
+/// let secret = SecretString::from("hunter2");
+/// assert_eq!(format!("{secret:?}"), "[REDACTED]");
+/// assert_eq!(secret.expose_secret(), "hunter2");
+/// ```
+#[derive(Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Wraps the given secret.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Returns the secret value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the secret is empty, e.g. because the server didn't return it.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl From<&String> for SecretString {
+    fn from(secret: &String) -> Self {
+        Self(secret.clone())
+    }
+}
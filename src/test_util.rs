@@ -0,0 +1,293 @@
+//! Recording and replaying HTTP interactions in tests.
+//!
+//! A [Cassette] records the responses of a live Gitea instance to a JSON file once, and replays
+//! them afterwards without any network access. This allows testing code that uses the client
+//! against realistic responses without running Gitea in CI.
+//!
+//! ```no_run
+//! # use gitea_sdk::{Client, Auth, test_util::Cassette};
+//! # async fn test_get_user() -> gitea_sdk::error::Result<()> {
+//! // Records the interactions if the file doesn't exist yet, and replays them otherwise.
+//! let cassette = Cassette::open("tests/cassettes/get_user.json")?;
+//! let client = Client::new("http://localhost:3000", Auth::Token("your-token"))
+//!     .with_cassette(cassette.clone());
+//! let user = client.user().current().send(&client).await?;
+//! assert_eq!(user.login, "username");
+//! cassette.save()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Requires the `test-util` feature.
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_LENGTH, SET_COOKIE},
+    Request, Response, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::{Result, TeatimeError, TeatimeErrorKind, SENSITIVE_PARAMS},
+    HttpResult,
+};
+
+/// The environment variable that makes [Cassette::open] record even if the cassette exists.
+pub const RECORD_ENV: &str = "GITEA_SDK_RECORD";
+
+/// A file of recorded HTTP interactions. See the [module](self) documentation for details.
+///
+/// Only the method, path and query of requests are recorded, so the cassette can be replayed
+/// against any base URL and the authentication headers never end up in it. Tokens passed as
+/// `token` or `access_token` query parameters and the token returned when creating an access
+/// token (its `sha1`) are replaced with `REDACTED`. Other secrets in response bodies are
+/// recorded as they are. Requests are matched in the order they were recorded, so the same
+/// request can be answered differently over time.
+///
+/// Cloning a cassette is cheap; clones share the recorded interactions.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    path: PathBuf,
+    recording: bool,
+    interactions: Vec<Interaction>,
+    /// Which interactions have already been replayed.
+    replayed: Vec<bool>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    method: String,
+    path: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    /// The body, if it is valid UTF-8.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// The base64-encoded body, if it isn't valid UTF-8.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+}
+
+impl Interaction {
+    fn to_response(&self) -> Result<Response> {
+        let body = match (&self.body, &self.body_base64) {
+            (Some(body), _) => body.clone().into_bytes(),
+            (None, Some(body)) => STANDARD.decode(body).map_err(cassette_error)?,
+            (None, None) => Vec::new(),
+        };
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(cassette_error)?;
+            let value = HeaderValue::from_str(value).map_err(cassette_error)?;
+            headers.append(name, value);
+        }
+        let mut res = http::Response::new(body);
+        *res.status_mut() = StatusCode::from_u16(self.status).map_err(cassette_error)?;
+        *res.headers_mut() = headers;
+        Ok(Response::from(res))
+    }
+}
+
+impl Cassette {
+    /// Creates a cassette that records all interactions, overwriting the file when it is saved.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::new(path.as_ref().to_path_buf(), true, Vec::new())
+    }
+
+    /// Loads a cassette to replay its interactions.
+    /// Requests that weren't recorded fail with [TeatimeErrorKind::Other].
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::read(path)?;
+        let file: CassetteFile = serde_json::from_slice(&file).map_err(cassette_error)?;
+        Ok(Self::new(path.to_path_buf(), false, file.interactions))
+    }
+
+    /// Replays the cassette if the file exists, and records it otherwise.
+    /// Set the `GITEA_SDK_RECORD` environment variable to record it again.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if std::env::var_os(RECORD_ENV).is_some() || !path.exists() {
+            return Ok(Self::record(path));
+        }
+        Self::replay(path)
+    }
+
+    fn new(path: PathBuf, recording: bool, interactions: Vec<Interaction>) -> Self {
+        let replayed = vec![false; interactions.len()];
+        let inner = Inner {
+            path,
+            recording,
+            interactions,
+            replayed,
+        };
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// Whether the cassette records interactions instead of replaying them.
+    pub fn is_recording(&self) -> bool {
+        self.inner().recording
+    }
+
+    /// Writes the recorded interactions to the file, creating its parent directories.
+    /// Does nothing when replaying.
+    pub fn save(&self) -> Result<()> {
+        let inner = self.inner();
+        if !inner.recording {
+            return Ok(());
+        }
+        if let Some(parent) = inner.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = CassetteFile {
+            interactions: inner.interactions.clone(),
+        };
+        let json = serde_json::to_vec_pretty(&file).map_err(cassette_error)?;
+        std::fs::write(&inner.path, json)?;
+        Ok(())
+    }
+
+    fn inner(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Records the response to the request, or replays the recorded one.
+    pub(crate) async fn fetch<F, Fut>(&self, req: Request, send: F) -> Result<Response>
+    where
+        F: FnOnce(Request) -> Fut,
+        Fut: Future<Output = HttpResult>,
+    {
+        let method = req.method().to_string();
+        let path = match req.url().query() {
+            Some(query) => format!("{}?{}", req.url().path(), redact_query(query)),
+            None => req.url().path().to_string(),
+        };
+        if !self.is_recording() {
+            let mut inner = self.inner();
+            let Inner {
+                interactions,
+                replayed,
+                ..
+            } = &mut *inner;
+            let next = interactions
+                .iter()
+                .zip(replayed.iter_mut())
+                .find(|(i, replayed)| !**replayed && i.method == method && i.path == path);
+            let Some((interaction, replayed)) = next else {
+                return Err(cassette_error(format!(
+                    "No recorded interaction for {method} {path}"
+                )));
+            };
+            *replayed = true;
+            return interaction.to_response();
+        }
+
+        let res = send(req).await?;
+        let headers = res
+            .headers()
+            .iter()
+            // The length no longer matches a body with redacted tokens.
+            .filter(|(name, _)| *name != SET_COOKIE && *name != CONTENT_LENGTH)
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let status = res.status().as_u16();
+        let body = res.bytes().await?.to_vec();
+        let (body, body_base64) = match String::from_utf8(body) {
+            Ok(body) => (Some(redact_body(&body).unwrap_or(body)), None),
+            Err(e) => (None, Some(STANDARD.encode(e.into_bytes()))),
+        };
+        let interaction = Interaction {
+            method,
+            path,
+            status,
+            headers,
+            body,
+            body_base64,
+        };
+        let res = interaction.to_response()?;
+        self.inner().interactions.push(interaction);
+        Ok(res)
+    }
+}
+
+/// What recorded credentials are replaced with.
+const REDACTED: &str = "REDACTED";
+
+/// Replaces the values of the query parameters that can carry credentials.
+fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if SENSITIVE_PARAMS.contains(&name.to_lowercase().as_str()) => {
+                format!("{name}={REDACTED}")
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Replaces the tokens in a JSON body. Returns `None` if there was nothing to replace, so other
+/// bodies are recorded exactly as they were received.
+fn redact_body(body: &str) -> Option<String> {
+    let mut json = serde_json::from_str(body).ok()?;
+    redact_json(&mut json).then(|| json.to_string())
+}
+
+/// Replaces the `sha1` of access tokens, which is the token itself. Other objects have a `sha1`
+/// as well, e.g. package files, so only objects that look like an access token are touched.
+fn redact_json(value: &mut Value) -> bool {
+    match value {
+        Value::Object(object) => {
+            let mut redacted = false;
+            if object.contains_key("token_last_eight") {
+                if let Some(sha1) = object
+                    .get_mut("sha1")
+                    .filter(|sha1| !sha1.as_str().unwrap_or_default().is_empty())
+                {
+                    *sha1 = Value::from(REDACTED);
+                    redacted = true;
+                }
+            }
+            for value in object.values_mut() {
+                redacted |= redact_json(value);
+            }
+            redacted
+        }
+        Value::Array(values) => {
+            let mut redacted = false;
+            for value in values {
+                redacted |= redact_json(value);
+            }
+            redacted
+        }
+        _ => false,
+    }
+}
+
+fn cassette_error(err: impl ToString) -> TeatimeError {
+    TeatimeError {
+        message: format!("Cassette error: {}", err.to_string()),
+        kind: TeatimeErrorKind::Other,
+        status_code: StatusCode::BAD_REQUEST,
+        request_id: None,
+    }
+}
@@ -0,0 +1,168 @@
+//! Helpers for testing code that uses the client against a local [wiremock] server, enabled by
+//! the `testing` feature.
+//!
+//! [MockGitea::start] starts a server that already answers the most common requests with
+//! fixtures, and [MockGitea::client] returns a [Client] pointed at it:
+//! ```no_run
+//! # use gitea_sdk::testing::{self, MockGitea};
+//! # async fn test_get_repo() -> gitea_sdk::error::Result<()> {
+//! let gitea = MockGitea::start().await;
+//! let client = gitea.client();
+//! let repo = client
+//!     .repos(testing::OWNER, testing::REPO)
+//!     .get()
+//!     .send(&client)
+//!     .await?;
+//! assert_eq!(repo.name, testing::REPO);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Other responses can be stubbed on [MockGitea::server]. Those take precedence over the
+//! fixtures, so they can also be used to override them:
+//! ```no_run
+//! # use gitea_sdk::testing::MockGitea;
+//! use wiremock::{matchers::{method, path}, Mock, ResponseTemplate};
+//! # async fn test_missing_user() {
+//! let gitea = MockGitea::start().await;
+//! Mock::given(method("GET"))
+//!     .and(path("/api/v1/user"))
+//!     .respond_with(ResponseTemplate::new(401))
+//!     .mount(gitea.server())
+//!     .await;
+//! let client = gitea.client();
+//! assert!(client.user().current().send(&client).await.is_err());
+//! # }
+//! ```
+use serde::Serialize;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::{
+    model::{
+        issues::{Issue, StateType},
+        repos::{RepoPermissions, Repository},
+        user::User,
+    },
+    Auth, Client,
+};
+
+/// The login of the authenticated user, who also owns the fixture repository.
+pub const OWNER: &str = "gitea-user";
+/// The name of the fixture repository.
+pub const REPO: &str = "repo";
+/// The number of the fixture issue.
+pub const ISSUE: i64 = 1;
+/// The token the client returned by [MockGitea::client] authenticates with.
+pub const TOKEN: &str = "mock-token";
+
+/// Fixtures have a low priority, so mocks mounted by tests (priority 5 by default) win.
+const FIXTURE_PRIORITY: u8 = 10;
+
+/// A [wiremock] server that answers common Gitea requests with fixtures.
+/// See the [module documentation](self) for details.
+///
+/// The following requests are stubbed:
+/// - `GET /api/v1/user` and `GET /api/v1/users/{OWNER}` return [user].
+/// - `GET /api/v1/repos/{OWNER}/{REPO}` returns [repo].
+/// - `GET /api/v1/repos/{OWNER}/{REPO}/issues` returns a list containing [issue].
+/// - `GET /api/v1/repos/{OWNER}/{REPO}/issues/{ISSUE}` returns [issue].
+pub struct MockGitea {
+    server: MockServer,
+}
+
+impl MockGitea {
+    /// Starts a server on a random local port and mounts the fixtures.
+    pub async fn start() -> Self {
+        let gitea = Self {
+            server: MockServer::start().await,
+        };
+        gitea.mount_fixture("/api/v1/user", user()).await;
+        gitea
+            .mount_fixture(format!("/api/v1/users/{OWNER}"), user())
+            .await;
+        gitea
+            .mount_fixture(format!("/api/v1/repos/{OWNER}/{REPO}"), repo())
+            .await;
+        gitea
+            .mount_fixture(format!("/api/v1/repos/{OWNER}/{REPO}/issues"), [issue()])
+            .await;
+        gitea
+            .mount_fixture(
+                format!("/api/v1/repos/{OWNER}/{REPO}/issues/{ISSUE}"),
+                issue(),
+            )
+            .await;
+        gitea
+    }
+
+    async fn mount_fixture(&self, url_path: impl Into<String>, body: impl Serialize) {
+        Mock::given(method("GET"))
+            .and(path(url_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .with_priority(FIXTURE_PRIORITY)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Returns a client pointed at the server, authenticated with [TOKEN].
+    pub fn client(&self) -> Client {
+        Client::new(self.uri(), Auth::Token(TOKEN))
+    }
+
+    /// The underlying server, to mount additional mocks or inspect the received requests.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// The base URL of the server, e.g. to build a client with custom options.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+}
+
+/// The authenticated user, [OWNER].
+pub fn user() -> User {
+    User {
+        id: 1,
+        login: OWNER.to_string(),
+        full_name: "Gitea User".to_string(),
+        email: format!("{OWNER}@example.com"),
+        ..Default::default()
+    }
+}
+
+/// The repository [REPO], owned by [user].
+pub fn repo() -> Repository {
+    Repository {
+        id: 1,
+        name: REPO.to_string(),
+        full_name: format!("{OWNER}/{REPO}"),
+        owner: user(),
+        default_branch: "main".to_string(),
+        has_issues: true,
+        has_pull_requests: true,
+        open_issues_count: 1,
+        permissions: Some(RepoPermissions {
+            admin: true,
+            push: true,
+            pull: true,
+        }),
+        ..Default::default()
+    }
+}
+
+/// The open issue [ISSUE] in [repo], created by [user].
+pub fn issue() -> Issue {
+    Issue {
+        id: 1,
+        number: ISSUE,
+        title: "Something is broken".to_string(),
+        body: Some("Steps to reproduce: ...".to_string()),
+        state: StateType::Open,
+        user: user(),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,88 @@
+//! Client-side throttling of requests.
+//!
+//! Throttling is disabled by default. Enable it by passing a [Throttle] to
+//! [Client::with_throttle](crate::Client::with_throttle):
+//! ```
+//! # use gitea_sdk::{Client, Auth, throttle::Throttle};
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+//!     .with_throttle(Throttle::new().requests_per_second(5).max_in_flight(2));
+//! ```
+use std::{sync::Mutex, time::Duration};
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::time::{self, Instant};
+
+/// Limits how fast a [Client](crate::Client) sends requests.
+///
+/// Every request sent by the client (including retries) counts towards the limits, no matter
+/// which task sends it. Requests exceeding a limit wait until they are allowed to be sent.
+/// A request counts as in flight until the response headers were received; reading the body
+/// does not hold up other requests.
+#[derive(Debug, Clone, Default)]
+pub struct Throttle {
+    pub(crate) requests_per_second: Option<u32>,
+    pub(crate) max_in_flight: Option<usize>,
+}
+
+impl Throttle {
+    /// Creates a throttle without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of requests to send per second.
+    /// Requests are spread out evenly instead of being sent in bursts.
+    pub fn requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// The maximum number of requests to have in flight at the same time.
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+}
+
+/// The shared state enforcing a [Throttle].
+#[derive(Debug)]
+pub(crate) struct Limiter {
+    interval: Option<Duration>,
+    next_slot: Mutex<Option<Instant>>,
+    in_flight: Option<Semaphore>,
+}
+
+impl Limiter {
+    pub(crate) fn new(throttle: Throttle) -> Self {
+        Self {
+            interval: throttle
+                .requests_per_second
+                .filter(|rps| *rps > 0)
+                .map(|rps| Duration::from_secs(1) / rps),
+            next_slot: Mutex::new(None),
+            in_flight: throttle.max_in_flight.map(|max| Semaphore::new(max.max(1))),
+        }
+    }
+
+    /// Waits until the next request may be sent.
+    /// The returned permit must be held until the request completed.
+    pub(crate) async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        let permit = match &self.in_flight {
+            // The semaphore is never closed, so acquiring can't fail.
+            Some(semaphore) => semaphore.acquire().await.ok(),
+            None => None,
+        };
+        if let Some(interval) = self.interval {
+            let slot = {
+                let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+                let now = Instant::now();
+                let slot = next_slot.map_or(now, |next| next.max(now));
+                *next_slot = Some(slot + interval);
+                slot
+            };
+            time::sleep_until(slot).await;
+        }
+        permit
+    }
+}
@@ -0,0 +1,9 @@
+//! Timers that work both on native targets and in the browser, where tokio's timer is not
+//! available.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use tokio::time::{sleep, sleep_until, Instant};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasmtimer::{
+    std::Instant,
+    tokio::{sleep, sleep_until},
+};
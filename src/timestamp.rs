@@ -0,0 +1,189 @@
+//! The type of the timestamps in models and request options.
+//!
+//! By default, a [Timestamp] is the RFC 3339 string Gitea sends, e.g. `2024-12-31T00:00:00Z`.
+//! With the `chrono` feature, it is a [chrono::DateTime] in UTC instead:
+//! ```
+//! # use gitea_sdk::model::issues::Issue;
+//! # fn print_created(issue: &Issue) {
+//! # #[cfg(feature = "chrono")]
+//! println!("Opened on {}", issue.created_at.date_naive());
+//! # }
+//! ```
+//!
+//! With the `time` feature, it wraps a [time::OffsetDateTime] in UTC, which it dereferences to:
+//! ```
+//! # use gitea_sdk::model::issues::Issue;
+//! # fn print_created(issue: &Issue) {
+//! # #[cfg(all(feature = "time", not(feature = "chrono")))]
+//! println!("Opened on {}", issue.created_at.date());
+//! # }
+//! ```
+//! Both features change the same type, so only one of them can be in effect. If both are
+//! enabled, e.g. by two crates depending on this one, `chrono` takes precedence and the `time`
+//! feature has no effect.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+use std::{fmt, ops::Deref, str::FromStr};
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+
+/// A point in time, parsed into a [chrono::DateTime] in UTC.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// A point in time as an RFC 3339 string. Enable the `chrono` or `time` feature to parse it.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Timestamp = String;
+
+/// A point in time, parsed into a [time::OffsetDateTime] in UTC.
+///
+/// `time` has no default date, so this wrapper defaults to the Unix epoch like
+/// [chrono::DateTime] does. It parses from and displays as an RFC 3339 string.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub OffsetDateTime);
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl Default for Timestamp {
+    fn default() -> Self {
+        Self(OffsetDateTime::UNIX_EPOCH)
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl Deref for Timestamp {
+    type Target = OffsetDateTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl From<OffsetDateTime> for Timestamp {
+    fn from(ts: OffsetDateTime) -> Self {
+        Self(ts.to_offset(UtcOffset::UTC))
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl From<Timestamp> for OffsetDateTime {
+    fn from(ts: Timestamp) -> Self {
+        ts.0
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl FromStr for Timestamp {
+    type Err = time::error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OffsetDateTime::parse(s, &Rfc3339).map(Self::from)
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ts = self.0.format(&Rfc3339).map_err(|_| fmt::Error)?;
+        f.write_str(&ts)
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|e| de::Error::custom(format!("invalid timestamp {s:?}: {e}")))
+    }
+}
+
+/// (De)serializes a [Timestamp] as an RFC 3339 string.
+/// `null` and empty strings deserialize to the default timestamp.
+pub(crate) mod rfc3339 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Timestamp;
+
+    #[cfg(feature = "chrono")]
+    pub(crate) fn serialize<S: Serializer>(
+        ts: &Timestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&ts.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    pub(crate) fn serialize<S: Serializer>(
+        ts: &Timestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(ts)
+    }
+
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    pub(crate) fn serialize<S: Serializer>(
+        ts: &Timestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(ts)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Timestamp, D::Error> {
+        Ok(option::deserialize(deserializer)?.unwrap_or_default())
+    }
+
+    #[cfg(feature = "chrono")]
+    fn parse<E: serde::de::Error>(s: String) -> Result<Timestamp, E> {
+        chrono::DateTime::parse_from_rfc3339(&s)
+            .map(|ts| ts.with_timezone(&chrono::Utc))
+            .map_err(|e| E::custom(format!("invalid timestamp {s:?}: {e}")))
+    }
+
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    fn parse<E: serde::de::Error>(s: String) -> Result<Timestamp, E> {
+        s.parse()
+            .map_err(|e| E::custom(format!("invalid timestamp {s:?}: {e}")))
+    }
+
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    fn parse<E: serde::de::Error>(s: String) -> Result<Timestamp, E> {
+        Ok(s)
+    }
+
+    /// Like [rfc3339](super::rfc3339), but `null` and empty strings deserialize to `None`.
+    pub(crate) mod option {
+        use super::*;
+
+        pub(crate) fn serialize<S: Serializer>(
+            ts: &Option<Timestamp>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match ts {
+                Some(ts) => super::serialize(ts, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Timestamp>, D::Error> {
+            match Option::<String>::deserialize(deserializer)? {
+                Some(s) if !s.is_empty() => parse(s).map(Some),
+                _ => Ok(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Instrumentation of requests using [tracing], enabled by the `tracing` feature.
+//!
+//! Every request is wrapped in an `INFO` span named `gitea_request` with the method, path,
+//! response status and duration of the request. Request and response bodies are emitted as
+//! `DEBUG` events, with the values of sensitive fields (passwords, tokens, secrets, ...) redacted.
+use std::future::Future;
+
+use reqwest::{Request, Response};
+use serde_json::Value;
+use tracing::{field, Instrument, Span};
+
+use crate::{error::Result, time::Instant};
+
+/// Fields whose values are replaced in logged bodies if their name contains one of these.
+const SENSITIVE_FIELDS: &[&str] = &["password", "token", "secret", "private", "sha1", "otp"];
+
+/// Creates the span for the given request and logs its body.
+pub(crate) fn request_span(req: &Request) -> Span {
+    let span = tracing::info_span!(
+        "gitea_request",
+        method = %req.method(),
+        path = %req.url().path(),
+        status = field::Empty,
+        duration_ms = field::Empty,
+    );
+    if let Some(body) = req.body().and_then(|body| body.as_bytes()) {
+        tracing::debug!(parent: &span, body = %redact(body), "request body");
+    }
+    span
+}
+
+/// Runs the request inside the span and records the outcome on it.
+pub(crate) async fn record(
+    span: Span,
+    request: impl Future<Output = Result<Response>>,
+) -> Result<Response> {
+    let start = Instant::now();
+    let res = request.instrument(span.clone()).await;
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+    match &res {
+        Ok(res) => {
+            span.record("status", res.status().as_u16());
+        }
+        Err(err) => {
+            span.record("status", err.status_code.as_u16());
+            tracing::debug!(parent: &span, error = %redact(err.message.as_bytes()), "request failed");
+        }
+    }
+    res
+}
+
+/// Logs the body of a response.
+pub(crate) fn response_body(body: &str) {
+    tracing::debug!(body = %redact(body.as_bytes()), "response body");
+}
+
+/// Returns a loggable version of the body with all sensitive values redacted.
+/// Bodies that are not json are not logged at all, as they can't be redacted reliably.
+pub(crate) fn redact(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes>", body.len()),
+    }
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_lowercase();
+                if SENSITIVE_FIELDS.iter().any(|field| key.contains(field)) {
+                    *value = Value::String("[REDACTED]".to_string());
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
@@ -0,0 +1,284 @@
+//! The abstraction over sending requests that all request builders are generic over.
+//!
+//! [Client] implements [GiteaHttp] by actually talking to a Gitea instance. Applications can
+//! implement it themselves to answer requests in unit tests without a server:
+//! ```
+//! # use gitea_sdk::{error::Result, pagination::BoxFuture, Client, GiteaHttp};
+//! # use reqwest::{Method, Request, RequestBuilder, Response};
+//! # use std::fmt::Display;
+//! struct MockGitea {
+//!     http: reqwest::Client,
+//! }
+//!
+//! impl GiteaHttp for MockGitea {
+//!     fn request_base(&self, method: Method, path: impl Display) -> RequestBuilder {
+//!         self.http.request(method, format!("http://mock/api/v1/{path}"))
+//!     }
+//!
+//!     fn make_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+//!         let body = match req.url().path() {
+//!             "/api/v1/user" => r#"{"id": 1, "login": "mock"}"#,
+//!             _ => "{}",
+//!         };
+//!         Box::pin(async move { Ok(http::Response::new(body).into()) })
+//!     }
+//! }
+//!
+//! # async fn test_current_user() {
+//! let mock = MockGitea { http: reqwest::Client::new() };
+//! // The `client` accessors only create builders, so any client can be used for them.
+//! let client = Client::new("http://unused", gitea_sdk::Auth::None::<String>);
+//! let user = client.user().current().send(&mock).await;
+//! # }
+//! ```
+use std::{fmt::Display, sync::Arc};
+
+use bytes::Bytes;
+use futures_util::{stream, Stream};
+use reqwest::{Method, Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    error::{Result, TeatimeError, TeatimeErrorKind},
+    pagination::{BoxFuture, Paged},
+    response::ApiResponse,
+    Client,
+};
+
+/// Creates and sends requests to the Gitea API.
+/// See the [module documentation](self) for details.
+///
+/// Only [GiteaHttp::request_base] and [GiteaHttp::make_request] need to be implemented; the
+/// other methods build on them.
+pub trait GiteaHttp: Send + Sync {
+    /// Creates a new request builder with the given method and path.
+    /// `path` is relative to `{base_url}/api/v1/`.
+    fn request_base(&self, method: Method, path: impl Display) -> RequestBuilder;
+
+    /// Sends a request and turns error responses into a [TeatimeError].
+    fn make_request(&self, req: Request) -> BoxFuture<'_, Result<Response>>;
+
+    /// Sends a request whose response body is streamed to the caller, e.g. an archive.
+    /// Defaults to [GiteaHttp::make_request].
+    fn make_download_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        self.make_request(req)
+    }
+
+    /// Creates a new GET-request builder with the given path.
+    fn get(&self, path: impl Display) -> RequestBuilder {
+        self.request_base(Method::GET, path)
+    }
+
+    /// Creates a new POST-request builder with the given path.
+    fn post(&self, path: impl Display) -> RequestBuilder {
+        self.request_base(Method::POST, path)
+    }
+
+    /// Creates a new PUT-request builder with the given path.
+    fn put(&self, path: impl Display) -> RequestBuilder {
+        self.request_base(Method::PUT, path)
+    }
+
+    /// Creates a new PATCH-request builder with the given path.
+    fn patch(&self, path: impl Display) -> RequestBuilder {
+        self.request_base(Method::PATCH, path)
+    }
+
+    /// Creates a new DELETE-request builder with the given path.
+    fn delete(&self, path: impl Display) -> RequestBuilder {
+        self.request_base(Method::DELETE, path)
+    }
+
+    /// Parses a json response into a given model.
+    fn parse_response<'a, T>(&'a self, res: Response) -> BoxFuture<'a, Result<T>>
+    where
+        T: DeserializeOwned + 'a,
+    {
+        Box::pin(parse_response(res))
+    }
+
+    /// Parses a json response into a given model, keeping its status code and headers.
+    fn parse_api_response<'a, T>(&'a self, res: Response) -> BoxFuture<'a, Result<ApiResponse<T>>>
+    where
+        T: DeserializeOwned + 'a,
+    {
+        Box::pin(parse_api_response(res))
+    }
+
+    /// Parses a json list response into a [Paged] list, keeping its pagination info.
+    fn parse_paged_response<'a, T>(&'a self, res: Response) -> BoxFuture<'a, Result<Paged<T>>>
+    where
+        T: DeserializeOwned + 'a,
+    {
+        Box::pin(parse_paged_response(res))
+    }
+}
+
+impl GiteaHttp for Client {
+    fn request_base(&self, method: Method, path: impl Display) -> RequestBuilder {
+        Client::request_base(self, method, path)
+    }
+
+    fn make_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(Client::make_request(self, req))
+    }
+
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    fn make_download_request(&self, mut req: Request) -> BoxFuture<'_, Result<Response>> {
+        // The browser picks the encoding itself.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use reqwest::header::{HeaderValue, ACCEPT_ENCODING};
+            let identity = HeaderValue::from_static("identity");
+            req.headers_mut().insert(ACCEPT_ENCODING, identity);
+        }
+        Box::pin(Client::make_request(self, req))
+    }
+}
+
+impl<C: GiteaHttp> GiteaHttp for &C {
+    fn request_base(&self, method: Method, path: impl Display) -> RequestBuilder {
+        (**self).request_base(method, path)
+    }
+
+    fn make_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        (**self).make_request(req)
+    }
+
+    fn make_download_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        (**self).make_download_request(req)
+    }
+}
+
+impl<C: GiteaHttp> GiteaHttp for Arc<C> {
+    fn request_base(&self, method: Method, path: impl Display) -> RequestBuilder {
+        (**self).request_base(method, path)
+    }
+
+    fn make_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        (**self).make_request(req)
+    }
+
+    fn make_download_request(&self, req: Request) -> BoxFuture<'_, Result<Response>> {
+        (**self).make_download_request(req)
+    }
+}
+
+pub(crate) async fn parse_response<T: DeserializeOwned>(res: Response) -> Result<T> {
+    let status_code = res.status();
+    let text = res.text().await?;
+    #[cfg(feature = "tracing")]
+    crate::trace::response_body(&text);
+    serde_json::from_str(&text).map_err(|e| TeatimeError {
+        message: format!("Error parsing response: {}", e),
+        kind: TeatimeErrorKind::SerializationError,
+        status_code,
+        request_id: None,
+    })
+}
+
+pub(crate) async fn parse_api_response<T: DeserializeOwned>(
+    res: Response,
+) -> Result<ApiResponse<T>> {
+    let status = res.status();
+    let headers = res.headers().clone();
+    let value = parse_response(res).await?;
+    Ok(ApiResponse {
+        value,
+        status,
+        headers,
+    })
+}
+
+/// Streams the body of a response chunk by chunk as it arrives.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn body_stream(res: Response) -> impl Stream<Item = Result<Bytes>> {
+    stream::try_unfold(res, |mut res| async move {
+        Ok(res.chunk().await?.map(|chunk| (chunk, res)))
+    })
+}
+
+/// Streams the body of a response. The browser only hands out the whole body at once.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn body_stream(res: Response) -> impl Stream<Item = Result<Bytes>> {
+    stream::once(async move { Ok(res.bytes().await?) })
+}
+
+/// Streams the body of a response into `writer`, returning the number of bytes written.
+pub(crate) async fn copy_response<W>(res: Response, writer: &mut W) -> Result<u64>
+where
+    W: AsyncWrite + Unpin,
+{
+    use futures_util::TryStreamExt;
+
+    let mut written = 0;
+    let mut body = std::pin::pin!(body_stream(res));
+    while let Some(chunk) = body.try_next().await? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+
+pub(crate) async fn parse_paged_response<T: DeserializeOwned>(res: Response) -> Result<Paged<T>> {
+    let headers = res.headers().clone();
+    let items = parse_response(res).await?;
+    Ok(Paged::new(items, &headers))
+}
+
+/// Adds a `send_blocking` method next to a builder's `send` when the `blocking` feature is
+/// enabled. It is invoked inside the builder's `impl` block with the output type of `send`,
+/// prefixed by `self,` if `send` takes the builder by value.
+macro_rules! send_blocking {
+    ($ret:ty) => {
+        /// Like `send`, but blocks the current thread until the request finished.
+        /// See [blocking](crate::blocking) for details.
+        #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+        pub fn send_blocking(
+            &self,
+            client: &$crate::blocking::Client,
+        ) -> $crate::error::Result<$ret> {
+            client.block_on(self.send(client))
+        }
+    };
+    (self, $ret:ty) => {
+        /// Like `send`, but blocks the current thread until the request finished.
+        /// See [blocking](crate::blocking) for details.
+        #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+        pub fn send_blocking(
+            self,
+            client: &$crate::blocking::Client,
+        ) -> $crate::error::Result<$ret> {
+            client.block_on(self.send(client))
+        }
+    };
+}
+pub(crate) use send_blocking;
+
+/// Adds a `timeout` setter to a request builder with a `timeout: Option<Duration>` field, and a
+/// `with_timeout` method applying it to the requests the builder sends.
+macro_rules! request_timeout {
+    () => {
+        /// Sets the timeout for this request, from sending it until the response body has been
+        /// read. This takes precedence over the client's timeout, see
+        /// [Client::with_timeout](crate::Client::with_timeout).
+        ///
+        /// Unlike the client's timeout, this also applies to requests that stream a file.
+        pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        // Builders that move their fields into the request apply the timeout themselves.
+        #[allow(dead_code)]
+        fn with_timeout(&self, mut req: reqwest::Request) -> reqwest::Request {
+            if let Some(timeout) = self.timeout {
+                *req.timeout_mut() = Some(timeout);
+            }
+            req
+        }
+    };
+}
+pub(crate) use request_timeout;
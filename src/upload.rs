@@ -0,0 +1,84 @@
+//! Uploading files without loading them into memory.
+//!
+//! A [FileUpload] streams its contents from any [AsyncRead] while the request is sent, so even
+//! multi-gigabyte files can be uploaded with a constant amount of memory. It can be passed to
+//! the attachment builders (e.g. [Attachments::upload](crate::api::issues::attachments::Attachments::upload))
+//! or sent to any endpoint with [Client::upload](crate::Client::upload).
+use std::{fmt, pin::Pin};
+
+use bytes::BytesMut;
+use futures_util::stream;
+use reqwest::{
+    multipart::{Form, Part},
+    Body,
+};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// How many bytes are read from the file at once.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// A file whose contents are streamed from an [AsyncRead] when it is uploaded.
+///
+/// The length must be known up front, as Gitea rejects uploads without a `Content-Length`.
+///
+/// # Example
+/// ```no_run
+/// # use gitea_sdk::upload::FileUpload;
+/// # async fn open_file() -> std::io::Result<()> {
+/// let file = tokio::fs::File::open("build.log").await?;
+/// let length = file.metadata().await?.len();
+/// let upload = FileUpload::new("build.log", file, length);
+/// # Ok(())
+/// # }
+/// ```
+pub struct FileUpload {
+    file_name: String,
+    length: u64,
+    reader: Pin<Box<dyn AsyncRead + Send>>,
+}
+
+impl fmt::Debug for FileUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileUpload")
+            .field("file_name", &self.file_name)
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileUpload {
+    /// Creates an upload of `length` bytes read from `reader`, named `file_name`.
+    pub fn new(
+        file_name: impl ToString,
+        reader: impl AsyncRead + Send + 'static,
+        length: u64,
+    ) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            length,
+            reader: Box::pin(reader),
+        }
+    }
+
+    /// The name of the uploaded file.
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// The number of bytes that will be uploaded.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Turns the upload into a multipart form with the file as the given field.
+    pub(crate) fn into_form(self, field: impl Into<std::borrow::Cow<'static, str>>) -> Form {
+        let chunks = stream::try_unfold(self.reader, |mut reader| async move {
+            let mut buf = BytesMut::with_capacity(CHUNK_SIZE);
+            let read = reader.read_buf(&mut buf).await?;
+            std::io::Result::Ok((read > 0).then(|| (buf.freeze(), reader)))
+        });
+        let body = Body::wrap_stream(chunks);
+        let part = Part::stream_with_length(body, self.length).file_name(self.file_name);
+        Form::new().part(field, part)
+    }
+}
@@ -1,6 +1,31 @@
-use std::env;
+use std::{
+    env,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
-use gitea_sdk::{error::Result, Auth, Client};
+use futures_util::TryStreamExt;
+use gitea_sdk::{
+    api::issues::labels::create::CreateRepoLabelBuilder,
+    cache::ResponseCache,
+    error::Result,
+    model::{
+        hooks::HookType,
+        issues::{Issue, StateType},
+        orgs::Visibility,
+        packages::PackageType,
+        repos::{MergeStyle, RepoSearchMode, SortKey, SortOrder},
+        team::Permission,
+        user::TokenScope,
+    },
+    pagination::Pageable,
+    retry::RetryPolicy,
+    throttle::Throttle,
+    upload::FileUpload,
+    Auth, Client, GiteaHttp, TokenProvider,
+};
 use reqwest::Method;
 use testcontainers::{
     core::{wait::HttpWaitStrategy, IntoContainerPort, WaitFor},
@@ -11,12 +36,15 @@ use testcontainers::{
 static GITEA_USER: &str = "test-user";
 static GITEA_PASSWORD: &str = "test-password";
 
-static _ADMIN_USER: &str = "test-admin";
-static _ADMIN_PASSWORD: &str = "test-password";
+static ADMIN_USER: &str = "test-admin";
+static ADMIN_PASSWORD: &str = "test-password";
 
 static GITEA_REPO: &str = "test-repo";
 static GITEA_REPO_DESCRIPTION: &str = "a test repo";
 
+static SSH_PUBLIC_KEY: &str =
+    "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOiG7rnY6ZyZSJrAdRqPhbdorvZzjrxl54I5yxOIzJI3 test";
+
 #[tokio::test]
 pub async fn test_client() {
     let wait_strategy = HttpWaitStrategy::new("/user/login")
@@ -87,6 +115,117 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_user_update_settings");
     test_user_update_settings(base_url, &token).await?;
 
+    println!("test_user_manage_keys");
+    test_user_manage_keys(base_url, &token).await?;
+
+    println!("test_user_gpg_key_token");
+    test_user_gpg_key_token(base_url, &token).await?;
+
+    println!("test_user_manage_emails");
+    test_user_manage_emails(base_url, &token).await?;
+
+    println!("test_user_list_followers");
+    test_user_list_followers(base_url, &token).await?;
+
+    println!("test_user_follow");
+    test_user_follow(base_url, &token).await?;
+
+    println!("test_user_manage_hooks");
+    test_user_manage_hooks(base_url, &token).await?;
+
+    println!("test_admin_list_emails");
+    test_admin_list_emails(base_url).await?;
+
+    println!("test_admin_crons");
+    test_admin_crons(base_url).await?;
+
+    println!("test_admin_manage_hooks");
+    test_admin_manage_hooks(base_url).await?;
+
+    println!("test_admin_user_keys");
+    test_admin_user_keys(base_url).await?;
+
+    println!("test_admin_create_org");
+    test_admin_create_org(base_url, &token).await?;
+
+    println!("test_admin_create_repo");
+    test_admin_create_repo(base_url, &token).await?;
+
+    println!("test_notification_count");
+    test_notification_count(base_url, &token).await?;
+
+    println!("test_list_packages");
+    test_list_packages(base_url, &token).await?;
+
+    println!("test_get_delete_package");
+    test_get_delete_package(base_url, &token).await?;
+
+    println!("test_templates");
+    test_templates(base_url, &token).await?;
+
+    println!("test_dynamic_auth");
+    test_dynamic_auth(base_url, &token).await?;
+
+    println!("test_client_builder");
+    test_client_builder(base_url, &token).await?;
+
+    println!("test_request_timeout");
+    test_request_timeout(base_url, &token).await?;
+
+    println!("test_retry");
+    test_retry(base_url, &token).await?;
+
+    println!("test_throttle");
+    test_throttle(base_url, &token).await?;
+
+    println!("test_cache");
+    test_cache(base_url, &token).await?;
+
+    println!("test_sudo");
+    test_sudo(base_url, &token).await?;
+
+    println!("test_batch");
+    test_batch(base_url, &token).await?;
+
+    println!("test_send_with_response");
+    test_send_with_response(base_url, &token).await?;
+
+    println!("test_observers");
+    test_observers(base_url, &token).await?;
+
+    println!("test_redaction");
+    test_redaction(base_url, &token).await?;
+
+    println!("test_login");
+    test_login(base_url).await?;
+
+    println!("test_default_headers");
+    test_default_headers(base_url, &token).await?;
+
+    println!("test_request_id");
+    test_request_id(base_url, &token).await?;
+
+    #[cfg(feature = "test-util")]
+    {
+        println!("test_cassette");
+        test_cassette(base_url, &token).await?;
+    }
+
+    println!("test_mock_transport");
+    test_mock_transport().await?;
+
+    #[cfg(feature = "chrono")]
+    {
+        println!("test_chrono_timestamps");
+        test_chrono_timestamps(base_url, &token).await?;
+    }
+
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    {
+        println!("test_time_timestamps");
+        test_time_timestamps(base_url, &token).await?;
+    }
+
     println!("test_create_org");
     test_create_org(base_url, &token).await?;
 
@@ -108,6 +247,9 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_org_is_member");
     test_org_is_member(base_url, &token).await?;
 
+    println!("test_org_manage_team");
+    test_org_manage_team(base_url, &token).await?;
+
     // TODO: remove member from org
 
     println!("test_org_create_repo");
@@ -116,6 +258,9 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_org_list_repos");
     test_org_list_repos(base_url, &token).await?;
 
+    println!("test_org_team_repos");
+    test_org_team_repos(base_url, &token).await?;
+
     println!("test_org_delete_repo");
     test_org_delete_repo(base_url, &token).await?;
 
@@ -131,12 +276,21 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_users_list_orgs");
     test_users_list_orgs(base_url, &token).await?;
 
+    println!("test_users_org_permissions");
+    test_users_org_permissions(base_url, &token).await?;
+
+    println!("test_org_actions_secrets");
+    test_org_actions_secrets(base_url, &token).await?;
+
     println!("test_delete_org");
     test_delete_org(base_url, &token).await?;
 
     println!("test_create_repo");
     test_create_repo(base_url, &token).await?;
 
+    println!("test_download");
+    test_download(base_url, &token).await?;
+
     println!("test_repo_create_branch");
     test_repo_create_branch(base_url, &token).await?;
 
@@ -194,9 +348,48 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_edit_issue");
     test_edit_issue(base_url, &token).await?;
 
+    println!("test_issue_add_labels");
+    test_issue_add_labels(base_url, &token).await?;
+
+    println!("test_issue_clear_labels");
+    test_issue_clear_labels(base_url, &token).await?;
+
+    println!("test_issue_create_attachment");
+    test_issue_create_attachment(base_url, &token).await?;
+
+    println!("test_issue_list_attachments");
+    test_issue_list_attachments(base_url, &token).await?;
+
+    println!("test_issue_upload_attachment");
+    test_issue_upload_attachment(base_url, &token).await?;
+
+    println!("test_issue_add_time");
+    test_issue_add_time(base_url, &token).await?;
+
+    println!("test_repo_list_times");
+    test_repo_list_times(base_url, &token).await?;
+
+    println!("test_pin_issue");
+    test_pin_issue(base_url, &token).await?;
+
+    println!("test_list_pinned_issues");
+    test_list_pinned_issues(base_url, &token).await?;
+
+    println!("test_close_issue");
+    test_close_issue(base_url, &token).await?;
+
+    println!("test_reopen_issue");
+    test_reopen_issue(base_url, &token).await?;
+
     println!("test_create_comment");
     test_create_comment(base_url, &token).await?;
 
+    println!("test_comment_add_reaction");
+    test_comment_add_reaction(base_url, &token).await?;
+
+    println!("test_comment_list_reactions");
+    test_comment_list_reactions(base_url, &token).await?;
+
     println!("test_edit_comment");
     test_edit_comment(base_url, &token).await?;
 
@@ -206,9 +399,21 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_list_repo_comments");
     test_list_repo_comments(base_url, &token).await?;
 
+    println!("test_issue_dependencies");
+    test_issue_dependencies(base_url, &token).await?;
+
     println!("test_delete_issue");
     test_delete_issue(base_url, &token).await?;
 
+    println!("test_user_list_subscriptions");
+    test_user_list_subscriptions(base_url, &token).await?;
+
+    println!("test_users_activities");
+    test_users_activities(base_url, &token).await?;
+
+    println!("test_repo_list_action_tasks");
+    test_repo_list_action_tasks(base_url, &token).await?;
+
     println!("test_delete_repo");
     test_delete_repo(base_url, &token).await?;
 
@@ -224,6 +429,12 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_search_users");
     test_search_users(base_url, &token).await?;
 
+    println!("test_search_topics");
+    test_search_topics(base_url, &token).await?;
+
+    println!("test_pagination");
+    test_pagination(base_url, &token).await?;
+
     Ok(())
 }
 
@@ -233,18 +444,19 @@ pub async fn test_base_client(base_url: &str) -> Result<Client> {
 
 pub async fn test_create_token(base_url: &str) -> Result<String> {
     let client = Client::new(base_url, Auth::Basic(GITEA_USER, GITEA_PASSWORD));
-    let scopes = vec![
-        "write:repository",
-        "write:user",
-        "write:issue",
-        "write:organization",
-    ];
+    let scopes = TokenScope::WriteRepository
+        | TokenScope::WriteUser
+        | TokenScope::WriteIssue
+        | TokenScope::WriteOrganization;
     let token = client
         .user()
         .create_access_token(GITEA_USER, "gritty-token", scopes)
         .send(&client)
         .await?;
-    Ok(token.sha1)
+    let scopes = token.scopes.clone().unwrap_or_default();
+    assert!(scopes.contains(TokenScope::WriteRepository));
+    assert!(scopes.contains(TokenScope::WriteOrganization));
+    Ok(token.sha1.expose_secret().to_string())
 }
 
 pub async fn test_list_tokens(base_url: &str, _token: &str) -> Result<()> {
@@ -363,10 +575,68 @@ pub async fn test_edit_org(base_url: &str, token: &str) -> Result<()> {
         .orgs("test-org")
         .edit()
         .description("a new test org")
+        .email("test-org@example.com")
+        .location("Earth")
+        .website("https://example.com")
+        .repo_admin_change_team_access(true)
+        .visibility(Visibility::Public)
         .send(&client)
         .await?;
     assert_eq!(org.name, "test-org");
     assert_eq!(org.description, Some("a new test org".to_string()));
+
+    // Everything the edit sets is read back.
+    let org = client.orgs("test-org").get().send(&client).await?;
+    assert_eq!(org.email, Some("test-org@example.com".to_string()));
+    assert_eq!(org.location, Some("Earth".to_string()));
+    assert_eq!(org.website, Some("https://example.com".to_string()));
+    assert!(org.repo_admin_change_team_access);
+    assert_eq!(org.visibility, Visibility::Public);
+    Ok(())
+}
+
+pub async fn test_org_manage_team(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let teams = client.orgs("test-org").teams();
+    let team = teams
+        .create("test-team")
+        .description("a test team")
+        .permission(Permission::Read)
+        .units(vec!["repo.code".to_string(), "repo.issues".to_string()])
+        .can_create_org_repo(true)
+        .send(&client)
+        .await?;
+    assert_eq!(team.name, "test-team");
+    assert_eq!(team.permission, Permission::Read);
+    assert!(team.can_create_org_repo);
+    assert!(!team.includes_all_repositories);
+    assert_eq!(team.units_map.get("repo.code").unwrap(), "read");
+
+    let list = teams.list().send(&client).await?;
+    assert!(list.iter().any(|t| t.name == "test-team"));
+    let found = teams.search("test").send(&client).await?;
+    assert_eq!(found.len(), 1);
+    let mine = client.user().teams().send(&client).await?;
+    let owners = mine.iter().find(|t| t.name == "Owners").unwrap();
+    assert_eq!(owners.organization.as_ref().unwrap().name, "test-org");
+
+    let team = teams.get(team.id).send(&client).await?;
+    assert_eq!(team.description, "a test team");
+
+    let team = teams.edit(team.id, "edited-team").send(&client).await?;
+    assert_eq!(team.name, "edited-team");
+
+    let members = teams.members(team.id);
+    members.add(GITEA_USER).send(&client).await?;
+    let users = members.list().send(&client).await?;
+    assert!(users.iter().any(|u| u.login == GITEA_USER));
+    let user = members.get(GITEA_USER).send(&client).await?;
+    assert_eq!(user.login, GITEA_USER);
+    members.remove(GITEA_USER).send(&client).await?;
+    assert!(!teams.is_member(team.id, GITEA_USER).send(&client).await?);
+
+    teams.delete(team.id).send(&client).await?;
+    assert!(teams.get(team.id).send(&client).await.is_err());
     Ok(())
 }
 
@@ -386,6 +656,32 @@ pub async fn test_org_create_repo(base_url: &str, token: &str) -> Result<()> {
     Ok(())
 }
 
+pub async fn test_org_team_repos(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let teams = client.orgs("test-org").teams();
+    let team = teams
+        .create("repo-team")
+        .permission(Permission::Read)
+        .send(&client)
+        .await?;
+
+    let repos = teams.repos(team.id);
+    repos.add("test-org", "test-repo").send(&client).await?;
+    let list = repos.list().send(&client).await?;
+    assert_eq!(list.len(), 1);
+    let repo = repos.get("test-org", "test-repo").send(&client).await?;
+    assert_eq!(repo.name, "test-repo");
+    repos.remove("test-org", "test-repo").send(&client).await?;
+    assert!(repos
+        .get("test-org", "test-repo")
+        .send(&client)
+        .await
+        .is_err());
+
+    teams.delete(team.id).send(&client).await?;
+    Ok(())
+}
+
 pub async fn test_org_delete_repo(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     client
@@ -405,6 +701,30 @@ pub async fn test_org_list_repos(base_url: &str, token: &str) -> Result<()> {
     Ok(())
 }
 
+pub async fn test_users_org_permissions(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let permissions = client
+        .users(GITEA_USER)
+        .org_permissions("test-org")
+        .send(&client)
+        .await?;
+    assert!(permissions.is_owner);
+    assert!(permissions.can_create_repository);
+    Ok(())
+}
+
+pub async fn test_org_actions_secrets(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let secrets = client.orgs("test-org").actions().secrets();
+    secrets.put("TEST_SECRET", "value").send(&client).await?;
+    let list = secrets.list().send(&client).await?;
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].name, "TEST_SECRET");
+    secrets.delete("TEST_SECRET").send(&client).await?;
+    assert!(secrets.list().send(&client).await?.is_empty());
+    Ok(())
+}
+
 pub async fn test_delete_org(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     client.orgs("test-org").delete().send(&client).await?;
@@ -431,6 +751,7 @@ pub async fn test_get_user(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     let user = client.user().current().send(&client).await?;
     assert_eq!(user.login, GITEA_USER);
+    assert_eq!(user.visibility, Visibility::Public);
     Ok(())
 }
 
@@ -453,242 +774,2282 @@ pub async fn test_user_update_settings(base_url: &str, token: &str) -> Result<()
     Ok(())
 }
 
-pub async fn test_create_repo(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_user_manage_keys(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let repo = client
-        .user()
-        .create_repo(GITEA_REPO)
-        .description(GITEA_REPO_DESCRIPTION)
-        .license("MIT")
-        .auto_init(true)
+    let keys = client.user().keys();
+    let key = keys
+        .create("test-key", SSH_PUBLIC_KEY)
         .send(&client)
         .await?;
-    assert_eq!(repo.owner.login, GITEA_USER);
-    assert_eq!(repo.name, GITEA_REPO);
-    assert_eq!(repo.description, GITEA_REPO_DESCRIPTION);
+    assert_eq!(key.title, "test-key");
+
+    let list = keys.list().send(&client).await?;
+    assert_eq!(list.len(), 1);
+    let fetched = keys.get(key.id).send(&client).await?;
+    assert_eq!(fetched.fingerprint, key.fingerprint);
+    let public = client.users(GITEA_USER).keys().send(&client).await?;
+    assert_eq!(public.len(), 1);
+
+    keys.delete(key.id).send(&client).await?;
+    assert!(keys.list().send(&client).await?.is_empty());
     Ok(())
 }
 
-pub async fn test_repo_create_branch(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_user_gpg_key_token(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    client
-        .repos(GITEA_USER, GITEA_REPO)
-        .create_branch("new-branch")
-        .send(&client)
-        .await?;
+    let gpg_keys = client.user().gpg_keys();
+    let gpg_token = gpg_keys.get_token().send(&client).await?;
+    assert!(!gpg_token.is_empty());
+    assert!(gpg_keys.list().send(&client).await?.is_empty());
+    let public = client.users(GITEA_USER).gpg_keys().send(&client).await?;
+    assert!(public.is_empty());
     Ok(())
 }
 
-pub async fn test_repo_get_branch(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_user_manage_emails(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let branch = client
-        .repos(GITEA_USER, GITEA_REPO)
-        .get_branch("new-branch")
+    let emails = client.user().emails();
+    let added = emails.add(vec!["second@example.com"]).send(&client).await?;
+    assert_eq!(added.len(), 1);
+    assert!(!added[0].primary);
+
+    let list = emails.list().send(&client).await?;
+    assert_eq!(list.len(), 2);
+    emails
+        .delete(vec!["second@example.com"])
         .send(&client)
         .await?;
-    assert_eq!(branch.name, "new-branch");
+    assert_eq!(emails.list().send(&client).await?.len(), 1);
     Ok(())
 }
 
-pub async fn test_repo_list_branches(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_user_list_followers(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let branches = client
-        .repos(GITEA_USER, GITEA_REPO)
-        .list_branches()
-        .send(&client)
-        .await?;
-    assert_eq!(branches.len(), 2);
-    assert_eq!(branches[0].name, "main");
+    let followers = client.user().followers().send(&client).await?;
+    assert!(followers.is_empty());
+    let following = client.users(GITEA_USER).following().send(&client).await?;
+    assert!(following.is_empty());
     Ok(())
 }
 
-pub async fn test_repo_delete_branch(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_user_follow(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    client
-        .repos(GITEA_USER, GITEA_REPO)
-        .delete_branch("new-branch")
-        .send(&client)
-        .await?;
+    client.user().follow(ADMIN_USER).send(&client).await?;
+    assert!(client.user().is_following(ADMIN_USER).send(&client).await?);
+    assert!(
+        client
+            .users(GITEA_USER)
+            .is_following(ADMIN_USER)
+            .send(&client)
+            .await?
+    );
+
+    client.user().unfollow(ADMIN_USER).send(&client).await?;
+    assert!(!client.user().is_following(ADMIN_USER).send(&client).await?);
     Ok(())
 }
 
-pub async fn test_user_list_repos(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_user_manage_hooks(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let repo = client
-        .user()
-        .list_repos()
-        .limit(10)
-        .page(1)
+    let hooks = client.user().hooks();
+    let hook = hooks
+        .create(HookType::Gitea, "http://localhost:8080/hook")
+        .active(true)
         .send(&client)
         .await?;
-    assert_eq!(repo.len(), 1);
-    assert_eq!(repo[0].owner.login, GITEA_USER);
-    assert_eq!(repo[0].name, GITEA_REPO);
-    assert_eq!(repo[0].description, GITEA_REPO_DESCRIPTION);
+    assert!(hook.active);
+
+    let hook = hooks.edit(hook.id).active(false).send(&client).await?;
+    assert!(!hook.active);
+    assert_eq!(hooks.list().send(&client).await?.len(), 1);
+    let fetched = hooks.get(hook.id).send(&client).await?;
+    assert_eq!(fetched.id, hook.id);
+
+    hooks.delete(hook.id).send(&client).await?;
+    assert!(hooks.list().send(&client).await?.is_empty());
     Ok(())
 }
 
-pub async fn test_users_list_repos(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let repo = client
-        .users(GITEA_USER)
-        .list_repos()
-        .limit(10)
-        .page(1)
+pub async fn test_admin_list_emails(base_url: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Basic(ADMIN_USER, ADMIN_PASSWORD));
+    let emails = client.admin().emails().list().send(&client).await?;
+    assert!(emails.iter().any(|e| e.username == GITEA_USER));
+    let emails = client
+        .admin()
+        .emails()
+        .search(GITEA_USER)
         .send(&client)
         .await?;
-    assert_eq!(repo.len(), 1);
-    assert_eq!(repo[0].owner.login, GITEA_USER);
-    assert_eq!(repo[0].name, GITEA_REPO);
-    assert_eq!(repo[0].description, GITEA_REPO_DESCRIPTION);
+    assert!(emails.iter().all(|e| e.username == GITEA_USER));
     Ok(())
 }
 
-pub async fn test_get_repo(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let repo = client
-        .repos(GITEA_USER, GITEA_REPO)
-        .get()
+pub async fn test_admin_crons(base_url: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Basic(ADMIN_USER, ADMIN_PASSWORD));
+    let crons = client.admin().crons().list().send(&client).await?;
+    assert!(crons.iter().any(|c| c.name == "repo_health_check"));
+    client
+        .admin()
+        .crons()
+        .run("repo_health_check")
         .send(&client)
         .await?;
-    assert_eq!(repo.owner.login, GITEA_USER);
-    assert_eq!(repo.name, GITEA_REPO);
-    assert_eq!(repo.description, GITEA_REPO_DESCRIPTION);
     Ok(())
 }
 
-pub async fn test_edit_repo(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let repo = client
-        .repos(GITEA_USER, GITEA_REPO)
-        .edit()
+pub async fn test_admin_manage_hooks(base_url: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Basic(ADMIN_USER, ADMIN_PASSWORD));
+    let hooks = client.admin().hooks();
+    let hook = hooks
+        .create(HookType::Gitea, "http://localhost:8080/system-hook")
         .send(&client)
         .await?;
-    assert_eq!(repo.owner.login, GITEA_USER);
-    assert_eq!(repo.name, GITEA_REPO);
-    assert_eq!(repo.description, GITEA_REPO_DESCRIPTION);
+    let hook = hooks.edit(hook.id).active(true).send(&client).await?;
+    assert!(hook.active);
+    assert_eq!(hooks.get(hook.id).send(&client).await?.id, hook.id);
+    hooks.delete(hook.id).send(&client).await?;
     Ok(())
 }
 
-pub async fn test_repo_is_starred(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let starred = client
-        .user()
-        .is_starred(GITEA_USER, GITEA_REPO)
+pub async fn test_admin_user_keys(base_url: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Basic(ADMIN_USER, ADMIN_PASSWORD));
+    let users = client.admin().users();
+    let key = users
+        .add_key(GITEA_USER, "admin-key", SSH_PUBLIC_KEY)
         .send(&client)
         .await?;
-    assert!(!starred);
+    assert_eq!(key.title, "admin-key");
+    users.delete_key(GITEA_USER, key.id).send(&client).await?;
     Ok(())
 }
 
-pub async fn test_star_repo(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    client
-        .user()
-        .star_repo(GITEA_USER, GITEA_REPO)
-        .send(&client)
+pub async fn test_admin_create_org(base_url: &str, token: &str) -> Result<()> {
+    let admin = Client::new(base_url, Auth::Basic(ADMIN_USER, ADMIN_PASSWORD));
+    let org = admin
+        .admin()
+        .users()
+        .create_org(GITEA_USER, "admin-org")
+        .description("created by an admin")
+        .send(&admin)
         .await?;
-    Ok(())
-}
+    assert_eq!(org.name, "admin-org");
 
-pub async fn test_list_starred(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let stars = client.user().list_starred().send(&client).await?;
-    assert_eq!(stars.len(), 1);
-    assert_eq!(stars[0].name, GITEA_REPO);
+    client.orgs("admin-org").delete().send(&client).await?;
     Ok(())
 }
 
-pub async fn test_users_list_starred(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let stars = client
-        .users(GITEA_USER)
-        .list_starred()
-        .send(&client)
+pub async fn test_admin_create_repo(base_url: &str, token: &str) -> Result<()> {
+    let admin = Client::new(base_url, Auth::Basic(ADMIN_USER, ADMIN_PASSWORD));
+    let repo = admin
+        .admin()
+        .users()
+        .create_repo(GITEA_USER, "admin-repo")
+        .send(&admin)
         .await?;
-    assert_eq!(stars.len(), 1);
-    assert_eq!(stars[0].name, GITEA_REPO);
-    Ok(())
-}
+    assert_eq!(repo.name, "admin-repo");
 
-pub async fn test_unstar_repo(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     client
-        .user()
-        .unstar_repo(GITEA_USER, GITEA_REPO)
+        .repos(GITEA_USER, "admin-repo")
+        .delete()
         .send(&client)
         .await?;
     Ok(())
 }
 
-pub async fn test_create_issue(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_notification_count(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let issue = client
-        .issues(GITEA_USER, GITEA_REPO)
-        .create("test issue")
-        .body("test issue body")
-        .send(&client)
-        .await?;
-    assert_eq!(issue.title, "test issue");
-    assert_eq!(issue.body, Some("test issue body".to_string()));
+    let count = client.notifications().count().send(&client).await?;
+    assert!(count.new >= 0);
     Ok(())
 }
 
-pub async fn test_list_issues(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_list_packages(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let issues = client
-        .issues(GITEA_USER, GITEA_REPO)
-        .list()
-        .send(&client)
-        .await?;
-    assert_eq!(issues.len(), 1);
+    let packages = client.packages(GITEA_USER).list().send(&client).await?;
+    assert!(packages.is_empty());
     Ok(())
 }
 
-pub async fn test_get_issue(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_get_delete_package(base_url: &str, token: &str) -> Result<()> {
+    // Packages can't be uploaded through the API, so we push one to the generic registry.
+    reqwest::Client::new()
+        .put(format!(
+            "{base_url}/api/packages/{GITEA_USER}/generic/test-package/1.0.0/file.txt"
+        ))
+        .header("Authorization", format!("token {token}"))
+        .body("hello")
+        .send()
+        .await?
+        .error_for_status()?;
+
     let client = Client::new(base_url, Auth::Token(token));
-    let issue = client
-        .issues(GITEA_USER, GITEA_REPO)
-        .get(1)
+    let package = client
+        .packages(GITEA_USER)
+        .get(PackageType::Generic, "test-package", "1.0.0")
         .send(&client)
         .await?;
-    assert_eq!(issue.title, "test issue");
-    Ok(())
-}
+    assert_eq!(package.name, "test-package");
+    assert_eq!(package.version, "1.0.0");
 
-pub async fn test_edit_issue(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let issue = client
-        .issues(GITEA_USER, GITEA_REPO)
-        .edit(1)
-        .title("my new title")
-        .unset_due_date(true)
+    let files = client
+        .packages(GITEA_USER)
+        .files(PackageType::Generic, "test-package", "1.0.0")
         .send(&client)
         .await?;
-    assert_eq!(issue.title, "my new title");
-    Ok(())
-}
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].name, "file.txt");
+    assert_eq!(files[0].size, 5);
 
-pub async fn test_create_comment(base_url: &str, token: &str) -> Result<()> {
-    let client = Client::new(base_url, Auth::Token(token));
-    let comment = client
-        .issues(GITEA_USER, GITEA_REPO)
-        .comments()
-        .create(1, "test comment")
+    client
+        .packages(GITEA_USER)
+        .delete(PackageType::Generic, "test-package", "1.0.0")
         .send(&client)
         .await?;
-    assert_eq!(comment.body, "test comment");
+    let packages = client.packages(GITEA_USER).list().send(&client).await?;
+    assert!(packages.is_empty());
     Ok(())
 }
 
-pub async fn test_edit_comment(base_url: &str, token: &str) -> Result<()> {
+pub async fn test_templates(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
-    let comment = client
-        .issues(GITEA_USER, GITEA_REPO)
-        .comments()
-        .edit(1, "totally different test comment")
-        .send(&client)
-        .await?;
+    let gitignores = client.templates().gitignores().send(&client).await?;
+    assert!(gitignores.iter().any(|name| name == "Rust"));
+    let gitignore = client.templates().gitignore("Rust").send(&client).await?;
+    assert_eq!(gitignore.name, "Rust");
+    assert!(!gitignore.source.is_empty());
+
+    let licenses = client.templates().licenses().send(&client).await?;
+    assert!(licenses.iter().any(|license| license.key == "MIT"));
+    let license = client.templates().license("MIT").send(&client).await?;
+    assert_eq!(license.key, "MIT");
+    assert!(!license.body.is_empty());
+
+    let label_sets = client.templates().labels().send(&client).await?;
+    assert!(label_sets.iter().any(|name| name == "Default"));
+    let labels = client.templates().label("Default").send(&client).await?;
+    assert!(!labels.is_empty());
+    Ok(())
+}
+
+struct StaticToken(String);
+
+impl TokenProvider for StaticToken {
+    fn token(&self) -> Pin<Box<dyn Future<Output = Result<String>> + Send + '_>> {
+        Box::pin(async { Ok(self.0.clone()) })
+    }
+}
+
+pub async fn test_dynamic_auth(base_url: &str, token: &str) -> Result<()> {
+    let provider = Arc::new(StaticToken(token.to_string()));
+    let client = Client::new(base_url, Auth::Dynamic::<String>(provider));
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, GITEA_USER);
+    Ok(())
+}
+
+pub async fn test_client_builder(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::builder(base_url, Auth::Token(token))
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(30))
+        .no_proxy()
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(Some(Duration::from_secs(30)))
+        .tcp_keepalive(Some(Duration::from_secs(60)))
+        .build()?;
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, GITEA_USER);
+
+    // Requests sent through an injected client are still authenticated.
+    let shared = Client::with_http_client(reqwest::Client::new(), base_url, Auth::Token(token));
+    let user = shared.user().current().send(&shared).await?;
+    assert_eq!(user.login, GITEA_USER);
+
+    let invalid = Client::builder(base_url, Auth::Token("invalid\ntoken")).build();
+    assert!(invalid.is_err());
+    Ok(())
+}
+
+pub async fn test_request_timeout(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token)).with_timeout(Duration::from_secs(30));
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, GITEA_USER);
+
+    let impatient = client.clone().with_timeout(Duration::from_nanos(1));
+    let res = impatient.user().current().send(&impatient).await;
+    assert!(res.is_err());
+
+    // A timeout set on the request takes precedence over the client's.
+    let user = impatient
+        .user()
+        .current()
+        .timeout(Duration::from_secs(30))
+        .send(&impatient)
+        .await?;
+    assert_eq!(user.login, GITEA_USER);
+    let res = client
+        .user()
+        .current()
+        .timeout(Duration::from_nanos(1))
+        .send(&client)
+        .await;
+    assert!(res.is_err());
+    Ok(())
+}
+
+pub async fn test_retry(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token))
+        .with_retry(RetryPolicy::new().initial_backoff(Duration::from_millis(10)));
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, GITEA_USER);
+
+    // Client errors are never retried.
+    let err = client
+        .repos(GITEA_USER, "does-not-exist")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+pub async fn test_throttle(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token))
+        .with_throttle(Throttle::new().requests_per_second(10).max_in_flight(1));
+    let builder = client.user().current();
+    let requests = (0..3).map(|_| builder.send(&client));
+    let users = futures_util::future::try_join_all(requests).await?;
+    assert!(users.iter().all(|user| user.login == GITEA_USER));
+    Ok(())
+}
+
+pub async fn test_cache(base_url: &str, token: &str) -> Result<()> {
+    let cache = Arc::new(ResponseCache::new().max_entries(10));
+    let client = Client::new(base_url, Auth::Token(token)).with_cache(cache.clone());
+    let first = client.user().current().send(&client).await?;
+    let second = client.user().current().send(&client).await?;
+    assert_eq!(first.login, second.login);
+
+    cache.clear();
+    let third = client.user().current().send(&client).await?;
+    assert_eq!(first.login, third.login);
+    Ok(())
+}
+
+pub async fn test_sudo(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let as_admin = client.sudo(ADMIN_USER);
+    let user = as_admin.user().current().send(&as_admin).await?;
+    assert_eq!(user.login, ADMIN_USER);
+
+    // The original client is not affected.
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, GITEA_USER);
+    Ok(())
+}
+
+pub async fn test_batch(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let usernames = [GITEA_USER, "does-not-exist", ADMIN_USER];
+    let requests = usernames.iter().map(|username| {
+        let builder = client.users(username).get();
+        let client = &client;
+        async move { builder.send(client).await }
+    });
+    let results = client.batch().concurrency(2).run(requests).await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().login, GITEA_USER);
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().login, ADMIN_USER);
+    Ok(())
+}
+
+pub async fn test_send_with_response(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let res = client
+        .users(GITEA_USER)
+        .get()
+        .send_with_response(&client)
+        .await?;
+    assert_eq!(res.status, reqwest::StatusCode::OK);
+    assert_eq!(res.value.login, GITEA_USER);
+    assert!(res.header("content-type").is_some());
+
+    // Both wrappers read the same total count from the headers.
+    let req = client.get(format!("users/{GITEA_USER}/repos")).build()?;
+    let res = client.make_request(req).await?;
+    let res = client
+        .parse_api_response::<Vec<serde_json::Value>>(res)
+        .await?;
+    let page = client
+        .users(GITEA_USER)
+        .list_repos()
+        .send_paged(&client)
+        .await?;
+    assert_eq!(res.total_count(), Some(res.value.len() as u64));
+    assert_eq!(res.total_count(), page.total_count);
+    Ok(())
+}
+
+pub async fn test_observers(base_url: &str, token: &str) -> Result<()> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::new(base_url, Auth::Token(token))
+        .on_request({
+            let requests = requests.clone();
+            move |event| requests.lock().unwrap().push(event.url.path().to_string())
+        })
+        .on_response({
+            let responses = responses.clone();
+            move |event| responses.lock().unwrap().push(event.status)
+        });
+    client.user().current().send(&client).await?;
+    let _ = client.users("does-not-exist").get().send(&client).await;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(*requests, ["/api/v1/user", "/api/v1/users/does-not-exist"]);
+    let responses = responses.lock().unwrap();
+    assert_eq!(
+        *responses,
+        [
+            Some(reqwest::StatusCode::OK),
+            Some(reqwest::StatusCode::NOT_FOUND)
+        ]
+    );
+    Ok(())
+}
+
+pub async fn test_redaction(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    assert!(!format!("{client:?}").contains(token));
+    let auth = Auth::Basic(GITEA_USER, GITEA_PASSWORD);
+    assert!(!format!("{auth:?}").contains(GITEA_PASSWORD));
+
+    let builder = client
+        .admin()
+        .users()
+        .create("redacted@example.com", "redacted", "super-secret");
+    assert!(!format!("{builder:?}").contains("super-secret"));
+    Ok(())
+}
+
+pub async fn test_login(base_url: &str) -> Result<()> {
+    let scopes = TokenScope::ReadUser;
+    let first = Client::login(base_url, GITEA_USER, GITEA_PASSWORD, "login-token", scopes).await?;
+    let user = first.user().current().send(&first).await?;
+    assert_eq!(user.login, GITEA_USER);
+
+    // Logging in again replaces the token instead of failing on the duplicate name.
+    let second = Client::login(base_url, GITEA_USER, GITEA_PASSWORD, "login-token", scopes).await?;
+    second.user().current().send(&second).await?;
+
+    let basic = Client::new(base_url, Auth::Basic(GITEA_USER, GITEA_PASSWORD));
+    let tokens = basic
+        .user()
+        .list_access_tokens(GITEA_USER)
+        .send(&basic)
+        .await?;
+    assert_eq!(tokens.iter().filter(|t| t.name == "login-token").count(), 1);
+    basic
+        .user()
+        .delete_access_token(GITEA_USER, "login-token")
+        .send(&basic)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_default_headers(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::builder(base_url, Auth::Token(token))
+        .user_agent_suffix("gritty-tests/1.0")
+        .default_header("X-Forwarded-For", "203.0.113.7")
+        .default_header("Proxy-Authorization", "Basic proxy-secret")
+        .build()?;
+    let debug = format!("{client:?}");
+    assert!(debug.contains(&format!(
+        "gitea-sdk/{} gritty-tests/1.0",
+        env!("CARGO_PKG_VERSION")
+    )));
+    assert!(debug.contains("203.0.113.7"));
+    assert!(!debug.contains("proxy-secret"));
+    client.user().current().send(&client).await?;
+
+    let client = Client::builder(base_url, Auth::Token(token))
+        .user_agent("gritty-tests")
+        .build()?;
+    assert!(!format!("{client:?}").contains("gitea-sdk/"));
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.append("X-Gritty-Tag", "first".parse().unwrap());
+    headers.append("X-Gritty-Tag", "second".parse().unwrap());
+    headers.insert("Cookie", "gritty=cookie-secret".parse().unwrap());
+    let client = Client::builder(base_url, Auth::Token(token))
+        .default_headers(headers)
+        .build()?;
+    let debug = format!("{client:?}");
+    assert!(debug.contains("first") && debug.contains("second"));
+    assert!(!debug.contains("cookie-secret"));
+    client.user().current().send(&client).await?;
+
+    let invalid = Client::builder(base_url, Auth::Token(token))
+        .default_header("Not A Header", "value")
+        .build();
+    assert!(invalid.is_err());
+    Ok(())
+}
+
+pub async fn test_request_id(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token)).with_request_id();
+    let err = client
+        .users("does-not-exist")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    let first = err.request_id.clone().expect("request ID is set");
+    assert!(err.to_string().contains(&first));
+
+    let err = client
+        .users("does-not-exist")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_ne!(err.request_id, Some(first));
+
+    let client = Client::new(base_url, Auth::Token(token));
+    let err = client
+        .users("does-not-exist")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.request_id, None);
+    Ok(())
+}
+
+#[cfg(feature = "test-util")]
+pub async fn test_cassette(base_url: &str, token: &str) -> Result<()> {
+    use gitea_sdk::test_util::Cassette;
+
+    let path = std::env::temp_dir().join("gitea-sdk-cassette.json");
+    let cassette = Cassette::record(&path);
+    let client = Client::new(base_url, Auth::Token(token)).with_cassette(cassette.clone());
+    let recorded = client.user().current().send(&client).await?;
+    let _ = client.users("does-not-exist").get().send(&client).await;
+    cassette.save()?;
+
+    // Replaying doesn't need a server.
+    let cassette = Cassette::replay(&path)?;
+    assert!(!cassette.is_recording());
+    let client = Client::new("http://gitea.invalid", Auth::None::<String>).with_cassette(cassette);
+    let replayed = client.user().current().send(&client).await?;
+    assert_eq!(replayed.login, recorded.login);
+    let err = client
+        .users("does-not-exist")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::NOT_FOUND);
+    // Every interaction is only replayed once.
+    assert!(client.user().current().send(&client).await.is_err());
+
+    // Tokens are redacted from response bodies and query strings.
+    let cassette = Cassette::record(&path);
+    let client = Client::new(base_url, Auth::Basic(GITEA_USER, GITEA_PASSWORD))
+        .with_cassette(cassette.clone());
+    let created = client
+        .user()
+        .create_access_token(GITEA_USER, "cassette-token", TokenScope::ReadUser)
+        .send(&client)
+        .await?;
+    let secret = created.sha1.expose_secret().to_string();
+    let req = client
+        .get("user")
+        .query(&[("access_token", &secret)])
+        .build()?;
+    client.make_request(req).await?;
+    client
+        .user()
+        .delete_access_token(GITEA_USER, "cassette-token")
+        .send(&client)
+        .await?;
+    cassette.save()?;
+    let recorded = std::fs::read_to_string(&path)?;
+    assert!(!recorded.contains(&secret));
+    assert!(recorded.contains(&created.token_last_eight));
+    assert!(recorded.contains("access_token=REDACTED"));
+
+    let client = Client::new("http://gitea.invalid", Auth::None::<String>)
+        .with_cassette(Cassette::replay(&path)?);
+    let replayed = client
+        .user()
+        .create_access_token(GITEA_USER, "cassette-token", TokenScope::ReadUser)
+        .send(&client)
+        .await?;
+    assert_eq!(replayed.sha1.expose_secret(), "REDACTED");
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+#[cfg(all(feature = "test-util", feature = "testing"))]
+#[tokio::test]
+async fn test_cassette_redacts_query() -> Result<()> {
+    use gitea_sdk::{test_util::Cassette, testing::MockGitea};
+
+    let gitea = MockGitea::start().await;
+    let path = std::env::temp_dir().join("gitea-sdk-cassette-query.json");
+    let cassette = Cassette::record(&path);
+    let client = gitea.client().with_cassette(cassette.clone());
+    let req = client
+        .get("user")
+        .query(&[
+            ("Token", "t0ps3cret"),
+            ("password", "hunter2"),
+            ("page", "1"),
+        ])
+        .build()?;
+    client.make_request(req).await?;
+    cassette.save()?;
+    let recorded = std::fs::read_to_string(&path)?;
+    std::fs::remove_file(path)?;
+    assert!(!recorded.contains("t0ps3cret"));
+    assert!(!recorded.contains("hunter2"));
+    assert!(recorded.contains("Token=REDACTED&password=REDACTED&page=1"));
+    Ok(())
+}
+
+/// A cache shared between clients doesn't leak responses across credentials.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_shared_cache() -> Result<()> {
+    use gitea_sdk::testing::MockGitea;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{header, header_exists, method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user"))
+        .and(header_exists("If-None-Match"))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .mount(gitea.server())
+        .await;
+    for login in ["alice", "bob"] {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/user"))
+            .and(header("Authorization", format!("token {login}-token")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(json!({ "login": login })),
+            )
+            .mount(gitea.server())
+            .await;
+    }
+
+    let cache = Arc::new(ResponseCache::new());
+    let alice = Client::new(gitea.uri(), Auth::Token("alice-token")).with_cache(cache.clone());
+    let bob = Client::new(gitea.uri(), Auth::Token("bob-token")).with_cache(cache);
+    assert_eq!(alice.user().current().send(&alice).await?.login, "alice");
+    assert_eq!(bob.user().current().send(&bob).await?.login, "bob");
+    // Revalidated with a 304, each client still gets its own response.
+    assert_eq!(alice.user().current().send(&alice).await?.login, "alice");
+    assert_eq!(bob.user().current().send(&bob).await?.login, "bob");
+    Ok(())
+}
+
+/// Streams stop on the last page instead of requesting an empty one after it.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_stream_stops_on_last_page() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, ResponseTemplate,
+    };
+
+    let issues = format!("/api/v1/repos/{}/{}/issues", testing::OWNER, testing::REPO);
+    let page = |number: &str, headers: &[(&str, String)], count: usize| {
+        let mut res = ResponseTemplate::new(200).set_body_json(vec![testing::issue(); count]);
+        for (name, value) in headers {
+            res = res.insert_header(*name, value.as_str());
+        }
+        Mock::given(method("GET"))
+            .and(path(&issues))
+            .and(query_param("page", number))
+            .respond_with(res)
+    };
+    let link = |number: i64, rel: &str| {
+        let url = format!("http://localhost{issues}?limit=2&page={number}");
+        ("Link", format!("<{url}>; rel=\"{rel}\""))
+    };
+    let send_all = |gitea: MockGitea| async move {
+        let client = gitea.client();
+        client
+            .issues(testing::OWNER, testing::REPO)
+            .list()
+            .limit(2)
+            .send_all(&client)
+            .await
+    };
+
+    // The last page has no `next` link.
+    let gitea = MockGitea::start().await;
+    page("1", &[link(2, "next")], 2).mount(gitea.server()).await;
+    page("2", &[link(1, "prev")], 2).mount(gitea.server()).await;
+    page("3", &[], 0).expect(0).mount(gitea.server()).await;
+    assert_eq!(send_all(gitea).await?.len(), 4);
+
+    // The total count is reached.
+    let gitea = MockGitea::start().await;
+    let total = [("X-Total-Count", "3".to_string())];
+    page("1", &total, 2).mount(gitea.server()).await;
+    page("2", &total, 1).mount(gitea.server()).await;
+    page("3", &total, 0).expect(0).mount(gitea.server()).await;
+    assert_eq!(send_all(gitea).await?.len(), 3);
+
+    // Without pagination headers, a page with fewer items than the limit is the last one.
+    let gitea = MockGitea::start().await;
+    page("1", &[], 2).mount(gitea.server()).await;
+    page("2", &[], 1).mount(gitea.server()).await;
+    page("3", &[], 0).expect(0).mount(gitea.server()).await;
+    assert_eq!(send_all(gitea).await?.len(), 3);
+    Ok(())
+}
+
+/// The signing key is sent as plain text, not JSON.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_repo_signing_key() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let key =
+        "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nmDMEZ...\n-----END PGP PUBLIC KEY BLOCK-----\n";
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/repos/{}/{}/signing-key.gpg",
+            testing::OWNER,
+            testing::REPO
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(key, "text/plain"))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+
+    let client = gitea.client();
+    let signing_key = client
+        .repos(testing::OWNER, testing::REPO)
+        .signing_key()
+        .send(&client)
+        .await?;
+    assert_eq!(signing_key, key);
+    let err = client
+        .repos(testing::OWNER, "unsigned")
+        .signing_key()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+/// The instance's signing key is sent as plain text, without the `/repos` prefix.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_instance_signing_key() -> Result<()> {
+    use gitea_sdk::testing::MockGitea;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let key = "-----BEGIN PGP PUBLIC KEY BLOCK-----\r\n\r\nmDMEZ...\r\n\
+               -----END PGP PUBLIC KEY BLOCK-----";
+    Mock::given(method("GET"))
+        .and(path("/api/v1/signing-key.gpg"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(key, "text/plain; charset=utf-8"))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+
+    let client = gitea.client();
+    let signing_key = client.signing_key().send(&client).await?;
+    // The key is returned exactly as sent, line endings included.
+    assert_eq!(signing_key, key);
+    Ok(())
+}
+
+/// A POST that timed out might have been processed, so it is only retried if allowed.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_retry_only_idempotent() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let policy = RetryPolicy::new()
+        .max_attempts(3)
+        .initial_backoff(Duration::from_millis(10));
+    let slow = ResponseTemplate::new(201).set_delay(Duration::from_secs(2));
+    let issues = format!("/api/v1/repos/{}/{}/issues", testing::OWNER, testing::REPO);
+    let create_issue = |client: Client| async move {
+        client
+            .issues(testing::OWNER, testing::REPO)
+            .create("created twice?")
+            .send(&client)
+            .await
+    };
+
+    let gitea = MockGitea::start().await;
+    Mock::given(method("POST"))
+        .and(path(&issues))
+        .respond_with(slow.clone())
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    let client = gitea
+        .client()
+        .with_timeout(Duration::from_millis(100))
+        .with_retry(policy.clone());
+    assert!(create_issue(client).await.is_err());
+
+    let gitea = MockGitea::start().await;
+    Mock::given(method("POST"))
+        .and(path(&issues))
+        .respond_with(slow)
+        .expect(3)
+        .mount(gitea.server())
+        .await;
+    let client = gitea
+        .client()
+        .with_timeout(Duration::from_millis(100))
+        .with_retry(policy.retry_non_idempotent(true));
+    assert!(create_issue(client).await.is_err());
+
+    // Idempotent requests are retried by default.
+    let gitea = MockGitea::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(gitea.server())
+        .await;
+    let client = gitea.client().with_retry(
+        RetryPolicy::new()
+            .max_attempts(3)
+            .initial_backoff(Duration::from_millis(10)),
+    );
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, testing::OWNER);
+    Ok(())
+}
+
+/// Neither the spans nor the events of a request may contain credentials.
+#[cfg(all(feature = "testing", feature = "tracing"))]
+#[tokio::test]
+async fn test_tracing_redacts_credentials() -> Result<()> {
+    use std::{
+        fmt::{Debug, Write},
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use gitea_sdk::testing::{self, MockGitea};
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    /// Writes the names and fields of all spans and events into a string.
+    #[derive(Default)]
+    struct Capture {
+        output: Arc<Mutex<String>>,
+        next_id: AtomicU64,
+    }
+    impl Visit for &Capture {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let mut output = self.output.lock().unwrap();
+            write!(output, " {}={:?}", field.name(), value).unwrap();
+        }
+    }
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+            self.output
+                .lock()
+                .unwrap()
+                .push_str(attrs.metadata().name());
+            attrs.record(&mut &*self);
+            span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+        fn record(&self, _: &span::Id, values: &span::Record<'_>) {
+            values.record(&mut &*self);
+        }
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut &*self);
+        }
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    let gitea = MockGitea::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/admin/users"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(testing::user()))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+        .with_priority(1)
+        .mount(gitea.server())
+        .await;
+    let client = gitea.client().with_timeout(Duration::from_millis(100));
+
+    let capture = Capture::default();
+    let output = capture.output.clone();
+    let _guard = tracing::subscriber::set_default(capture);
+    let req = client
+        .post("admin/users")
+        .json(&serde_json::json!({ "login_name": "alice", "password": "hunter2" }))
+        .build()?;
+    client.make_request(req).await?;
+    let req = client
+        .get("user")
+        .query(&[("token", "query-secret"), ("page", "2")])
+        .build()?;
+    assert!(client.make_request(req).await.is_err());
+
+    let output = output.lock().unwrap();
+    assert!(output.contains("gitea_request"), "{output}");
+    assert!(output.contains("path=/api/v1/user"), "{output}");
+    assert!(output.contains("alice"), "{output}");
+    assert!(output.contains("request failed"), "{output}");
+    for secret in [testing::TOKEN, "hunter2", "query-secret"] {
+        assert!(!output.contains(secret), "{secret} leaked: {output}");
+    }
+    Ok(())
+}
+
+/// Requests are sent through the middleware, with the authentication of the client.
+#[cfg(all(feature = "testing", feature = "middleware"))]
+#[tokio::test]
+async fn test_middleware() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user"))
+        .and(header("x-middleware", "yes"))
+        .and(header("authorization", format!("token {}", testing::TOKEN)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(testing::user()))
+        .with_priority(1)
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    let mut headers = HeaderMap::new();
+    headers.insert("x-middleware", HeaderValue::from_static("yes"));
+    let http = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()?;
+    let client = gitea
+        .client()
+        .with_middleware(reqwest_middleware::ClientBuilder::new(http).build());
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, testing::OWNER);
+    Ok(())
+}
+
+/// Answers every request with the same user, recording the requested paths.
+struct MockGitea {
+    http: reqwest::Client,
+    paths: Mutex<Vec<String>>,
+}
+
+impl GiteaHttp for MockGitea {
+    fn request_base(
+        &self,
+        method: Method,
+        path: impl std::fmt::Display,
+    ) -> reqwest::RequestBuilder {
+        self.http
+            .request(method, format!("http://mock/api/v1/{path}"))
+    }
+
+    fn make_request(
+        &self,
+        req: reqwest::Request,
+    ) -> gitea_sdk::pagination::BoxFuture<'_, Result<reqwest::Response>> {
+        self.paths
+            .lock()
+            .unwrap()
+            .push(req.url().path().to_string());
+        let body = format!(r#"{{"id": 1, "login": "{GITEA_USER}"}}"#);
+        Box::pin(async move { Ok(http::Response::new(body).into()) })
+    }
+}
+
+pub async fn test_mock_transport() -> Result<()> {
+    let mock = MockGitea {
+        http: reqwest::Client::new(),
+        paths: Mutex::new(Vec::new()),
+    };
+    let client = Client::new("http://unused", Auth::None::<String>);
+    let user = client.user().current().send(&mock).await?;
+    assert_eq!(user.login, GITEA_USER);
+    let user = client.users(GITEA_USER).get().send(&&mock).await?;
+    assert_eq!(user.id, 1);
+    assert_eq!(
+        *mock.paths.lock().unwrap(),
+        ["/api/v1/user", &format!("/api/v1/users/{GITEA_USER}")]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_mock_gitea() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let client = gitea.client();
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, testing::OWNER);
+    let repo = client
+        .repos(testing::OWNER, testing::REPO)
+        .get()
+        .send(&client)
+        .await?;
+    assert_eq!(repo.owner.login, testing::OWNER);
+    let issues = client
+        .issues(testing::OWNER, testing::REPO)
+        .list()
+        .send(&client)
+        .await?;
+    assert_eq!(issues[0].number, testing::ISSUE);
+
+    // Mocks mounted by the test override the fixtures.
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(gitea.server())
+        .await;
+    let err = client.user().current().send(&client).await.unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+/// Runs without an async runtime of its own, like applications using the blocking client do.
+#[cfg(all(feature = "blocking", feature = "testing"))]
+#[test]
+fn test_blocking_client() {
+    use gitea_sdk::{
+        blocking::{self, Wait},
+        testing::{self, MockGitea},
+    };
+
+    // The mock server runs on its own thread, the runtime is only needed to start it.
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let gitea = runtime.block_on(MockGitea::start());
+    let client = blocking::Client::from(gitea.client());
+
+    let user = client.user().current().send_blocking(&client).unwrap();
+    assert_eq!(user.login, testing::OWNER);
+    let repo = client
+        .repos(testing::OWNER, testing::REPO)
+        .get()
+        .send_blocking(&client)
+        .unwrap();
+    assert_eq!(repo.name, testing::REPO);
+    let issues = client
+        .issues(testing::OWNER, testing::REPO)
+        .list()
+        .send_paged(&client)
+        .wait(&client)
+        .unwrap();
+    assert_eq!(issues.items[0].number, testing::ISSUE);
+    let err = client
+        .users("does-not-exist")
+        .get()
+        .send_blocking(&client)
+        .unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::NOT_FOUND);
+}
+
+/// The Actions list endpoints send their total count in the body instead of a header.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_actions_total_count() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let actions = format!("/api/v1/repos/{}/{}/actions", testing::OWNER, testing::REPO);
+    let list = |field: &str, total_count: u64, ids: &[i64]| {
+        let items: Vec<_> = ids.iter().map(|id| json!({ "id": id })).collect();
+        ResponseTemplate::new(200)
+            .set_body_json(json!({ "total_count": total_count, field: items }))
+    };
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/runs")))
+        .respond_with(list("workflow_runs", 3, &[1, 2]))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/runs/1/jobs")))
+        .respond_with(list("jobs", 1, &[10]))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/artifacts")))
+        .and(query_param("page", "1"))
+        .respond_with(list("artifacts", 3, &[20, 21]))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/artifacts")))
+        .and(query_param("page", "2"))
+        .respond_with(list("artifacts", 3, &[22]))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/artifacts")))
+        .and(query_param("page", "3"))
+        .respond_with(list("artifacts", 3, &[]))
+        .expect(0)
+        .mount(gitea.server())
+        .await;
+
+    let client = gitea.client();
+    let actions = client.repos(testing::OWNER, testing::REPO).actions();
+    let runs = actions.runs().limit(2).send_paged(&client).await?;
+    assert_eq!(runs.total_count, Some(3));
+    assert_eq!(runs.items.len(), 2);
+    let jobs = actions.run_jobs(1).send_paged(&client).await?;
+    assert_eq!(jobs.total_count, Some(1));
+    assert_eq!(jobs.items[0].id, 10);
+    let artifacts = actions.artifacts().page(1).send_paged(&client).await?;
+    assert_eq!(artifacts.total_count, Some(3));
+    let artifacts = actions.artifacts().send_all(&client).await?;
+    let ids: Vec<_> = artifacts.iter().map(|artifact| artifact.id).collect();
+    assert_eq!(ids, [20, 21, 22]);
+    Ok(())
+}
+
+/// Quotas are only supported by Forgejo, so they are tested against the mock server.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_quota() -> Result<()> {
+    use gitea_sdk::{model::quota::QuotaRule, testing::MockGitea};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let rule = json!({ "name": "repos", "limit": 1024, "subjects": ["size:repos:all"] });
+    let edited = json!({ "name": "repos", "limit": -1, "subjects": ["size:repos:all"] });
+    let group = json!({ "name": "small", "rules": [rule] });
+    let mock =
+        |verb: &str, url: &str| Mock::given(method(verb)).and(path(format!("/api/v1/{url}")));
+    mock("POST", "admin/quota/rules")
+        .and(body_json(&rule))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&rule))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("GET", "admin/quota/rules/repos")
+        .respond_with(ResponseTemplate::new(200).set_body_json(&rule))
+        .mount(gitea.server())
+        .await;
+    mock("PATCH", "admin/quota/rules/repos")
+        .and(body_json(json!({ "limit": -1 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&edited))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("GET", "admin/quota/rules")
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([edited])))
+        .mount(gitea.server())
+        .await;
+    mock("DELETE", "admin/quota/rules/repos")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("POST", "admin/quota/groups")
+        .and(body_json(&group))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&group))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("GET", "admin/quota/groups/small")
+        .respond_with(ResponseTemplate::new(200).set_body_json(&group))
+        .mount(gitea.server())
+        .await;
+    mock("PUT", "admin/quota/groups/small/rules/repos")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("DELETE", "admin/quota/groups/small/rules/repos")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("PUT", "admin/quota/groups/small/users/gritty")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    mock("GET", "admin/quota/groups/small/users")
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "login": "gritty" }])))
+        .mount(gitea.server())
+        .await;
+    mock("DELETE", "admin/quota/groups/small/users/gritty")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    let info = json!({
+        "used": { "size": { "repos": { "public": 512, "private": 0 } } },
+        "groups": [group],
+    });
+    mock("GET", "admin/users/gritty/quota")
+        .respond_with(ResponseTemplate::new(200).set_body_json(&info))
+        .mount(gitea.server())
+        .await;
+    mock("GET", "user/quota")
+        .respond_with(ResponseTemplate::new(200).set_body_json(&info))
+        .mount(gitea.server())
+        .await;
+
+    let client = gitea.client();
+    let quota = client.admin().quota();
+    let created = quota
+        .create_rule("repos", 1024)
+        .subjects(vec!["size:repos:all".to_string()])
+        .send(&client)
+        .await?;
+    assert_eq!(created.limit, 1024);
+    let fetched = quota.get_rule("repos").send(&client).await?;
+    assert_eq!(fetched.subjects, ["size:repos:all"]);
+    let edited = quota.edit_rule("repos").limit(-1).send(&client).await?;
+    assert_eq!(edited.limit, -1);
+    let rules = quota.list_rules().send(&client).await?;
+    assert_eq!(rules[0].limit, -1);
+
+    let rule = QuotaRule {
+        name: "repos".to_string(),
+        limit: 1024,
+        subjects: vec!["size:repos:all".to_string()],
+    };
+    let created = quota
+        .create_group("small")
+        .rules(vec![rule])
+        .send(&client)
+        .await?;
+    assert_eq!(created.rules[0].name, "repos");
+    let fetched = quota.get_group("small").send(&client).await?;
+    assert_eq!(fetched.rules[0].limit, 1024);
+    quota.add_rule("small", "repos").send(&client).await?;
+    quota.remove_rule("small", "repos").send(&client).await?;
+    quota.delete_rule("repos").send(&client).await?;
+
+    quota.add_user("small", "gritty").send(&client).await?;
+    let users = quota.list_users("small").send(&client).await?;
+    assert_eq!(users[0].login, "gritty");
+    let info = quota.get_user_quota("gritty").send(&client).await?;
+    assert_eq!(info.groups[0].name, "small");
+    assert_eq!(info.used.size.repos.public, 512);
+    let own = client.user().quota().send(&client).await?;
+    assert_eq!(own.groups[0].rules[0].name, "repos");
+    quota.remove_user("small", "gritty").send(&client).await?;
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_create_pr_from_commits() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{body_partial_json, method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let pulls = format!("/api/v1/repos/{}/{}/pulls", testing::OWNER, testing::REPO);
+    let commit = |message: &str| json!({ "commit": { "message": message } });
+    // Gitea lists the newest commit first.
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/repos/{}/{}/compare/main...feature",
+            testing::OWNER,
+            testing::REPO
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_commits": 3,
+            "commits": [
+                commit("Document the gizmo"),
+                commit("Test the gizmo\n\nWith a body that is not used."),
+                commit("Add the gizmo"),
+            ],
+        })))
+        .mount(gitea.server())
+        .await;
+    let title = "Add the gizmo";
+    let body = "- Test the gizmo\n- Document the gizmo";
+    let branch = |name: &str| {
+        json!({
+            "label": name,
+            "ref": name,
+            "repo": testing::repo(),
+            "repo_id": 1,
+            "sha": "",
+        })
+    };
+    let pr = json!({
+        "additions": 0,
+        "allow_maintainer_edit": false,
+        "assignees": null,
+        "base": branch("main"),
+        "body": body,
+        "changed_files": 0,
+        "closed_at": null,
+        "comments": 0,
+        "created_at": "2024-12-31T00:00:00Z",
+        "deletions": 0,
+        "diff_url": "",
+        "draft": false,
+        "due_date": null,
+        "head": branch("feature"),
+        "html_url": "",
+        "id": 1,
+        "is_locked": false,
+        "labels": [],
+        "merge_base": "",
+        "merge_commit_sha": null,
+        "mergeable": true,
+        "merged": false,
+        "merged_at": null,
+        "merged_by": null,
+        "milestone": { "id": 3, "title": "v1.0", "state": "open" },
+        "number": 2,
+        "patch_url": "",
+        "pin_order": 0,
+        "requested_reviewers": null,
+        "review_comments": 0,
+        "state": "open",
+        "title": title,
+        "updated_at": "2024-12-31T00:00:00Z",
+        "url": "",
+        "user": testing::user(),
+    });
+    Mock::given(method("POST"))
+        .and(path(pulls))
+        .and(body_partial_json(json!({
+            "base": "main",
+            "head": "feature",
+            "title": title,
+            "body": body,
+            "labels": [1],
+            "milestone": 3,
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(pr))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+
+    let client = gitea.client();
+    let pr = client
+        .pulls(testing::OWNER, testing::REPO)
+        .create_from_commits("feature", "main")
+        .labels(vec![1])
+        .milestone(3)
+        .send(&client)
+        .await?;
+    assert_eq!(pr.title, title);
+    assert_eq!(pr.body, body);
+    assert_eq!(
+        pr.milestone.map(|milestone| milestone.title).as_deref(),
+        Some("v1.0")
+    );
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/repos/{}/{}/compare/main...main",
+            testing::OWNER,
+            testing::REPO
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "total_commits": 0, "commits": [] })),
+        )
+        .mount(gitea.server())
+        .await;
+    let err = client
+        .pulls(testing::OWNER, testing::REPO)
+        .create_from_commits("main", "main")
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+    Ok(())
+}
+
+/// Notifications are only created for other users' activity, so they are tested against the
+/// mock server.
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_notification_thread() -> Result<()> {
+    use gitea_sdk::{
+        model::notifications::NotifySubjectType,
+        testing::{self, MockGitea},
+    };
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let thread = |unread: bool, pinned: bool| {
+        json!({
+            "id": 7,
+            "unread": unread,
+            "pinned": pinned,
+            "repository": testing::repo(),
+            "subject": { "title": "Something is broken", "type": "Issue", "state": "open" },
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/api/v1/notifications/threads/7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(thread(true, false)))
+        .mount(gitea.server())
+        .await;
+    for (status, unread, pinned) in [
+        ("read", false, false),
+        ("unread", true, false),
+        ("pinned", false, true),
+    ] {
+        // Gitea answers status changes with 205 Reset Content, but still sends the thread.
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/notifications/threads/7"))
+            .and(query_param("to-status", status))
+            .respond_with(ResponseTemplate::new(205).set_body_json(thread(unread, pinned)))
+            .expect(1)
+            .mount(gitea.server())
+            .await;
+    }
+
+    let client = gitea.client();
+    let thread = client.notifications().thread(7);
+    let fetched = thread.get().send(&client).await?;
+    assert!(fetched.unread);
+    assert_eq!(fetched.repository.name, testing::REPO);
+    assert!(matches!(fetched.subject.r#type, NotifySubjectType::Issue));
+    let read = thread.mark_read().send(&client).await?;
+    assert!(!read.unread);
+    let unread = thread.mark_unread().send(&client).await?;
+    assert!(unread.unread);
+    let pinned = thread.mark_pinned().send(&client).await?;
+    assert!(pinned.pinned);
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_actions_runs_and_jobs() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let repo = format!("repos/{}/{}", testing::OWNER, testing::REPO);
+    let run = json!({
+        "id": 1,
+        "event": "push",
+        "head_branch": "main",
+        "status": "completed",
+        "conclusion": "success",
+        "actor": testing::user(),
+        "repository": testing::repo(),
+    });
+    let job = json!({
+        "id": 10,
+        "run_id": 1,
+        "name": "build",
+        "status": "in_progress",
+        "steps": [{ "name": "checkout", "number": 1, "status": "completed" }],
+    });
+    for scope in [repo.as_str(), "orgs/gritty-org", "admin"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/api/v1/{scope}/actions/runs")))
+            .and(query_param("branch", "main"))
+            .and(query_param("status", "completed"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "total_count": 1, "workflow_runs": [run] })),
+            )
+            .expect(1)
+            .mount(gitea.server())
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/api/v1/{scope}/actions/jobs")))
+            .and(query_param("status", "in_progress"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "total_count": 1, "jobs": [job] })),
+            )
+            .expect(1)
+            .mount(gitea.server())
+            .await;
+    }
+
+    let client = gitea.client();
+    let repo = client.repos(testing::OWNER, testing::REPO).actions();
+    let org = client.orgs("gritty-org").actions();
+    let admin = client.admin().actions();
+    for runs in [repo.runs(), org.runs(), admin.runs()] {
+        let runs = runs
+            .branch("main")
+            .status("completed")
+            .send(&client)
+            .await?;
+        assert_eq!(runs[0].conclusion, "success");
+        assert_eq!(runs[0].actor.login, testing::OWNER);
+        assert_eq!(runs[0].repository.name, testing::REPO);
+    }
+    for jobs in [repo.jobs(), org.jobs(), admin.jobs()] {
+        let jobs = jobs.status("in_progress").send(&client).await?;
+        assert_eq!(jobs[0].run_id, 1);
+        assert_eq!(jobs[0].steps[0].name, "checkout");
+    }
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_actions_artifacts_and_logs() -> Result<()> {
+    use gitea_sdk::testing::{self, MockGitea};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let gitea = MockGitea::start().await;
+    let actions = format!("/api/v1/repos/{}/{}/actions", testing::OWNER, testing::REPO);
+    let archive = b"PK\x03\x04 not really a zip".as_slice();
+    let logs = "build\ttest\nall green\n";
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/artifacts/20")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 20,
+            "name": "build-output",
+            "size_in_bytes": archive.len(),
+            "workflow_run": { "id": 1, "head_sha": "abc" },
+        })))
+        .mount(gitea.server())
+        .await;
+    // Gitea redirects to the storage the archive lives in.
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/artifacts/20/zip")))
+        .respond_with(ResponseTemplate::new(302).insert_header(
+            "Location",
+            format!("{}/storage/build-output.zip", gitea.uri()),
+        ))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/storage/build-output.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(archive))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/artifacts/21/zip")))
+        .respond_with(ResponseTemplate::new(410))
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("{actions}/artifacts/20")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{actions}/jobs/10/logs")))
+        .respond_with(ResponseTemplate::new(200).set_body_string(logs))
+        .mount(gitea.server())
+        .await;
+
+    let client = gitea.client();
+    let actions = client.repos(testing::OWNER, testing::REPO).actions();
+    let artifact = actions.get_artifact(20).send(&client).await?;
+    assert_eq!(artifact.name, "build-output");
+    assert_eq!(artifact.workflow_run.id, 1);
+
+    let mut zip = Vec::new();
+    let written = actions
+        .download_artifact(20)
+        .send(&client, &mut zip)
+        .await?;
+    assert_eq!(written, archive.len() as u64);
+    assert_eq!(zip, archive);
+    let mut expired = Vec::new();
+    let err = actions
+        .download_artifact(21)
+        .send(&client, &mut expired)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::GONE);
+    assert!(expired.is_empty());
+
+    let mut log = Vec::new();
+    actions.job_logs(10).send(&client, &mut log).await?;
+    assert_eq!(String::from_utf8(log).unwrap(), logs);
+    actions.delete_artifact(20).send(&client).await?;
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn test_retry_rate_limited() -> Result<()> {
+    use gitea_sdk::{
+        error::TeatimeErrorKind,
+        testing::{self, MockGitea},
+    };
+    use wiremock::{
+        matchers::{method, path},
+        Mock, ResponseTemplate,
+    };
+
+    let policy = RetryPolicy::new()
+        .initial_backoff(Duration::from_millis(10))
+        .max_backoff(Duration::from_secs(5));
+    let rate_limited = |retry_after: &str| {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/user"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", retry_after))
+    };
+
+    // The client waits for as long as the server asks, not just the (much shorter) backoff.
+    let gitea = MockGitea::start().await;
+    rate_limited("1")
+        .up_to_n_times(1)
+        .mount(gitea.server())
+        .await;
+    let client = gitea.client().with_retry(policy.clone());
+    let start = std::time::Instant::now();
+    let user = client.user().current().send(&client).await?;
+    assert_eq!(user.login, testing::OWNER);
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    // A longer wait than the maximum backoff is not waited for.
+    let gitea = MockGitea::start().await;
+    rate_limited("60").expect(1).mount(gitea.server()).await;
+    let client = gitea.client().with_retry(policy.clone());
+    let err = client.user().current().send(&client).await.unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::TOO_MANY_REQUESTS);
+    let TeatimeErrorKind::RateLimited { retry_after } = err.kind else {
+        panic!("expected a rate limit error, got {:?}", err.kind);
+    };
+    assert_eq!(retry_after, Some(Duration::from_secs(60)));
+
+    // Retrying rate limited requests can be disabled.
+    let gitea = MockGitea::start().await;
+    rate_limited("1").expect(1).mount(gitea.server()).await;
+    let client = gitea
+        .client()
+        .with_retry(policy.clone().retry_rate_limited(false));
+    let err = client.user().current().send(&client).await.unwrap_err();
+    assert!(matches!(err.kind, TeatimeErrorKind::RateLimited { .. }));
+
+    // A 503 with a Retry-After header is treated like a 429.
+    let gitea = MockGitea::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "60"))
+        .expect(1)
+        .mount(gitea.server())
+        .await;
+    let client = gitea.client().with_retry(policy);
+    let err = client.user().current().send(&client).await.unwrap_err();
+    assert_eq!(err.status_code, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+    assert!(matches!(err.kind, TeatimeErrorKind::RateLimited { .. }));
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+pub async fn test_chrono_timestamps(base_url: &str, token: &str) -> Result<()> {
+    use chrono::{DateTime, Utc};
+
+    let client = Client::new(base_url, Auth::Token(token));
+    let user = client.user().current().send(&client).await?;
+    let now = DateTime::<Utc>::from(std::time::SystemTime::now());
+    assert!(user.created.timestamp() > 0);
+    assert!(user.created <= now);
+    // Timestamps are also sent as RFC 3339 strings.
+    let times = client
+        .user()
+        .times()
+        .since(user.created)
+        .send(&client)
+        .await?;
+    assert!(times.is_empty());
+    Ok(())
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub async fn test_time_timestamps(base_url: &str, token: &str) -> Result<()> {
+    use time::OffsetDateTime;
+
+    let client = Client::new(base_url, Auth::Token(token));
+    let user = client.user().current().send(&client).await?;
+    assert!(user.created.unix_timestamp() > 0);
+    assert!(*user.created <= OffsetDateTime::now_utc());
+    // Timestamps are also sent as RFC 3339 strings.
+    let times = client
+        .user()
+        .times()
+        .since(user.created)
+        .send(&client)
+        .await?;
+    assert!(times.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_unknown_enum_values() {
+    use gitea_sdk::model::{
+        notifications::NotifySubjectType, repos::ObjectFormatName, reviews::ReviewStateType,
+    };
+
+    // Values added by newer Gitea versions don't fail the whole payload.
+    let issue: Issue = serde_json::from_str(r#"{"number": 1, "state": "archived"}"#).unwrap();
+    assert!(matches!(issue.state, StateType::Unknown));
+    let state: StateType = serde_json::from_str(r#""open""#).unwrap();
+    assert!(matches!(state, StateType::Open));
+    let review: ReviewStateType = serde_json::from_str(r#""DISMISSED_LATER""#).unwrap();
+    assert!(matches!(review, ReviewStateType::Unknown));
+
+    // The fallback can't be sent back to the server.
+    macro_rules! assert_unknown {
+        ($($enum:ident),*) => {$(
+            let value: $enum = serde_json::from_str(r#""added-in-a-newer-version""#).unwrap();
+            assert!(matches!(value, $enum::Unknown), "{}", stringify!($enum));
+            assert!(serde_json::to_string(&value).is_err(), "{}", stringify!($enum));
+        )*};
+    }
+    assert_unknown!(
+        HookType,
+        NotifySubjectType,
+        ObjectFormatName,
+        PackageType,
+        Permission,
+        StateType,
+        TokenScope,
+        Visibility
+    );
+}
+
+pub async fn test_create_repo(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let repo = client
+        .user()
+        .create_repo(GITEA_REPO)
+        .description(GITEA_REPO_DESCRIPTION)
+        .license("MIT")
+        .auto_init(true)
+        .send(&client)
+        .await?;
+    assert_eq!(repo.owner.login, GITEA_USER);
+    assert_eq!(repo.name, GITEA_REPO);
+    assert_eq!(repo.description, GITEA_REPO_DESCRIPTION);
+    Ok(())
+}
+
+pub async fn test_download(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let path = format!("repos/{GITEA_USER}/{GITEA_REPO}/raw/LICENSE");
+    let chunks: Vec<_> = client.download(&path).try_collect().await?;
+    let license = chunks.concat();
+    assert!(String::from_utf8_lossy(&license).contains("MIT License"));
+
+    let mut buf = Vec::new();
+    let written = client.download_to(&path, &mut buf).await?;
+    assert_eq!(written, buf.len() as u64);
+    assert_eq!(buf, license);
+
+    // Downloads aren't cut short by the client's timeout.
+    let impatient = client.clone().with_timeout(Duration::from_nanos(1));
+    let written = impatient.download_to(&path, &mut Vec::new()).await?;
+    assert_eq!(written, license.len() as u64);
+
+    let missing = format!("repos/{GITEA_USER}/{GITEA_REPO}/raw/does-not-exist");
+    let err = client.download(missing).try_collect::<Vec<_>>().await;
+    assert_eq!(err.unwrap_err().status_code, reqwest::StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+pub async fn test_repo_create_branch(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    client
+        .repos(GITEA_USER, GITEA_REPO)
+        .create_branch("new-branch")
+        .send(&client)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_repo_get_branch(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let branch = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .get_branch("new-branch")
+        .send(&client)
+        .await?;
+    assert_eq!(branch.name, "new-branch");
+    Ok(())
+}
+
+pub async fn test_repo_list_branches(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let branches = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .list_branches()
+        .send(&client)
+        .await?;
+    assert_eq!(branches.len(), 2);
+    assert_eq!(branches[0].name, "main");
+    Ok(())
+}
+
+pub async fn test_repo_delete_branch(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    client
+        .repos(GITEA_USER, GITEA_REPO)
+        .delete_branch("new-branch")
+        .send(&client)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_user_list_repos(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let repo = client
+        .user()
+        .list_repos()
+        .limit(10)
+        .page(1)
+        .send(&client)
+        .await?;
+    assert_eq!(repo.len(), 1);
+    assert_eq!(repo[0].owner.login, GITEA_USER);
+    assert_eq!(repo[0].name, GITEA_REPO);
+    assert_eq!(repo[0].description, GITEA_REPO_DESCRIPTION);
+    Ok(())
+}
+
+pub async fn test_users_list_repos(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let repo = client
+        .users(GITEA_USER)
+        .list_repos()
+        .limit(10)
+        .page(1)
+        .send(&client)
+        .await?;
+    assert_eq!(repo.len(), 1);
+    assert_eq!(repo[0].owner.login, GITEA_USER);
+    assert_eq!(repo[0].name, GITEA_REPO);
+    assert_eq!(repo[0].description, GITEA_REPO_DESCRIPTION);
+    Ok(())
+}
+
+pub async fn test_get_repo(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let repo = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .get()
+        .send(&client)
+        .await?;
+    assert_eq!(repo.owner.login, GITEA_USER);
+    assert_eq!(repo.name, GITEA_REPO);
+    assert_eq!(repo.description, GITEA_REPO_DESCRIPTION);
+    let permissions = repo.permissions.unwrap();
+    assert!(permissions.admin && permissions.push && permissions.pull);
+    assert!(repo.repo_transfer.is_none());
+    Ok(())
+}
+
+pub async fn test_edit_repo(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let repo = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .edit()
+        .default_merge_style(MergeStyle::Squash)
+        .send(&client)
+        .await?;
+    assert_eq!(repo.owner.login, GITEA_USER);
+    assert_eq!(repo.name, GITEA_REPO);
+    assert_eq!(repo.description, GITEA_REPO_DESCRIPTION);
+    assert_eq!(repo.default_merge_style, "squash");
+    Ok(())
+}
+
+pub async fn test_repo_is_starred(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let starred = client
+        .user()
+        .is_starred(GITEA_USER, GITEA_REPO)
+        .send(&client)
+        .await?;
+    assert!(!starred);
+    Ok(())
+}
+
+pub async fn test_star_repo(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    client
+        .user()
+        .star_repo(GITEA_USER, GITEA_REPO)
+        .send(&client)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_list_starred(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let stars = client.user().list_starred().send(&client).await?;
+    assert_eq!(stars.len(), 1);
+    assert_eq!(stars[0].name, GITEA_REPO);
+    Ok(())
+}
+
+pub async fn test_users_list_starred(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let stars = client
+        .users(GITEA_USER)
+        .list_starred()
+        .send(&client)
+        .await?;
+    assert_eq!(stars.len(), 1);
+    assert_eq!(stars[0].name, GITEA_REPO);
+    Ok(())
+}
+
+pub async fn test_unstar_repo(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    client
+        .user()
+        .unstar_repo(GITEA_USER, GITEA_REPO)
+        .send(&client)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_create_issue(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issue = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .create("test issue")
+        .body("test issue body")
+        .send(&client)
+        .await?;
+    assert_eq!(issue.title, "test issue");
+    assert_eq!(issue.body, Some("test issue body".to_string()));
+    Ok(())
+}
+
+pub async fn test_list_issues(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issues = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .list()
+        .send(&client)
+        .await?;
+    assert_eq!(issues.len(), 1);
+    Ok(())
+}
+
+pub async fn test_get_issue(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issue = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .get(1)
+        .send(&client)
+        .await?;
+    assert_eq!(issue.title, "test issue");
+    Ok(())
+}
+
+pub async fn test_edit_issue(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issue = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .edit(1)
+        .title("my new title")
+        .unset_due_date(true)
+        .send(&client)
+        .await?;
+    assert_eq!(issue.title, "my new title");
+    Ok(())
+}
+
+pub async fn test_issue_add_labels(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    CreateRepoLabelBuilder::new(GITEA_USER, GITEA_REPO, "bug", "#ee0701")
+        .send(&client)
+        .await?;
+    let labels = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .labels(1)
+        .add(vec!["bug"])
+        .send(&client)
+        .await?;
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].name, "bug");
+    Ok(())
+}
+
+pub async fn test_issue_clear_labels(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    client
+        .issues(GITEA_USER, GITEA_REPO)
+        .labels(1)
+        .clear()
+        .send(&client)
+        .await?;
+    let issue = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .get(1)
+        .send(&client)
+        .await?;
+    assert!(issue.labels.is_empty());
+    Ok(())
+}
+
+pub async fn test_issue_create_attachment(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let attachment = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .attachments(1)
+        .create("test.txt", "test attachment".as_bytes())
+        .send(&client)
+        .await?;
+    assert_eq!(attachment.name, "test.txt");
+    assert_eq!(attachment.size, 15);
+    Ok(())
+}
+
+pub async fn test_issue_list_attachments(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let attachments = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .attachments(1)
+        .list()
+        .send(&client)
+        .await?;
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].name, "test.txt");
+    Ok(())
+}
+
+pub async fn test_issue_upload_attachment(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let data = vec![b'x'; 256 * 1024];
+    let file = FileUpload::new("big.log", std::io::Cursor::new(data), 256 * 1024);
+    let attachment = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .attachments(1)
+        .upload(file)
+        .send(&client)
+        .await?;
+    assert_eq!(attachment.name, "big.log");
+    assert_eq!(attachment.size, 256 * 1024);
+    client
+        .issues(GITEA_USER, GITEA_REPO)
+        .attachments(1)
+        .delete(attachment.id)
+        .send(&client)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_issue_add_time(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let time = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .times(1)
+        .add(3600)
+        .send(&client)
+        .await?;
+    assert_eq!(time.time, 3600);
+    assert_eq!(time.user_name, GITEA_USER);
+    Ok(())
+}
+
+pub async fn test_repo_list_times(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let times = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .times()
+        .user(GITEA_USER)
+        .send(&client)
+        .await?;
+    assert_eq!(times.len(), 1);
+    assert_eq!(times[0].time, 3600);
+
+    let times = client.user().times().send(&client).await?;
+    assert_eq!(times.len(), 1);
+    Ok(())
+}
+
+pub async fn test_repo_list_action_tasks(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let tasks = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .actions()
+        .tasks()
+        .send(&client)
+        .await?;
+    assert!(tasks.is_empty());
+    Ok(())
+}
+
+pub async fn test_pin_issue(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    client
+        .issues(GITEA_USER, GITEA_REPO)
+        .pin(1)
+        .send(&client)
+        .await?;
+    Ok(())
+}
+
+pub async fn test_list_pinned_issues(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issues = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .list_pinned()
+        .send(&client)
+        .await?;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].number, 1);
+    Ok(())
+}
+
+pub async fn test_close_issue(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issue = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .close(1)
+        .send(&client)
+        .await?;
+    assert!(matches!(issue.state, StateType::Closed));
+    Ok(())
+}
+
+pub async fn test_reopen_issue(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issue = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .reopen(1)
+        .send(&client)
+        .await?;
+    assert!(matches!(issue.state, StateType::Open));
+    Ok(())
+}
+
+pub async fn test_create_comment(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let comment = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .comments()
+        .create(1, "test comment")
+        .send(&client)
+        .await?;
+    assert_eq!(comment.body, "test comment");
+    Ok(())
+}
+
+pub async fn test_comment_add_reaction(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let reaction = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .comments()
+        .reactions(1)
+        .add("heart")
+        .send(&client)
+        .await?;
+    assert_eq!(reaction.content, "heart");
+    Ok(())
+}
+
+pub async fn test_comment_list_reactions(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let reactions = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .comments()
+        .reactions(1)
+        .list()
+        .send(&client)
+        .await?;
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].content, "heart");
+    Ok(())
+}
+
+pub async fn test_edit_comment(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let comment = client
+        .issues(GITEA_USER, GITEA_REPO)
+        .comments()
+        .edit(1, "totally different test comment")
+        .send(&client)
+        .await?;
     if let Some(comment) = comment {
         assert_eq!(comment.body, "totally different test comment");
     }
@@ -723,6 +3084,38 @@ pub async fn test_search_issues(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     let issues = client.search().issues().send(&client).await?;
     assert_eq!(issues.len(), 1);
+    let repository = issues[0].repository.as_ref().unwrap();
+    assert_eq!(repository.owner, GITEA_USER);
+    assert_eq!(repository.name, GITEA_REPO);
+    assert!(!issues[0].is_pull_request());
+    Ok(())
+}
+
+pub async fn test_issue_dependencies(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let issues = client.issues(GITEA_USER, GITEA_REPO);
+    let blocker = issues.create("blocking issue").send(&client).await?;
+    issues
+        .dependencies(1)
+        .add(GITEA_USER, GITEA_REPO, blocker.number)
+        .send(&client)
+        .await?;
+
+    let dependencies = issues.dependencies(1).list().send(&client).await?;
+    assert_eq!(dependencies.len(), 1);
+    assert_eq!(dependencies[0].number, blocker.number);
+    let blocks = issues.blocks(blocker.number).list().send(&client).await?;
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].number, 1);
+
+    issues
+        .blocks(blocker.number)
+        .remove(GITEA_USER, GITEA_REPO, 1)
+        .send(&client)
+        .await?;
+    let dependencies = issues.dependencies(1).list().send(&client).await?;
+    assert!(dependencies.is_empty());
+    issues.delete(blocker.number).send(&client).await?;
     Ok(())
 }
 
@@ -736,6 +3129,28 @@ pub async fn test_delete_issue(base_url: &str, token: &str) -> Result<()> {
     Ok(())
 }
 
+pub async fn test_user_list_subscriptions(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let repos = client.user().subscriptions().send(&client).await?;
+    assert!(repos.iter().any(|r| r.name == GITEA_REPO));
+    let repos = client
+        .users(GITEA_USER)
+        .subscriptions()
+        .send(&client)
+        .await?;
+    assert!(repos.iter().any(|r| r.name == GITEA_REPO));
+    Ok(())
+}
+
+pub async fn test_users_activities(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let activities = client.users(GITEA_USER).activities().send(&client).await?;
+    assert!(activities.iter().any(|a| a.op_type == "create_repo"));
+    let heatmap = client.users(GITEA_USER).heatmap().send(&client).await?;
+    assert!(!heatmap.is_empty());
+    Ok(())
+}
+
 pub async fn test_delete_repo(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     client
@@ -772,6 +3187,22 @@ pub async fn test_get_commits(base_url: &str, token: &str) -> Result<()> {
         .send(&client)
         .await?;
     assert_eq!(commits.len(), 1);
+    let commit = &commits[0];
+    assert!(commit.parents.is_empty());
+    assert!(commit.stats.is_some_and(|stats| stats.additions > 0));
+    assert!(commit.files.as_ref().is_some_and(|files| !files.is_empty()));
+    let verification = commit.commit.verification.as_ref().unwrap();
+    assert!(!verification.reason.is_empty());
+
+    let commits = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .get_commits()
+        .stat(false)
+        .files(false)
+        .send(&client)
+        .await?;
+    assert!(commits[0].stats.is_none());
+    assert!(commits[0].files.is_none());
     Ok(())
 }
 
@@ -779,6 +3210,15 @@ pub async fn test_search_repos(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     let repos = client.search().repos().send(&client).await?;
     assert_eq!(repos.len(), 1);
+    let repos = client
+        .search()
+        .repos()
+        .mode(RepoSearchMode::Source)
+        .sort(SortKey::Created)
+        .order(SortOrder::Desc)
+        .send(&client)
+        .await?;
+    assert_eq!(repos.len(), 1);
     Ok(())
 }
 
@@ -793,3 +3233,38 @@ pub async fn test_search_users(base_url: &str, token: &str) -> Result<()> {
     assert_eq!(users.len(), 1);
     Ok(())
 }
+
+pub async fn test_search_topics(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let topics = client
+        .search()
+        .topics("no-repo-uses-this-topic")
+        .send(&client)
+        .await?;
+    assert!(topics.is_empty());
+    Ok(())
+}
+
+pub async fn test_pagination(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let users = client.search().users().send(&client).await?;
+    assert!(users.len() > 1);
+
+    let all_users = client.search().users().limit(1).send_all(&client).await?;
+    assert_eq!(all_users.len(), users.len());
+
+    let first = client
+        .search()
+        .users()
+        .limit(1)
+        .into_stream(&client)
+        .try_next()
+        .await?;
+    assert_eq!(first.map(|u| u.id), users.first().map(|u| u.id));
+
+    let page = client.search().users().limit(1).send_paged(&client).await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.total_count, Some(users.len() as u64));
+    assert_eq!(page.next_page, Some(2));
+    Ok(())
+}